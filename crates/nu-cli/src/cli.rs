@@ -77,6 +77,7 @@ pub async fn run_script_file(
         syncer.load_environment();
         syncer.sync_env_vars(ctx);
         syncer.sync_path_vars(ctx);
+        syncer.sync_scope_settings(ctx);
 
         if let Err(reason) = syncer.autoenv(ctx) {
             print_err(reason, &Text::from(""), ctx);
@@ -105,6 +106,7 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
         syncer.load_environment();
         syncer.sync_env_vars(ctx);
         syncer.sync_path_vars(ctx);
+        syncer.sync_scope_settings(ctx);
 
         if let Err(reason) = syncer.autoenv(ctx) {
             print_err(reason, &Text::from(""), ctx);
@@ -271,6 +273,7 @@ pub async fn cli(mut context: EvaluationContext) -> Result<(), Box<dyn Error>> {
                 syncer.reload();
                 syncer.sync_env_vars(ctx);
                 syncer.sync_path_vars(ctx);
+                syncer.sync_scope_settings(ctx);
             }
 
             if let Err(reason) = syncer.autoenv(ctx) {
@@ -0,0 +1,252 @@
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use futures::StreamExt;
+use indexmap::set::IndexSet;
+use parking_lot::Mutex;
+
+use super::matchers::{sort_by_rank, Matcher};
+use crate::completion::{Completer, CompletionContext, Suggestion};
+use nu_engine::{run_block, EvaluationContext};
+use nu_protocol::hir::{Block, ClassifiedCommand, InternalCommand};
+use nu_protocol::{UntaggedValue, Value};
+use nu_source::Span;
+use nu_stream::InputStream;
+
+/// Commands whose arguments are column names
+pub const COLUMN_COMMANDS: &[&str] = &["get", "reject", "select", "sort-by", "where"];
+
+/// Commands that are cheap and free of side effects, so running them just to see which columns
+/// come out of them is fine. Any `from` subcommand is allowed as well.
+const DRY_RUN_COMMANDS: &[&str] = &[
+    "compact", "echo", "first", "flatten", "get", "keep", "last", "nth", "open", "pivot", "reject",
+    "select", "skip", "sort-by", "uniq", "where", "wrap",
+];
+
+/// `open` is only run on files up to this size
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Only the first rows of the upstream output are looked at for their columns
+const MAX_ROWS: usize = 100;
+
+/// How long the completer waits for the dry run before giving up on it
+const DRY_RUN_TIMEOUT: Duration = Duration::from_millis(500);
+
+pub struct ColumnCompleter<'a> {
+    pub(crate) values: &'a [Value],
+}
+
+impl<'a> Completer for ColumnCompleter<'a> {
+    fn complete(
+        &self,
+        _ctx: &CompletionContext<'_>,
+        partial: &str,
+        matcher: &dyn Matcher,
+    ) -> Vec<Suggestion> {
+        column_suggestions(self.values, partial, matcher)
+    }
+}
+
+fn column_suggestions(values: &[Value], partial: &str, matcher: &dyn Matcher) -> Vec<Suggestion> {
+    // `package.au` completes the columns inside of `package`
+    let (head, partial) = match partial.rfind('.') {
+        Some(pos) => (&partial[..=pos], &partial[pos + 1..]),
+        None => ("", partial),
+    };
+    let path: Vec<&str> = head
+        .split('.')
+        .filter(|member| !member.is_empty())
+        .collect();
+
+    let mut columns = IndexSet::new();
+    for value in values {
+        collect_columns(value, &path, &mut columns);
+    }
+
+    let mut columns: Vec<String> = columns
+        .into_iter()
+        .filter(|column| matcher.matches(partial, column))
+        .collect();
+    sort_by_rank(matcher, partial, &mut columns, |column| column);
+
+    columns
+        .into_iter()
+        .map(|column| Suggestion {
+            replacement: format!("{}{}", head, column),
+            display: column,
+        })
+        .collect()
+}
+
+fn collect_columns(value: &Value, path: &[&str], columns: &mut IndexSet<String>) {
+    match (&value.value, path.split_first()) {
+        (UntaggedValue::Table(rows), _) => {
+            for row in rows {
+                collect_columns(row, path, columns);
+            }
+        }
+        (UntaggedValue::Row(dict), Some((member, rest))) => {
+            if let Some(value) = dict.entries.get(*member) {
+                collect_columns(value, rest, columns);
+            }
+        }
+        (UntaggedValue::Row(dict), None) => columns.extend(dict.entries.keys().cloned()),
+        _ => {}
+    }
+}
+
+/// The source of the pipeline leading up to the command at `location`, if it is safe to run it
+/// to find out the columns it gives.
+pub fn upstream_source(
+    line: &str,
+    block: &Block,
+    location: Span,
+    context: &EvaluationContext,
+) -> Option<String> {
+    let pipeline = block
+        .block
+        .iter()
+        .flat_map(|group| group.pipelines.iter())
+        .find(|pipeline| {
+            pipeline.span.start() <= location.start() && location.end() <= pipeline.span.end()
+        })?;
+
+    let current = pipeline.list.iter().rposition(|command| match command {
+        ClassifiedCommand::Internal(internal) => internal.name_span.start() <= location.start(),
+        _ => false,
+    })?;
+
+    let upstream = &pipeline.list[..current];
+    let (first, current) = match (upstream.first(), &pipeline.list[current]) {
+        (Some(ClassifiedCommand::Internal(first)), ClassifiedCommand::Internal(current)) => {
+            (first, current)
+        }
+        _ => return None,
+    };
+
+    for command in upstream {
+        let internals = match command {
+            ClassifiedCommand::Internal(internal) => internal.get_internal_commands(),
+            _ => return None,
+        };
+
+        if !internals
+            .into_iter()
+            .all(|internal| is_safe_to_run(internal, line, context))
+        {
+            return None;
+        }
+    }
+
+    let source = line[first.name_span.start()..current.name_span.start()]
+        .trim_end()
+        .trim_end_matches('|');
+
+    Some(source.to_string())
+}
+
+fn is_safe_to_run(internal: &InternalCommand, line: &str, context: &EvaluationContext) -> bool {
+    if internal.name == "open" {
+        let path = match internal.args.positional.as_ref().and_then(|p| p.first()) {
+            Some(path) => path
+                .span
+                .slice(line)
+                .trim_matches(|c| c == '"' || c == '\'' || c == '`'),
+            None => return false,
+        };
+
+        return PathBuf::from(context.shell_manager.path())
+            .join(path)
+            .metadata()
+            .map(|metadata| metadata.is_file() && metadata.len() <= MAX_FILE_SIZE)
+            .unwrap_or(false);
+    }
+
+    internal.name.starts_with("from ") || DRY_RUN_COMMANDS.contains(&internal.name.as_str())
+}
+
+/// Runs the given pipeline in the background to collect the values it outputs, giving up if that
+/// takes too long.
+pub fn dry_run(source: String, context: &EvaluationContext) -> Option<Vec<Value>> {
+    let mut context = context.clone();
+
+    // Neither the errors nor the variables of the dry run are the user's business, and the run
+    // may still be going once the completer has given up on it
+    context.current_errors = Arc::new(Mutex::new(Vec::new()));
+    context.scope = context.scope.detached();
+
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let values = futures::executor::block_on(async {
+            let (block, err) = nu_parser::parse(&source, 0, &context.scope);
+            if err.is_some() {
+                return None;
+            }
+
+            match run_block(&block, &context, InputStream::empty()).await {
+                Ok(stream) => Some(stream.take(MAX_ROWS).collect::<Vec<_>>().await),
+                Err(_) => None,
+            }
+        });
+
+        let _ = sender.send(values);
+    });
+
+    receiver.recv_timeout(DRY_RUN_TIMEOUT).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::column_suggestions;
+    use crate::completion::matchers::case_sensitive::Matcher;
+    use indexmap::indexmap;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_test_support::value::{row, string, table};
+
+    fn package() -> Value {
+        row(indexmap! {
+            "package".to_string() => row(indexmap! {
+                "name".to_string() => string("nu"),
+                "authors".to_string() => table(&[string("The Nu Project Contributors")]),
+                "version".to_string() => string("0.28.0"),
+            }),
+            "dependencies".to_string() => row(indexmap! {}),
+        })
+    }
+
+    fn completions(values: &[Value], partial: &str) -> Vec<String> {
+        column_suggestions(values, partial, &Matcher)
+            .into_iter()
+            .map(|suggestion| suggestion.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn completes_columns() {
+        assert_eq!(completions(&[package()], "pack"), vec!["package"]);
+    }
+
+    #[test]
+    fn completes_nested_columns() {
+        assert_eq!(
+            completions(&[package()], "package."),
+            vec!["package.name", "package.authors", "package.version"]
+        );
+        assert_eq!(
+            completions(&[package()], "package.v"),
+            vec!["package.version"]
+        );
+    }
+
+    #[test]
+    fn completes_columns_of_every_row() {
+        let values = vec![
+            row(indexmap! { "name".to_string() => string("a") }),
+            UntaggedValue::Table(vec![row(indexmap! { "size".to_string() => string("1kb") })])
+                .into_untagged_value(),
+        ];
+
+        assert_eq!(completions(&values, ""), vec!["name", "size"]);
+    }
+}
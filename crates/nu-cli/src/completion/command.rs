@@ -3,7 +3,7 @@ use std::path::Path;
 
 use indexmap::set::IndexSet;
 
-use super::matchers::Matcher;
+use super::matchers::{sort_by_rank, Matcher};
 use crate::completion::{Completer, CompletionContext, Suggestion};
 use nu_engine::EvaluationContext;
 
@@ -19,10 +19,19 @@ impl Completer for CommandCompleter {
         let context: &EvaluationContext = ctx.as_ref();
         let mut commands: IndexSet<String> = IndexSet::from_iter(context.scope.get_command_names());
 
-        // Command suggestions can come from three possible sets:
+        // Command suggestions can come from four possible sets:
         //   1. internal command names,
-        //   2. external command names relative to PATH env var, and
-        //   3. any other executable (that matches what's been typed so far).
+        //   2. aliases,
+        //   3. external command names relative to PATH env var, and
+        //   4. any other executable (that matches what's been typed so far).
+
+        commands.extend(
+            context
+                .scope
+                .get_aliases()
+                .into_iter()
+                .map(|(alias, _)| alias),
+        );
 
         let path_executables = find_path_executables().unwrap_or_default();
 
@@ -53,10 +62,54 @@ impl Completer for CommandCompleter {
             suggestions.extend(iter);
         }
 
-        suggestions
+        sort_by_rank(matcher, partial, &mut suggestions, |s| &s.display);
+        finish_sole_suggestion(suggestions)
     }
 }
 
+impl CommandCompleter {
+    /// Completes the second word of a subcommand (eg. `row` in `split row`) when the partial is
+    /// the first argument of `cmd`.
+    pub fn complete_subcommand(
+        &self,
+        ctx: &CompletionContext<'_>,
+        cmd: &str,
+        partial: &str,
+        matcher: &dyn Matcher,
+    ) -> Vec<Suggestion> {
+        let context: &EvaluationContext = ctx.as_ref();
+        let prefix = format!("{} ", cmd);
+
+        let mut suggestions = context
+            .scope
+            .get_command_names()
+            .into_iter()
+            .filter_map(|name| name.strip_prefix(&prefix).map(String::from))
+            .filter(|subcommand| matcher.matches(partial, subcommand))
+            .map(|subcommand| Suggestion {
+                replacement: subcommand.clone(),
+                display: subcommand,
+            })
+            .collect();
+
+        sort_by_rank(matcher, partial, &mut suggestions, |s| &s.display);
+        finish_sole_suggestion(suggestions)
+    }
+}
+
+/// When only one command matches there is nothing left to choose, so a space is added to let the
+/// user go straight on to typing the arguments. Directories are left alone, as the user will most
+/// likely want to keep completing inside them.
+fn finish_sole_suggestion(mut suggestions: Vec<Suggestion>) -> Vec<Suggestion> {
+    if let [suggestion] = suggestions.as_mut_slice() {
+        if !suggestion.replacement.ends_with(std::path::MAIN_SEPARATOR) {
+            suggestion.replacement.push(' ');
+        }
+    }
+
+    suggestions
+}
+
 // TODO create a struct for "is executable" and store this information in it so we don't recompute
 //      on every dir entry
 
@@ -139,3 +192,45 @@ fn find_path_executables() -> Option<IndexSet<String>> {
 
     Some(executables)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::finish_sole_suggestion;
+    use crate::completion::Suggestion;
+
+    fn suggestion(name: &str) -> Suggestion {
+        Suggestion {
+            replacement: name.to_string(),
+            display: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn appends_a_space_to_the_only_suggestion() {
+        assert_eq!(
+            finish_sole_suggestion(vec![suggestion("split-row")]),
+            vec![Suggestion {
+                replacement: "split-row ".to_string(),
+                display: "split-row".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn leaves_several_suggestions_alone() {
+        assert_eq!(
+            finish_sole_suggestion(vec![suggestion("from-toml"), suggestion("from-tsv")]),
+            vec![suggestion("from-toml"), suggestion("from-tsv")]
+        );
+    }
+
+    #[test]
+    fn leaves_a_sole_directory_alone() {
+        let dir = format!("src{}", std::path::MAIN_SEPARATOR);
+
+        assert_eq!(
+            finish_sole_suggestion(vec![suggestion(&dir)]),
+            vec![suggestion(&dir)]
+        );
+    }
+}
@@ -37,7 +37,21 @@ impl<'s> Flatten<'s> {
                 )
                 .collect(),
             Expression::Command => vec![LocationType::Command.spanned(e.span)],
-            Expression::Path(path) => self.expression(&path.head),
+            Expression::Path(path) => match &path.head.expr {
+                // Shorthand like the `size` in `where size > 10kb` has an implicit `$it` spanning
+                // the whole path, which is really a column name to complete
+                Expression::Variable(name, _)
+                    if name == "$it" && !e.span.slice(self.line).starts_with('$') =>
+                {
+                    vec![
+                        LocationType::Argument(self.command.clone(), self.flag.clone())
+                            .spanned(e.span),
+                    ]
+                }
+                // A path on a variable, like `$nu.env.PATH`, is completed as a whole
+                Expression::Variable(_, _) => vec![LocationType::Variable.spanned(e.span)],
+                _ => self.expression(&path.head),
+            },
             Expression::Variable(_, _) => vec![LocationType::Variable.spanned(e.span)],
 
             Expression::Boolean(_)
@@ -46,6 +60,7 @@ impl<'s> Flatten<'s> {
             | Expression::Literal(Literal::GlobPattern(_))
             | Expression::Literal(Literal::Number(_))
             | Expression::Literal(Literal::Size(_, _))
+            | Expression::Literal(Literal::Date(_))
             | Expression::Literal(Literal::String(_)) => {
                 vec![
                     LocationType::Argument(self.command.clone(), self.flag.clone()).spanned(e.span),
@@ -58,6 +73,7 @@ impl<'s> Flatten<'s> {
                 result.append(&mut self.expression(&binary.right));
                 result
             }
+            Expression::Not(expr) => self.expression(expr),
             Expression::Range(range) => {
                 let mut result = Vec::new();
                 if let Some(left) = &range.left {
@@ -252,6 +268,32 @@ pub fn completion_location(line: &str, block: &Block, pos: usize) -> Vec<Complet
     }
 }
 
+/// The flags already given to the command that the span at `location` is part of, leaving out the
+/// flag at `location` itself
+pub fn flags_in_use(block: &Block, location: Span) -> Vec<String> {
+    let command = block
+        .get_internal_commands()
+        .into_iter()
+        .filter(|internal| {
+            internal.args.span.start() <= location.start()
+                && location.end() <= internal.args.span.end()
+        })
+        .min_by_key(|internal| internal.args.span.end() - internal.args.span.start());
+
+    match command.and_then(|internal| internal.args.named.as_ref()) {
+        Some(named) => named
+            .named
+            .iter()
+            .filter(|(_, value)| match value {
+                NamedValue::PresentSwitch(span) | NamedValue::Value(span, _) => *span != location,
+                _ => false,
+            })
+            .map(|(name, _)| name.clone())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,11 +343,11 @@ mod tests {
 
         use nu_parser::ParserScope;
 
-        fn completion_location(
+        fn spanned_completion_location(
             line: &str,
             scope: &dyn ParserScope,
             pos: usize,
-        ) -> Vec<LocationType> {
+        ) -> Vec<CompletionLocation> {
             let (tokens, _) = lex(line, 0);
             let (lite_block, _) = parse_block(tokens);
 
@@ -314,6 +356,14 @@ mod tests {
             scope.exit_scope();
 
             super::completion_location(line, &block, pos)
+        }
+
+        fn completion_location(
+            line: &str,
+            scope: &dyn ParserScope,
+            pos: usize,
+        ) -> Vec<LocationType> {
+            spanned_completion_location(line, scope, pos)
                 .into_iter()
                 .map(|v| v.item)
                 .collect()
@@ -353,6 +403,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn completes_hyphenated_command_names_as_one_word() {
+            let registry: VecRegistry = vec![Signature::build("split-row")].into();
+            let line = "echo 1 | split-r";
+
+            let locations = spanned_completion_location(line, &registry, 16);
+
+            assert_eq!(
+                locations
+                    .into_iter()
+                    .map(|location| (location.item, location.span.slice(line).to_string()))
+                    .collect::<Vec<_>>(),
+                vec![(LocationType::Command, "split-r".to_string())],
+            );
+        }
+
         #[test]
         fn completes_variables() {
             let registry: VecRegistry = Vec::new().into();
@@ -364,6 +430,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn completes_paths_on_variables_as_a_whole() {
+            let registry: VecRegistry = Vec::new().into();
+            let line = "echo $nu.env.PA";
+
+            let locations = spanned_completion_location(line, &registry, 15);
+
+            assert_eq!(
+                locations
+                    .into_iter()
+                    .map(|location| (location.item, location.span.slice(line).to_string()))
+                    .collect::<Vec<_>>(),
+                vec![(LocationType::Variable, "$nu.env.PA".to_string())],
+            );
+        }
+
         #[test]
         fn completes_flags() {
             let registry: VecRegistry = vec![Signature::build("du")
@@ -390,6 +472,44 @@ mod tests {
             );
         }
 
+        #[test]
+        fn completes_arguments_after_an_earlier_parse_error() {
+            let registry: VecRegistry = vec![
+                Signature::build("where").required(
+                    "condition",
+                    SyntaxShape::RowCondition,
+                    "the condition",
+                ),
+                Signature::build("open").required("path", SyntaxShape::FilePath, "the path"),
+            ]
+            .into();
+            let line = "where size > ; open Car";
+
+            assert_eq!(
+                completion_location(line, &registry, 23),
+                vec![LocationType::Argument(Some("open".to_string()), None)],
+            );
+        }
+
+        #[test]
+        fn completes_shorthand_column_paths_as_arguments() {
+            let registry: VecRegistry = vec![Signature::build("where").required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "the condition",
+            )]
+            .into();
+
+            for line in &["where pack", "where package.ver"] {
+                let end = line.len();
+
+                assert_eq!(
+                    completion_location(line, &registry, end),
+                    vec![LocationType::Argument(Some("where".to_string()), None)],
+                );
+            }
+        }
+
         #[test]
         fn has_correct_command_name_for_argument() {
             let registry: VecRegistry = vec![Signature::build("cd")].into();
@@ -431,4 +551,54 @@ mod tests {
             );
         }
     }
+
+    mod flags_in_use {
+        use super::*;
+
+        fn flags_in_use(line: &str, scope: &dyn ParserScope, location: Span) -> Vec<String> {
+            let (tokens, _) = lex(line, 0);
+            let (lite_block, _) = parse_block(tokens);
+
+            scope.enter_scope();
+            let (block, _) = classify_block(&lite_block, scope);
+            scope.exit_scope();
+
+            super::flags_in_use(&block, location)
+        }
+
+        fn registry() -> VecRegistry {
+            vec![
+                Signature::build("open")
+                    .optional("path", SyntaxShape::FilePath, "the path")
+                    .switch("raw", "load content as a string", None)
+                    .named("encoding", SyntaxShape::String, "the encoding", Some('e')),
+                Signature::build("ls").switch("all", "show all", Some('a')),
+            ]
+            .into()
+        }
+
+        #[test]
+        fn finds_flags_given_to_the_command() {
+            let line = "open Cargo.toml --raw -e utf-8 --";
+
+            assert_eq!(
+                flags_in_use(line, &registry(), Span::new(31, 33)),
+                vec!["raw".to_string(), "encoding".to_string()],
+            );
+        }
+
+        #[test]
+        fn ignores_the_flag_being_completed() {
+            let line = "open Cargo.toml --raw";
+
+            assert!(flags_in_use(line, &registry(), Span::new(16, 21)).is_empty());
+        }
+
+        #[test]
+        fn ignores_flags_of_other_commands_in_the_pipeline() {
+            let line = "ls --all | open Cargo.toml --";
+
+            assert!(flags_in_use(line, &registry(), Span::new(27, 29)).is_empty());
+        }
+    }
 }
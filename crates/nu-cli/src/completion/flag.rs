@@ -1,9 +1,12 @@
-use super::matchers::Matcher;
+use super::matchers::{sort_by_rank, Matcher};
 use crate::completion::{Completer, CompletionContext, Suggestion};
 use nu_engine::EvaluationContext;
+use nu_protocol::Signature;
 
 pub struct FlagCompleter {
     pub(crate) cmd: String,
+    /// Flags (by their long name) that are already given elsewhere on the line
+    pub(crate) used: Vec<String>,
 }
 
 impl Completer for FlagCompleter {
@@ -15,27 +18,111 @@ impl Completer for FlagCompleter {
     ) -> Vec<Suggestion> {
         let context: &EvaluationContext = ctx.as_ref();
 
+        // Externals have no signature to go by, so rather than guess we don't suggest anything
         if let Some(cmd) = context.scope.get_command(&self.cmd) {
-            let sig = cmd.signature();
-            let mut suggestions = Vec::new();
-            for (name, (named_type, _desc)) in sig.named.iter() {
-                suggestions.push(format!("--{}", name));
-
-                if let Some(c) = named_type.get_short() {
-                    suggestions.push(format!("-{}", c));
-                }
-            }
-
-            suggestions
-                .into_iter()
-                .filter(|v| matcher.matches(partial, v))
-                .map(|v| Suggestion {
-                    replacement: format!("{} ", v),
-                    display: v,
-                })
-                .collect()
+            flag_suggestions(&cmd.signature(), &self.used, partial, matcher)
         } else {
             Vec::new()
         }
     }
 }
+
+fn flag_suggestions(
+    sig: &Signature,
+    used: &[String],
+    partial: &str,
+    matcher: &dyn Matcher,
+) -> Vec<Suggestion> {
+    let mut long_flags = Vec::new();
+    let mut short_flags = Vec::new();
+    for (name, (named_type, _desc)) in sig.named.iter() {
+        if used.contains(name) {
+            continue;
+        }
+
+        long_flags.push(format!("--{}", name));
+
+        if let Some(c) = named_type.get_short() {
+            short_flags.push(format!("-{}", c));
+        }
+    }
+
+    // A lone `-` is the start of a short flag, unless the command doesn't have any
+    let mut suggestions: Vec<String> = if partial == "-" && !short_flags.is_empty() {
+        short_flags
+    } else {
+        long_flags.into_iter().chain(short_flags).collect()
+    };
+
+    suggestions.retain(|v| matcher.matches(partial, v));
+    sort_by_rank(matcher, partial, &mut suggestions, |v| v);
+
+    suggestions
+        .into_iter()
+        .map(|v| Suggestion {
+            replacement: format!("{} ", v),
+            display: v,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flag_suggestions;
+    use crate::completion::matchers::case_sensitive::Matcher;
+    use nu_protocol::{Signature, SyntaxShape};
+
+    fn signature() -> Signature {
+        Signature::build("open")
+            .switch("raw", "load content as a string instead of a table", None)
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "encoding to use to open file",
+                Some('e'),
+            )
+            .switch("all", "show all", Some('a'))
+    }
+
+    fn displayed(sig: &Signature, used: &[&str], partial: &str) -> Vec<String> {
+        let used: Vec<String> = used.iter().map(|flag| flag.to_string()).collect();
+
+        flag_suggestions(sig, &used, partial, &Matcher)
+            .into_iter()
+            .map(|suggestion| suggestion.display)
+            .collect()
+    }
+
+    #[test]
+    fn completes_long_flags_after_two_hyphens() {
+        assert_eq!(
+            displayed(&signature(), &[], "--"),
+            vec!["--help", "--raw", "--encoding", "--all"]
+        );
+        assert_eq!(displayed(&signature(), &[], "--r"), vec!["--raw"]);
+    }
+
+    #[test]
+    fn completes_short_flags_after_one_hyphen() {
+        assert_eq!(displayed(&signature(), &[], "-"), vec!["-h", "-e", "-a"]);
+    }
+
+    #[test]
+    fn completes_long_flags_after_one_hyphen_when_there_are_no_short_ones() {
+        let sig = Signature::build("open").switch("raw", "load content as a string", None);
+
+        assert_eq!(displayed(&sig, &["help"], "-"), vec!["--raw"]);
+    }
+
+    #[test]
+    fn leaves_out_flags_that_are_already_used() {
+        assert_eq!(
+            displayed(&signature(), &["help", "raw", "all"], "--"),
+            vec!["--encoding"]
+        );
+        assert_eq!(
+            displayed(&signature(), &["help", "encoding"], "-"),
+            vec!["-a"]
+        );
+    }
+}
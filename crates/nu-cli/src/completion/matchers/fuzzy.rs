@@ -0,0 +1,101 @@
+use crate::completion::matchers;
+
+/// Matches candidates that contain the characters of the partial in order, ignoring case, so
+/// `row` matches `split-row` and `sr` does too
+pub struct Matcher;
+
+/// Ranks of the ways a candidate can match, the better ones first
+const PREFIX: usize = 0;
+const SUBSTRING: usize = 1000;
+const SUBSEQUENCE: usize = 2000;
+
+/// How far within a kind of match candidates are told apart
+const MAX_DISTANCE: usize = 999;
+
+impl matchers::Matcher for Matcher {
+    fn matches(&self, partial: &str, from: &str) -> bool {
+        rank(partial, from).is_some()
+    }
+
+    fn rank(&self, partial: &str, from: &str) -> usize {
+        rank(partial, from).unwrap_or(usize::MAX)
+    }
+}
+
+fn rank(partial: &str, from: &str) -> Option<usize> {
+    let partial: Vec<char> = partial.to_lowercase().chars().collect();
+    let from: Vec<char> = from.to_lowercase().chars().collect();
+
+    if from.starts_with(&partial) {
+        // The fewer characters are left to type, the better
+        return Some(PREFIX + (from.len() - partial.len()).min(MAX_DISTANCE));
+    }
+
+    if let Some(pos) = from
+        .windows(partial.len())
+        .position(|window| window == partial.as_slice())
+    {
+        return Some(SUBSTRING + pos.min(MAX_DISTANCE));
+    }
+
+    // The characters in between the ones typed count against the candidate
+    let mut skipped = 0;
+    let mut remaining = from.iter();
+    for c in &partial {
+        skipped += remaining.position(|candidate| candidate == c)?;
+    }
+
+    Some(SUBSEQUENCE + skipped.min(MAX_DISTANCE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rank;
+    use super::Matcher;
+    use crate::completion::matchers::{self, sort_by_rank};
+
+    #[test]
+    fn ranks_candidates() {
+        let cases = vec![
+            ("split", "split", Some(0)),
+            ("spl", "split-row", Some(6)),
+            ("SPL", "split-row", Some(6)),
+            ("row", "split-row", Some(1006)),
+            ("-row", "split-row", Some(1005)),
+            ("sr", "split-row", Some(2005)),
+            ("srw", "split-row", Some(2006)),
+            ("", "anything", Some(8)),
+            ("wor", "split-row", None),
+            ("rows", "split-row", None),
+            ("split-row-x", "split-row", None),
+        ];
+
+        for (partial, candidate, expected) in cases {
+            assert_eq!(
+                rank(partial, candidate),
+                expected,
+                "ranking {} against {}",
+                candidate,
+                partial
+            );
+        }
+    }
+
+    #[test]
+    fn matches_what_it_ranks() {
+        let matcher: Box<dyn matchers::Matcher> = Box::new(Matcher);
+
+        assert!(matcher.matches("row", "split-row"));
+        assert!(matcher.matches("fty", "from-toml-tsv-yaml"));
+        assert!(!matcher.matches("yaml-from", "from-yaml"));
+    }
+
+    #[test]
+    fn sorts_better_matches_first() {
+        let mut candidates = vec!["split-row", "rotate", "row", "from-xml"];
+        candidates.retain(|candidate| matchers::Matcher::matches(&Matcher, "ro", candidate));
+        sort_by_rank(&Matcher, "ro", &mut candidates, |candidate| candidate);
+
+        assert_eq!(candidates, vec!["row", "rotate", "from-xml", "split-row"]);
+    }
+}
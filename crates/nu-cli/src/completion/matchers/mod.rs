@@ -1,6 +1,23 @@
 pub(crate) mod case_insensitive;
 pub(crate) mod case_sensitive;
+pub(crate) mod fuzzy;
 
 pub trait Matcher {
     fn matches(&self, partial: &str, from: &str) -> bool;
+
+    /// How well `from` matches `partial`, where lower is better. Only meaningful when `from`
+    /// matches at all. Matchers that don't tell matches apart rank them all the same.
+    fn rank(&self, _partial: &str, _from: &str) -> usize {
+        0
+    }
+}
+
+/// Puts the best matches first, keeping the order of equally good ones
+pub fn sort_by_rank<T>(
+    matcher: &dyn Matcher,
+    partial: &str,
+    items: &mut Vec<T>,
+    key: impl Fn(&T) -> &str,
+) {
+    items.sort_by_key(|item| matcher.rank(partial, key(item)));
 }
@@ -1,8 +1,10 @@
+pub(crate) mod column;
 pub(crate) mod command;
 pub(crate) mod engine;
 pub(crate) mod flag;
 pub(crate) mod matchers;
 pub(crate) mod path;
+pub(crate) mod variable;
 
 use matchers::Matcher;
 use nu_engine::EvaluationContext;
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::matchers::Matcher;
+use super::matchers::{sort_by_rank, Matcher};
 use crate::completion::{Completer, CompletionContext, Suggestion};
 
 const SEP: char = std::path::MAIN_SEPARATOR;
@@ -43,10 +43,16 @@ impl PathCompleter {
         };
 
         if let Ok(result) = base_dir.read_dir() {
-            result
+            let mut suggestions: Vec<PathSuggestion> = result
                 .filter_map(|entry| {
                     entry.ok().and_then(|entry| {
                         let mut file_name = entry.file_name().to_string_lossy().into_owned();
+
+                        // Hidden files are only suggested once a `.` has been typed
+                        if file_name.starts_with('.') && !partial.starts_with('.') {
+                            return None;
+                        }
+
                         if matcher.matches(partial, file_name.as_str()) {
                             let mut path = format!("{}{}", base_dir_name, file_name);
                             if entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false) {
@@ -66,7 +72,12 @@ impl PathCompleter {
                         }
                     })
                 })
-                .collect()
+                .collect();
+
+            sort_by_rank(matcher, partial, &mut suggestions, |ps| {
+                &ps.suggestion.display
+            });
+            suggestions
         } else {
             Vec::new()
         }
@@ -86,3 +97,65 @@ impl Completer for PathCompleter {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PathCompleter, SEP};
+    use crate::completion::matchers::case_sensitive;
+    use std::path::PathBuf;
+
+    fn setup(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nu_path_completion_{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).expect("could not create the test directory");
+        for file in &["Cargo.toml", "Cargo.lock", ".hidden"] {
+            std::fs::write(dir.join(file), "").expect("could not create a test file");
+        }
+        dir
+    }
+
+    fn suggestions(partial: &str) -> Vec<String> {
+        let mut replacements: Vec<_> = PathCompleter
+            .path_suggestions(partial, &case_sensitive::Matcher)
+            .into_iter()
+            .map(|suggestion| suggestion.suggestion.display)
+            .collect();
+        replacements.sort();
+        replacements
+    }
+
+    #[test]
+    fn completes_entries_of_the_directory_typed_so_far() {
+        let dir = setup("entries");
+        let base = format!("{}{}", dir.display(), SEP);
+
+        assert_eq!(
+            suggestions(&base),
+            vec![
+                "Cargo.lock".to_string(),
+                "Cargo.toml".to_string(),
+                format!("src{}", SEP)
+            ]
+        );
+        assert_eq!(
+            suggestions(&format!("{}Cargo.t", base)),
+            vec!["Cargo.toml".to_string()]
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn completes_hidden_files_only_after_a_dot() {
+        let dir = setup("hidden");
+        let base = format!("{}{}", dir.display(), SEP);
+
+        assert!(!suggestions(&base).contains(&".hidden".to_string()));
+        assert_eq!(
+            suggestions(&format!("{}.", base)),
+            vec![".hidden".to_string()]
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,78 @@
+use super::matchers::{sort_by_rank, Matcher};
+use crate::completion::{Completer, CompletionContext, Suggestion};
+use indexmap::set::IndexSet;
+use nu_engine::EvaluationContext;
+
+/// Variables the evaluator knows about without them being in scope
+const BUILTIN_VARIABLES: &[&str] = &["$nu", "$env", "$true", "$false", "$nothing"];
+
+pub struct VariableCompleter {
+    /// Whether the variable is in a place that gets input, so `$it` is available
+    pub(crate) it_in_scope: bool,
+}
+
+impl Completer for VariableCompleter {
+    fn complete(
+        &self,
+        ctx: &CompletionContext<'_>,
+        partial: &str,
+        matcher: &dyn Matcher,
+    ) -> Vec<Suggestion> {
+        let context: &EvaluationContext = ctx.as_ref();
+
+        let mut variables: IndexSet<String> = IndexSet::new();
+        if self.it_in_scope {
+            variables.insert("$it".to_string());
+        }
+        variables.extend(BUILTIN_VARIABLES.iter().map(|name| name.to_string()));
+        variables.extend(
+            context
+                .scope
+                .get_vars()
+                .into_iter()
+                .map(|(name, _)| name)
+                .filter(|name| name != "$it"),
+        );
+
+        let mut variables: Vec<String> = variables
+            .into_iter()
+            .filter(|name| matcher.matches(partial, name))
+            .collect();
+        sort_by_rank(matcher, partial, &mut variables, |name| name);
+
+        variables
+            .into_iter()
+            .map(|name| Suggestion {
+                replacement: name.clone(),
+                display: name,
+            })
+            .collect()
+    }
+}
+
+/// Whether `$it` is available at `pos`, which is the case within blocks and in any pipeline stage
+/// after the first
+pub fn it_in_scope(line: &str, pos: usize) -> bool {
+    matches!(
+        line[..pos].rfind(|c| matches!(c, '|' | '{' | ';' | '(')),
+        Some(start) if matches!(&line[start..=start], "|" | "{")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::it_in_scope;
+
+    #[test]
+    fn it_is_in_scope_after_a_pipe_or_in_a_block() {
+        assert!(it_in_scope("ls | echo $", 11));
+        assert!(it_in_scope("ls | each { echo $", 18));
+    }
+
+    #[test]
+    fn it_is_not_in_scope_at_the_start_of_a_pipeline() {
+        assert!(!it_in_scope("echo $", 6));
+        assert!(!it_in_scope("ls | get name; echo $", 21));
+        assert!(!it_in_scope("echo $(echo $", 13));
+    }
+}
@@ -73,6 +73,18 @@ impl EnvironmentSyncer {
         auto
     }
 
+    /// Hands the config settings the parser needs over to the scope, so that parsing never has
+    /// to read the config itself
+    pub fn sync_scope_settings(&mut self, ctx: &mut EvaluationContext) {
+        let config = self.config.lock();
+
+        ctx.scope.set_case_insensitive_commands(
+            config
+                .var("case_insensitive_commands")
+                .map_or(false, |val| val.is_true()),
+        );
+    }
+
     pub fn sync_env_vars(&mut self, ctx: &mut EvaluationContext) {
         let mut environment = self.env.lock();
 
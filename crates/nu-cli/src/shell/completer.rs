@@ -1,16 +1,25 @@
+use crate::completion::column::{self, ColumnCompleter};
 use crate::completion::command::CommandCompleter;
 use crate::completion::flag::FlagCompleter;
 use crate::completion::matchers;
 use crate::completion::matchers::Matcher;
 use crate::completion::path::{PathCompleter, PathSuggestion};
+use crate::completion::variable::{self, VariableCompleter};
 use crate::completion::{self, Completer, Suggestion};
 use nu_engine::EvaluationContext;
 use nu_parser::ParserScope;
-use nu_source::Tag;
+use nu_protocol::Value;
+use nu_source::{SpannedItem, Tag};
+use parking_lot::Mutex;
 
 use std::borrow::Cow;
 
-pub(crate) struct NuCompleter {}
+#[derive(Default)]
+pub(crate) struct NuCompleter {
+    /// The values given by the last dry run, along with the pipeline that gave them, so that
+    /// completing columns again on the same line doesn't run it again
+    column_cache: Mutex<Option<(String, Vec<Value>)>>,
+}
 
 impl NuCompleter {}
 
@@ -26,7 +35,7 @@ impl NuCompleter {
         let nu_context: &EvaluationContext = context.as_ref();
 
         nu_context.scope.enter_scope();
-        let (block, _) = nu_parser::parse(line, 0, &nu_context.scope);
+        let (block, _) = nu_parser::parse_partial(line, 0, &nu_context.scope);
         nu_context.scope.exit_scope();
 
         let locations = completion::engine::completion_location(line, &block, pos);
@@ -44,17 +53,21 @@ impl NuCompleter {
         let matcher = matcher.as_str();
         let matcher: &dyn Matcher = match matcher {
             "case-insensitive" => &matchers::case_insensitive::Matcher,
+            "fuzzy" => &matchers::fuzzy::Matcher,
             _ => &matchers::case_sensitive::Matcher,
         };
 
         if locations.is_empty() {
             (pos, Vec::new())
         } else {
-            let pos = locations[0].span.start();
+            let start = locations[0].span.start();
             let suggestions = locations
                 .into_iter()
                 .flat_map(|location| {
-                    let partial = location.span.slice(line);
+                    // Only what's before the cursor is being completed. This matters most in an
+                    // unterminated string, which runs on to the end of the line.
+                    let end = location.span.end().min(pos).max(location.span.start());
+                    let partial = &line[location.span.start()..end];
                     match location.item {
                         LocationType::Command => {
                             let command_completer = CommandCompleter;
@@ -62,57 +75,137 @@ impl NuCompleter {
                         }
 
                         LocationType::Flag(cmd) => {
-                            let flag_completer = FlagCompleter { cmd };
+                            let used = completion::engine::flags_in_use(&block, location.span);
+                            let flag_completer = FlagCompleter { cmd, used };
                             flag_completer.complete(context, partial, matcher.to_owned())
                         }
 
                         LocationType::Argument(cmd, _arg_name) => {
                             let path_completer = PathCompleter;
 
-                            const QUOTE_CHARS: &[char] = &['\'', '"', '`'];
-
-                            // TODO Find a better way to deal with quote chars. Can the completion
-                            //      engine relay this back to us? Maybe have two spans: inner and
-                            //      outer. The former is what we want to complete, the latter what
-                            //      we'd need to replace.
-                            let (quote_char, partial) = if partial.starts_with(QUOTE_CHARS) {
-                                let (head, tail) = partial.split_at(1);
-                                (Some(head), tail)
-                            } else {
-                                (None, partial)
+                            // Right after a command name the argument may just as well be the
+                            // start of one of its subcommands
+                            let subcommands = match &cmd {
+                                Some(cmd)
+                                    if !partial.starts_with(QUOTE_CHARS)
+                                        && line[..location.span.start()]
+                                            .trim_end()
+                                            .ends_with(cmd.as_str()) =>
+                                {
+                                    CommandCompleter
+                                        .complete_subcommand(context, cmd, partial, matcher)
+                                }
+                                _ => Vec::new(),
                             };
 
-                            let partial = if let Some(quote_char) = quote_char {
-                                if partial.ends_with(quote_char) {
-                                    &partial[..partial.len() - 1]
-                                } else {
-                                    partial
+                            let (quote, partial) = unquote(partial);
+                            let partial = partial.as_str();
+
+                            if let Some(cmd) = &cmd {
+                                if column::COLUMN_COMMANDS.contains(&cmd.as_str()) {
+                                    let columns = self
+                                        .column_values(line, &block, location.span, nu_context)
+                                        .map(|values| {
+                                            ColumnCompleter { values: &values }
+                                                .complete(context, partial, matcher)
+                                        })
+                                        .unwrap_or_default();
+
+                                    if !columns.is_empty() {
+                                        return columns
+                                            .into_iter()
+                                            .map(|s| Suggestion {
+                                                replacement: quote_like(quote, s.replacement),
+                                                display: s.display,
+                                            })
+                                            .collect();
+                                    }
                                 }
-                            } else {
-                                partial
-                            };
+                            }
 
                             let completed_paths = path_completer.path_suggestions(partial, matcher);
-                            match cmd.as_deref().unwrap_or("") {
+                            let paths = match cmd.as_deref().unwrap_or("") {
                                 "cd" => select_directory_suggestions(completed_paths),
                                 _ => completed_paths,
                             }
                             .into_iter()
                             .map(|s| Suggestion {
-                                replacement: requote(s.suggestion.replacement),
+                                replacement: quote_like(quote, s.suggestion.replacement),
                                 display: s.suggestion.display,
-                            })
-                            .collect()
+                            });
+
+                            subcommands.into_iter().chain(paths).collect()
                         }
 
-                        LocationType::Variable => Vec::new(),
+                        LocationType::Variable => match partial.find('.') {
+                            // A path on a variable completes the columns of its value
+                            Some(dot) => {
+                                let (variable, path) = (&partial[..dot], &partial[dot + 1..]);
+                                let values = if variable == "$it" {
+                                    self.column_values(line, &block, location.span, nu_context)
+                                } else {
+                                    column::dry_run(format!("echo {}", variable), nu_context)
+                                };
+
+                                values
+                                    .map(|values| {
+                                        ColumnCompleter { values: &values }
+                                            .complete(context, path, matcher)
+                                    })
+                                    .unwrap_or_default()
+                                    .into_iter()
+                                    .map(|s| Suggestion {
+                                        replacement: format!("{}.{}", variable, s.replacement),
+                                        display: s.display,
+                                    })
+                                    .collect()
+                            }
+                            None => {
+                                let variable_completer = VariableCompleter {
+                                    it_in_scope: variable::it_in_scope(line, location.span.start()),
+                                };
+                                variable_completer.complete(context, partial, matcher)
+                            }
+                        },
                     }
                 })
                 .collect();
 
-            (pos, suggestions)
+            (start, suggestions)
         }
     }
+
+    /// The values coming into the command at `location`, found by running the pipeline up to it
+    fn column_values(
+        &self,
+        line: &str,
+        block: &nu_protocol::hir::Block,
+        location: nu_source::Span,
+        context: &EvaluationContext,
+    ) -> Option<Vec<Value>> {
+        let enabled = nu_data::config::config(Tag::unknown())
+            .ok()
+            .and_then(|cfg| cfg.get("complete_columns").map(|v| v.is_true()))
+            .unwrap_or(true);
+
+        if !enabled {
+            return None;
+        }
+
+        let source = column::upstream_source(line, block, location, context)?;
+
+        let mut cache = self.column_cache.lock();
+        if let Some((cached_source, values)) = &*cache {
+            if *cached_source == source {
+                return Some(values.clone());
+            }
+        }
+
+        let values = column::dry_run(source.clone(), context)?;
+        *cache = Some((source, values.clone()));
+
+        Some(values)
+    }
 }
 
 fn select_directory_suggestions(completed_paths: Vec<PathSuggestion>) -> Vec<PathSuggestion> {
@@ -128,6 +221,45 @@ fn select_directory_suggestions(completed_paths: Vec<PathSuggestion>) -> Vec<Pat
         .collect()
 }
 
+const QUOTE_CHARS: &[char] = &['\'', '"', '`'];
+
+/// Takes the quotes off of a (possibly unterminated) quoted partial, decoding the escapes of a
+/// double-quoted one, so it can be matched against what it stands for. Also gives the quote used.
+fn unquote(partial: &str) -> (Option<char>, String) {
+    let quote = match partial.chars().next() {
+        Some(quote) if QUOTE_CHARS.contains(&quote) => quote,
+        _ => return (None, partial.to_string()),
+    };
+
+    let inner = &partial[quote.len_utf8()..];
+    let inner = match inner.strip_suffix(quote) {
+        // An escaped quote (one after an odd number of backslashes) doesn't end a double-quoted
+        // string
+        Some(rest) if quote != '"' || (rest.len() - rest.trim_end_matches('\\').len()) % 2 == 0 => {
+            rest
+        }
+        _ => inner,
+    };
+
+    let content = if quote == '"' {
+        nu_parser::unescape_string(&format!("\"{}\"", inner).spanned_unknown())
+            .unwrap_or_else(|_| inner.to_string())
+    } else {
+        inner.to_string()
+    };
+
+    (Some(quote), content)
+}
+
+/// Quotes a completion the same way the partial it completes was quoted, closing the quote
+fn quote_like(quote: Option<char>, value: String) -> String {
+    match quote {
+        Some('"') => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+        Some(quote) if !value.contains(quote) => format!("{0}{1}{0}", quote, value),
+        _ => requote(value),
+    }
+}
+
 fn requote(orig_value: String) -> String {
     let value: Cow<str> = rustyline::completion::unescape(&orig_value, Some('\\'));
 
@@ -155,3 +287,103 @@ fn requote(orig_value: String) -> String {
         value.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{quote_like, unquote, NuCompleter};
+    use crate::completion::CompletionContext;
+    use crate::create_default_context;
+
+    fn complete(line: &str) -> Vec<String> {
+        let context = create_default_context(false).expect("could not create a context");
+        let (_, suggestions) =
+            NuCompleter::default().complete(line, line.len(), &CompletionContext::new(&context));
+
+        suggestions
+            .into_iter()
+            .map(|suggestion| suggestion.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn completes_columns_of_the_upstream_output() {
+        assert_eq!(complete("open Cargo.toml | select pack"), vec!["package"]);
+        assert_eq!(complete("open Cargo.toml | where pack"), vec!["package"]);
+    }
+
+    #[test]
+    fn completes_nested_columns_of_the_upstream_output() {
+        assert_eq!(
+            complete("open Cargo.toml | get package.vers"),
+            vec!["package.version"]
+        );
+    }
+
+    #[test]
+    fn completes_variables() {
+        assert_eq!(complete("echo $tr"), vec!["$true"]);
+        assert_eq!(complete("ls | echo $i"), vec!["$it"]);
+        assert!(complete("echo $i").is_empty());
+    }
+
+    #[test]
+    fn completes_paths_on_variables() {
+        assert_eq!(
+            complete("open Cargo.toml | where $it.pack"),
+            vec!["$it.package"]
+        );
+        assert_eq!(
+            complete("open Cargo.toml | each { echo $it.pack"),
+            vec!["$it.package"]
+        );
+        assert_eq!(complete("echo $nu.hist"), vec!["$nu.history-path"]);
+    }
+
+    #[test]
+    fn completes_inside_of_open_quotes() {
+        assert_eq!(complete("open \"Cargo.t"), vec!["\"Cargo.toml\""]);
+        assert_eq!(complete("open 'Cargo.t"), vec!["'Cargo.toml'"]);
+        assert_eq!(complete("open `Cargo.t"), vec!["`Cargo.toml`"]);
+        assert_eq!(
+            complete("open Cargo.toml | get \"pack"),
+            vec!["\"package\""]
+        );
+    }
+
+    #[test]
+    fn completes_up_to_the_cursor_in_an_unterminated_string() {
+        let line = "open \"Cargo.t | lines";
+        let context = create_default_context(false).expect("could not create a context");
+        let (start, suggestions) =
+            NuCompleter::default().complete(line, 13, &CompletionContext::new(&context));
+
+        assert_eq!(start, 5);
+        assert_eq!(
+            suggestions
+                .into_iter()
+                .map(|suggestion| suggestion.replacement)
+                .collect::<Vec<_>>(),
+            vec!["\"Cargo.toml\""]
+        );
+    }
+
+    #[test]
+    fn decodes_and_encodes_escapes_in_double_quotes() {
+        assert_eq!(
+            unquote(r#""say \"hi"#),
+            (Some('"'), r#"say "hi"#.to_string())
+        );
+        assert_eq!(unquote(r#""done""#), (Some('"'), "done".to_string()));
+        assert_eq!(unquote(r#""ends \""#), (Some('"'), r#"ends ""#.to_string()));
+        assert_eq!(
+            quote_like(Some('"'), r#"say "hi\there"#.to_string()),
+            r#""say \"hi\\there""#
+        );
+        assert_eq!(quote_like(Some('\''), "it's".to_string()), "\"it's\"");
+    }
+
+    #[test]
+    fn does_not_run_commands_that_are_not_known_to_be_harmless() {
+        assert!(complete("open Cargo.toml | save x.toml | select pack").is_empty());
+    }
+}
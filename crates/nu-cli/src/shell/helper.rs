@@ -18,7 +18,7 @@ impl Helper {
         hinter: Option<rustyline::hint::HistoryHinter>,
     ) -> Helper {
         Helper {
-            completer: NuCompleter {},
+            completer: NuCompleter::default(),
             hinter,
             context,
             colored_prompt: String::new(),
@@ -114,7 +114,9 @@ impl rustyline::validate::Validator for NuValidator {
 
         let (tokens, err) = nu_parser::lex(src, 0);
         if let Some(err) = err {
-            if let nu_errors::ParseErrorReason::Eof { .. } = err.reason() {
+            if let nu_errors::ParseErrorReason::Eof { .. }
+            | nu_errors::ParseErrorReason::Unclosed { .. } = err.reason()
+            {
                 return Ok(rustyline::validate::ValidationResult::Incomplete);
             }
         }
@@ -232,7 +232,9 @@ fn get_result_shape_of(
         | Operator::LessThanOrEqual
         | Operator::GreaterThanOrEqual
         | Operator::Contains
-        | Operator::NotContains => {
+        | Operator::NotContains
+        | Operator::Matches
+        | Operator::NotMatches => {
             //TODO introduce syntaxshape boolean
             SyntaxShape::Int
         }
@@ -283,6 +285,7 @@ fn get_shape_of_expr(expr: &SpannedExpression) -> Option<SyntaxShape> {
                 nu_protocol::hir::Literal::Operator(_) => Some(SyntaxShape::Operator),
                 nu_protocol::hir::Literal::ColumnPath(_) => Some(SyntaxShape::ColumnPath),
                 nu_protocol::hir::Literal::Bare(_) => Some(SyntaxShape::String),
+                nu_protocol::hir::Literal::Date(_) => Some(SyntaxShape::Any),
             }
         }
         //Synthetic are expressions that are generated by the parser and not inputed by the user
@@ -291,6 +294,7 @@ fn get_shape_of_expr(expr: &SpannedExpression) -> Option<SyntaxShape> {
         Expression::Synthetic(_) => Some(SyntaxShape::String),
 
         Expression::Binary(_) => Some(SyntaxShape::RowCondition),
+        Expression::Not(_) => Some(SyntaxShape::RowCondition),
         Expression::Range(_) => Some(SyntaxShape::Range),
         Expression::List(_) => Some(SyntaxShape::Table),
         Expression::Boolean(_) => Some(SyntaxShape::String),
@@ -537,6 +541,10 @@ impl VarSyntaxShapeDeductor {
                 trace!("Infering vars in bin expr");
                 self.infer_shapes_in_binary_expr((pipeline_idx, pipeline), spanned_expr, scope)?;
             }
+            Expression::Not(expr) => {
+                trace!("Infering vars in not expr");
+                self.infer_shapes_in_expr((pipeline_idx, pipeline), expr, scope)?;
+            }
             Expression::Block(b) => {
                 trace!("Infering vars in block");
                 self.infer_shape(&b, scope)?;
@@ -751,7 +759,10 @@ impl VarSyntaxShapeDeductor {
                         VarShapeDeduction::from_usage_with_alternatives(&var.span, &shapes),
                     )?;
                 }
-                Operator::Contains | Operator::NotContains => {
+                Operator::Contains
+                | Operator::NotContains
+                | Operator::Matches
+                | Operator::NotMatches => {
                     self.checked_insert(
                         var,
                         vec![VarShapeDeduction::from_usage(
@@ -790,6 +801,7 @@ impl VarSyntaxShapeDeductor {
                         | Expression::Synthetic(_)
                         | Expression::Variable(_, _)
                         | Expression::Binary(_)
+                        | Expression::Not(_)
                         | Expression::Range(_)
                         | Expression::Block(_)
                         | Expression::Path(_)
@@ -4,9 +4,11 @@ pub(crate) mod macros;
 mod from_delimited_data;
 mod to_delimited_data;
 
+pub(crate) mod alias;
 pub(crate) mod ansi;
 pub(crate) mod append;
 pub(crate) mod args;
+pub(crate) mod ast;
 pub mod autoenv;
 pub(crate) mod autoenv_trust;
 pub(crate) mod autoenv_untrust;
@@ -39,10 +41,12 @@ pub(crate) mod each;
 pub(crate) mod echo;
 pub(crate) mod empty;
 pub(crate) mod enter;
+pub(crate) mod enumerate;
 pub(crate) mod every;
 pub(crate) mod exec;
 pub(crate) mod exit;
 pub(crate) mod first;
+pub(crate) mod first_bytes;
 pub(crate) mod flatten;
 pub(crate) mod format;
 pub(crate) mod from;
@@ -109,6 +113,7 @@ pub(crate) mod shells;
 pub(crate) mod shuffle;
 pub(crate) mod size;
 pub(crate) mod skip;
+pub(crate) mod skip_bytes;
 pub(crate) mod sleep;
 pub(crate) mod sort_by;
 pub(crate) mod source;
@@ -121,6 +126,7 @@ pub(crate) mod termsize;
 pub(crate) mod to;
 pub(crate) mod to_csv;
 pub(crate) mod to_html;
+pub(crate) mod to_ini;
 pub(crate) mod to_json;
 pub(crate) mod to_md;
 pub(crate) mod to_toml;
@@ -140,9 +146,11 @@ pub(crate) mod wrap;
 pub(crate) use autoview::Autoview;
 pub(crate) use cd::Cd;
 
+pub(crate) use alias::Alias;
 pub(crate) use ansi::Ansi;
 pub(crate) use ansi::AnsiStrip;
 pub(crate) use append::Command as Append;
+pub(crate) use ast::Ast;
 pub(crate) use autoenv::Autoenv;
 pub(crate) use autoenv_trust::AutoenvTrust;
 pub(crate) use autoenv_untrust::AutoenvUnTrust;
@@ -179,10 +187,12 @@ pub(crate) mod clear;
 pub(crate) use clear::Clear;
 pub(crate) mod touch;
 pub(crate) use enter::Enter;
+pub(crate) use enumerate::Enumerate;
 pub(crate) use every::Every;
 pub(crate) use exec::Exec;
 pub(crate) use exit::Exit;
 pub(crate) use first::First;
+pub(crate) use first_bytes::FirstBytes;
 pub(crate) use flatten::Command as Flatten;
 pub(crate) use format::{FileSize, Format};
 pub(crate) use from::From;
@@ -258,6 +268,7 @@ pub(crate) use shells::Shells;
 pub(crate) use shuffle::Shuffle;
 pub(crate) use size::Size;
 pub(crate) use skip::{Skip, SkipUntil, SkipWhile};
+pub(crate) use skip_bytes::SkipBytes;
 pub(crate) use sleep::Sleep;
 pub(crate) use sort_by::SortBy;
 pub(crate) use source::Source;
@@ -275,6 +286,7 @@ pub(crate) use termsize::TermSize;
 pub(crate) use to::To;
 pub(crate) use to_csv::ToCSV;
 pub(crate) use to_html::ToHTML;
+pub(crate) use to_ini::ToINI;
 pub(crate) use to_json::ToJSON;
 pub(crate) use to_md::Command as ToMarkdown;
 pub(crate) use to_toml::ToTOML;
@@ -294,7 +306,8 @@ pub(crate) use wrap::Wrap;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::examples::{test_anchors, test_examples};
+    use crate::commands::default_context::create_default_context;
+    use crate::examples::{test_anchors, test_examples, test_examples_parse};
     use nu_engine::{whole_stream_command, Command};
     use nu_errors::ShellError;
 
@@ -355,6 +368,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn examples_of_every_command_parse() -> Result<(), ShellError> {
+        let context = create_default_context(false)
+            .map_err(|err| ShellError::untagged_runtime_error(err.to_string()))?;
+
+        test_examples_parse(&context)
+    }
+
     #[test]
     fn tracks_metadata() -> Result<(), ShellError> {
         for cmd in full_tests() {
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder};
+
+pub struct Alias;
+
+#[async_trait]
+impl WholeStreamCommand for Alias {
+    fn name(&self) -> &str {
+        "alias"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("alias")
+            .optional("name", SyntaxShape::String, "the name of the alias")
+            .optional("equals", SyntaxShape::String, "the equals sign")
+            .rest(SyntaxShape::String, "the pipeline the alias expands to")
+    }
+
+    fn usage(&self) -> &str {
+        "Define a shortcut for a pipeline, or list the aliases that are defined."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        // Definitions are installed by the parser, so the only thing left to do here is listing
+        // the aliases when none was given
+        let tag = args.call_info.name_tag.clone();
+
+        let aliases = args
+            .scope
+            .get_aliases()
+            .into_iter()
+            .map(move |(name, expansion)| {
+                let expansion = expansion
+                    .into_iter()
+                    .map(|part| part.item)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let mut dict = TaggedDictBuilder::new(&tag);
+                dict.insert_untagged("name", name);
+                dict.insert_untagged("expansion", expansion);
+                ReturnSuccess::value(dict.into_value())
+            });
+
+        Ok(futures::stream::iter(aliases).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Define a shortcut for listing files by size",
+                example: "alias lss = ls | sort-by size",
+                result: None,
+            },
+            Example {
+                description: "List the aliases that are defined",
+                example: "alias",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Alias;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Alias {})
+    }
+}
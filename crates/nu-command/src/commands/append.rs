@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::columns::{spread, unify_columns};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
@@ -25,33 +26,24 @@ impl WholeStreamCommand for Command {
     }
 
     fn usage(&self) -> &str {
-        "Append a row to the table."
+        "Append rows to the table, giving every row the columns of the others."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        let (Arguments { mut value }, input) = args.process().await?;
+        let (Arguments { value }, input) = args.process().await?;
 
         let input: Vec<Value> = input.collect().await;
 
+        let mut rows = spread(value);
         if let Some(first) = input.get(0) {
-            value.tag = first.tag();
-        }
-
-        // Checks if we are trying to append a row literal
-        if let Value {
-            value: UntaggedValue::Table(values),
-            tag,
-        } = &value
-        {
-            if values.len() == 1 && values[0].is_row() {
-                value = values[0].value.clone().into_value(tag);
+            for row in rows.iter_mut() {
+                row.tag = first.tag();
             }
         }
 
         Ok(futures::stream::iter(
-            input
+            unify_columns(input.into_iter().chain(rows).collect())
                 .into_iter()
-                .chain(vec![value])
                 .map(ReturnSuccess::value),
         )
         .to_output_stream())
@@ -80,6 +72,15 @@ impl WholeStreamCommand for Command {
                     row! { "country".into() => Value::from("USA")},
                 ]),
             },
+            Example {
+                description:
+                    "Add rows having other columns, leaving the cells they are missing empty",
+                example: "echo [[name]; [nu]] | append [[name, stars]; [bat, 5]]",
+                result: Some(vec![
+                    row! { "name".into() => Value::from("nu"), "stars".into() => UntaggedValue::nothing().into()},
+                    row! { "name".into() => Value::from("bat"), "stars".into() => UntaggedValue::int(5).into()},
+                ]),
+            },
         ]
     }
 }
@@ -0,0 +1,103 @@
+use crate::commands::from_json::convert_json_value_to_nu_value;
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_source::Tagged;
+
+pub struct Ast;
+
+#[derive(Deserialize)]
+pub struct AstArgs {
+    pipeline: Tagged<String>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Ast {
+    fn name(&self) -> &str {
+        "ast"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ast").required(
+            "pipeline",
+            SyntaxShape::String,
+            "the pipeline to print the syntax tree of",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Parses the given pipeline, without running it, and prints its syntax tree."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        ast(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show how a pipeline parses",
+            example: "ast 'ls | where size > 10kb'",
+            result: None,
+        }]
+    }
+}
+
+async fn ast(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let scope = args.scope.clone();
+    let (AstArgs { pipeline }, _) = args.process().await?;
+
+    // Spans in the syntax tree (and in any parse error) should point into the command line
+    // itself, which is only possible when the pipeline was given as a literal string.
+    let span = pipeline.tag.span;
+    let span_offset = match span.end() - span.start() {
+        len if len == pipeline.item.len() => Some(span.start()),
+        len if len == pipeline.item.len() + 2 => Some(span.start() + 1),
+        _ => None,
+    };
+
+    scope.enter_scope();
+    let (block, err) = nu_parser::parse(&pipeline.item, span_offset.unwrap_or(0), &scope);
+    scope.exit_scope();
+
+    if let Some(err) = err {
+        let err = ShellError::from(err);
+
+        return Err(match (span_offset, err.clone().into_diagnostic()) {
+            (None, Some(mut diagnostic)) => {
+                // The spans can't be mapped back onto the command line, so point at the
+                // whole pipeline instead
+                for label in diagnostic.labels.iter_mut() {
+                    label.range = span.start()..span.end();
+                }
+                ShellError::diagnostic(diagnostic)
+            }
+            _ => err,
+        });
+    }
+
+    let tree = nu_json::to_value(&block.block).map_err(|err| {
+        ShellError::labeled_error(
+            "Could not print the syntax tree",
+            err.to_string(),
+            &pipeline.tag,
+        )
+    })?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        convert_json_value_to_nu_value(&tree, &pipeline.tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ast;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Ast {})
+    }
+}
@@ -86,6 +86,7 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
     let table = context.get_command("table");
 
     let pivot_mode = configuration.pivot_mode();
+    let hex_dump_rows = configuration.hex_dump_rows();
 
     let (mut input_stream, context) = RunnableContextWithoutInput::convert(context);
     let term_width = context.host.lock().width();
@@ -206,8 +207,7 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
                             let result = binary.run(command_args).await?;
                             result.collect::<Vec<_>>().await;
                         } else {
-                            use pretty_hex::*;
-                            out!("{:?}", b.hex_dump());
+                            out!("{}", hex_dump(b, hex_dump_rows));
                         }
                     }
 
@@ -283,6 +283,25 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
     Ok(OutputStream::empty())
 }
 
+/// Shows binary data as rows of offsets, hex bytes and their ASCII, up to the given number of
+/// rows, with a footer telling how many bytes were left out
+fn hex_dump(bytes: &[u8], rows: usize) -> String {
+    use pretty_hex::*;
+
+    let config = HexConfig {
+        title: false,
+        ..HexConfig::default()
+    };
+    let shown = bytes.len().min(rows * config.width);
+
+    let mut output = config_hex(&&bytes[..shown], config);
+    if shown < bytes.len() {
+        output.push_str(&format!("\n… {} more bytes", bytes.len() - shown));
+    }
+
+    output
+}
+
 fn create_default_command_args(context: &RunnableContextWithoutInput) -> RawCommandArgs {
     let span = context.name.span;
     RawCommandArgs {
@@ -25,6 +25,7 @@ impl AutoPivotMode {
 
 pub trait ConfigExtensions: Debug + Send {
     fn pivot_mode(&self) -> AutoPivotMode;
+    fn hex_dump_rows(&self) -> usize;
 }
 
 pub fn pivot_mode(config: &NuConfig) -> AutoPivotMode {
@@ -44,8 +45,21 @@ pub fn pivot_mode(config: &NuConfig) -> AutoPivotMode {
     AutoPivotMode::Never
 }
 
+pub fn hex_dump_rows(config: &NuConfig) -> usize {
+    config
+        .vars
+        .get("hex_dump_rows")
+        .and_then(|rows| rows.as_u64().ok())
+        .map(|rows| rows as usize)
+        .unwrap_or(32)
+}
+
 impl ConfigExtensions for NuConfig {
     fn pivot_mode(&self) -> AutoPivotMode {
         pivot_mode(self)
     }
+
+    fn hex_dump_rows(&self) -> usize {
+        hex_dump_rows(self)
+    }
 }
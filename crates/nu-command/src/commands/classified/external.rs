@@ -16,7 +16,7 @@ use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::hir::Expression;
 use nu_protocol::hir::{ExternalCommand, ExternalRedirection};
-use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_protocol::{closest_match, Primitive, ShellTypeName, UntaggedValue, Value};
 use nu_source::Tag;
 use nu_stream::trace_stream;
 
@@ -29,9 +29,14 @@ pub(crate) async fn run_external_command(
     trace!(target: "nu::run::external", "-> {}", command.name);
 
     if !did_find_command(&command.name) {
+        let label = match closest_match(context.scope.get_command_names(), &command.name) {
+            Some(suggestion) => format!("command not found, did you mean '{}'?", suggestion),
+            None => "command not found".to_string(),
+        };
+
         return Err(ShellError::labeled_error(
             "Command not found",
-            "command not found",
+            label,
             &command.name_tag,
         ));
     }
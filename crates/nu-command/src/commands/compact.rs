@@ -3,14 +3,14 @@ use futures::future;
 use futures::stream::StreamExt;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::Tagged;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_value_ext::ValueExt;
 
 pub struct Compact;
 
 #[derive(Deserialize)]
 pub struct CompactArgs {
-    rest: Vec<Tagged<String>>,
+    rest: Vec<ColumnPath>,
 }
 
 #[async_trait]
@@ -20,7 +20,10 @@ impl WholeStreamCommand for Compact {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("compact").rest(SyntaxShape::Any, "the columns to compact from the table")
+        Signature::build("compact").rest(
+            SyntaxShape::ColumnPath,
+            "the columns, or paths to nested ones, that must not be empty",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -32,11 +35,18 @@ impl WholeStreamCommand for Compact {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Filter out all directory entries having no 'target'",
-            example: "ls -la | compact target",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Filter out all directory entries having no 'target'",
+                example: "ls -la | compact target",
+                result: None,
+            },
+            Example {
+                description: "Filter out the rows missing a nested column",
+                example: "echo [[name, config]; [nu, $(echo [[retries]; [3]])] [bat, '']] | compact config.retries | get name",
+                result: Some(vec![Value::from("nu")]),
+            },
+        ]
     }
 }
 
@@ -50,23 +60,20 @@ pub async fn compact(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 } else {
                     None
                 }
-            } else {
-                match item {
-                    Value {
-                        value: UntaggedValue::Row(ref r),
-                        ..
-                    } => {
-                        if columns
-                            .iter()
-                            .all(|field| r.get_data(field).borrow().is_some())
-                        {
-                            Some(ReturnSuccess::value(item))
-                        } else {
-                            None
-                        }
+            } else if item.is_row() {
+                // A column that's missing is as empty as one holding nothing
+                if columns.iter().all(|path| {
+                    match item.get_data_by_column_path(path, Box::new(move |_, _, error| error)) {
+                        Ok(cell) => !cell.is_empty(),
+                        Err(_) => false,
                     }
-                    _ => None,
+                }) {
+                    Some(ReturnSuccess::value(item))
+                } else {
+                    None
                 }
+            } else {
+                None
             })
         })
         .to_output_stream())
@@ -31,4 +31,19 @@ impl WholeStreamCommand for Command {
         )])
         .to_output_stream())
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show all the settings in the config",
+                example: "config",
+                result: None,
+            },
+            Example {
+                description: "Show the settings as a table of names and values",
+                example: "config | pivot",
+                result: None,
+            },
+        ]
+    }
 }
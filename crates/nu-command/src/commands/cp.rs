@@ -20,6 +20,11 @@ impl WholeStreamCommand for Cpy {
                 "copy recursively through subdirectories",
                 Some('r'),
             )
+            .switch(
+                "force",
+                "overwrite anything already at the destination",
+                Some('f'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -45,6 +50,11 @@ impl WholeStreamCommand for Cpy {
                 example: "cp -r dir_a dir_b",
                 result: None,
             },
+            Example {
+                description: "Copy all text files to dir_b, replacing the ones already there",
+                example: "cp --force *.txt dir_b",
+                result: None,
+            },
         ]
     }
 }
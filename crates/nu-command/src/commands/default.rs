@@ -1,13 +1,12 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::Tagged;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_value_ext::ValueExt;
 
 #[derive(Deserialize)]
 struct DefaultArgs {
-    column: Tagged<String>,
+    column: ColumnPath,
     value: Value,
 }
 
@@ -21,7 +20,11 @@ impl WholeStreamCommand for Default {
 
     fn signature(&self) -> Signature {
         Signature::build("default")
-            .required("column name", SyntaxShape::String, "the name of the column")
+            .required(
+                "column name",
+                SyntaxShape::ColumnPath,
+                "the name of the column, or the path to a nested one",
+            )
             .required(
                 "column value",
                 SyntaxShape::Any,
@@ -38,11 +41,18 @@ impl WholeStreamCommand for Default {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Give a default 'target' to all file entries",
-            example: "ls -la | default target 'nothing'",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Give a default 'target' to all file entries",
+                example: "ls -la | default target 'nothing'",
+                result: None,
+            },
+            Example {
+                description: "Fill in a nested column, keeping the values that are there",
+                example: "echo [[name, config]; [nu, $(echo [[retries]; [3]])]] | default config.retries 0 | get config.retries",
+                result: Some(vec![UntaggedValue::int(3).into()]),
+            },
+        ]
     }
 }
 
@@ -51,18 +61,21 @@ async fn default(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     Ok(input
         .map(move |item| {
-            let should_add = matches!(
-                item,
-                Value {
-                    value: UntaggedValue::Row(ref r),
-                    ..
-                } if r.get_data(&column.item).borrow().is_none()
-            );
+            if !item.is_row() {
+                return ReturnSuccess::value(item);
+            }
+
+            // Only a missing or empty cell gets the default, the values present are left alone
+            let should_add =
+                match item.get_data_by_column_path(&column, Box::new(move |_, _, error| error)) {
+                    Ok(cell) => cell.is_none(),
+                    Err(_) => true,
+                };
 
             if should_add {
-                match item.insert_data_at_path(&column.item, value.clone()) {
-                    Some(new_value) => ReturnSuccess::value(new_value),
-                    None => ReturnSuccess::value(item),
+                match item.forgiving_insert_data_at_column_path(&column, value.clone()) {
+                    Ok(new_value) => ReturnSuccess::value(new_value),
+                    Err(_) => ReturnSuccess::value(item),
                 }
             } else {
                 ReturnSuccess::value(item)
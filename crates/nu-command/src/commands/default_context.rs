@@ -15,6 +15,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Let),
             whole_stream_command(LetEnv),
             whole_stream_command(Def),
+            whole_stream_command(Alias),
             whole_stream_command(Source),
             // System/file operations
             whole_stream_command(Exec),
@@ -52,6 +53,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Describe),
             whole_stream_command(Which),
             whole_stream_command(Debug),
+            whole_stream_command(Ast),
             whole_stream_command(WithEnv),
             whole_stream_command(Do),
             whole_stream_command(Sleep),
@@ -113,6 +115,9 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(Ansi),
             whole_stream_command(AnsiStrip),
             whole_stream_command(Char),
+            // Binary manipulation
+            whole_stream_command(FirstBytes),
+            whole_stream_command(SkipBytes),
             // Column manipulation
             whole_stream_command(DropColumn),
             whole_stream_command(Move),
@@ -133,6 +138,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(First),
             whole_stream_command(Last),
             whole_stream_command(Every),
+            whole_stream_command(Enumerate),
             whole_stream_command(Nth),
             whole_stream_command(Drop),
             whole_stream_command(Format),
@@ -192,6 +198,7 @@ pub fn create_default_context(interactive: bool) -> Result<EvaluationContext, Bo
             whole_stream_command(To),
             whole_stream_command(ToCSV),
             whole_stream_command(ToHTML),
+            whole_stream_command(ToINI),
             whole_stream_command(ToJSON),
             whole_stream_command(ToMarkdown),
             whole_stream_command(ToTOML),
@@ -5,7 +5,8 @@ use nu_engine::WholeStreamCommand;
 use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::{
-    hir::CapturedBlock, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+    hir::CapturedBlock, ReturnSuccess, ReturnValue, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
 };
 use nu_source::Tagged;
 
@@ -115,40 +116,45 @@ async fn each(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let (each_args, input): (EachArgs, _) = raw_args.process().await?;
     let block = Arc::new(Box::new(each_args.block));
-
-    if each_args.numbered.item {
-        Ok(input
-            .enumerate()
-            .then(move |input| {
-                let block = block.clone();
-                let context = context.clone();
-                let row = make_indexed_item(input.0, input.1);
-
-                async {
-                    match process_row(block, context, row).await {
-                        Ok(s) => s,
-                        Err(e) => OutputStream::one(Err(e)),
-                    }
-                }
-            })
-            .flatten()
-            .to_output_stream())
-    } else {
-        Ok(input
-            .then(move |input| {
-                let block = block.clone();
-                let context = context.clone();
-
-                async {
-                    match process_row(block, context, input).await {
-                        Ok(s) => s,
-                        Err(e) => OutputStream::one(Err(e)),
-                    }
+    let numbered = each_args.numbered.item;
+
+    Ok(input
+        .enumerate()
+        .then(move |(index, input)| {
+            let block = block.clone();
+            let context = context.clone();
+            let block_span = block.block.span;
+            let row = if numbered {
+                make_indexed_item(index, input)
+            } else {
+                input
+            };
+
+            async move {
+                match process_row(block, context, row).await {
+                    Ok(s) => s
+                        .map(move |result| in_row(result, index, block_span))
+                        .to_output_stream(),
+                    Err(e) => OutputStream::one(in_row(Err(e), index, block_span)),
                 }
-            })
-            .flatten()
-            .to_output_stream())
-    }
+            }
+        })
+        .flatten()
+        .to_output_stream())
+}
+
+/// Points errors the block ran into at the block, naming the row it was running on
+fn in_row(result: ReturnValue, index: usize, block_span: Span) -> ReturnValue {
+    let error = match result {
+        Ok(ReturnSuccess::Value(Value {
+            value: UntaggedValue::Error(error),
+            ..
+        })) => error,
+        Err(error) => error,
+        result => return result,
+    };
+
+    Err(error.with_secondary_label(format!("the block failed on row {}", index), block_span))
 }
 
 #[cfg(test)]
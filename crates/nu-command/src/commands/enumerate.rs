@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use indexmap::indexmap;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+
+const INDEX_COLUMN_NAME: &str = "index";
+
+pub struct Enumerate;
+
+#[derive(Deserialize)]
+struct EnumerateArgs {
+    #[serde(rename = "at-end")]
+    at_end: bool,
+    force: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Enumerate {
+    fn name(&self) -> &str {
+        "enumerate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("enumerate")
+            .switch(
+                "at-end",
+                "add the index as the last column rather than the first",
+                Some('e'),
+            )
+            .switch(
+                "force",
+                "replace an index column the rows already have",
+                Some('f'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Adds an index column counting the rows from 0."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        enumerate(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Number the rows of a table",
+                example: "echo [[name]; [nu] [bat]] | enumerate",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "index".to_string() => UntaggedValue::int(0).into(),
+                        "name".to_string() => Value::from("nu"),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "index".to_string() => UntaggedValue::int(1).into(),
+                        "name".to_string() => Value::from("bat"),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Number a list, keeping its values in an item column",
+                example: "echo [a b] | enumerate --at-end",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "item".to_string() => Value::from("a"),
+                        "index".to_string() => UntaggedValue::int(0).into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "item".to_string() => Value::from("b"),
+                        "index".to_string() => UntaggedValue::int(1).into(),
+                    })
+                    .into(),
+                ]),
+            },
+        ]
+    }
+}
+
+async fn enumerate(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let (EnumerateArgs { at_end, force }, input) = args.process().await?;
+
+    Ok(input
+        .enumerate()
+        .map(move |(index, item)| {
+            let mut row = TaggedDictBuilder::new(item.tag());
+
+            if !at_end {
+                row.insert_untagged(INDEX_COLUMN_NAME, UntaggedValue::int(index));
+            }
+
+            match item.value {
+                UntaggedValue::Row(dict) => {
+                    if dict.contains_key(INDEX_COLUMN_NAME) && !force {
+                        return Err(ShellError::labeled_error_with_secondary(
+                            "The rows already have an index column",
+                            "use --force to replace it",
+                            &name_tag,
+                            format!("row {} has an index", index),
+                            item.tag.span,
+                        ));
+                    }
+
+                    for (column, value) in dict.entries {
+                        if column != INDEX_COLUMN_NAME {
+                            row.insert_value(column, value);
+                        }
+                    }
+                }
+                value => row.insert_value("item", value.into_value(&item.tag)),
+            }
+
+            if at_end {
+                row.insert_untagged(INDEX_COLUMN_NAME, UntaggedValue::int(index));
+            }
+
+            ReturnSuccess::value(row.into_value())
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Enumerate;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(Enumerate {})
+    }
+}
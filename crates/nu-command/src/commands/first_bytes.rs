@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct FirstBytes;
+
+#[derive(Deserialize)]
+pub struct FirstBytesArgs {
+    bytes: Tagged<usize>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FirstBytes {
+    fn name(&self) -> &str {
+        "first-bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("first-bytes").required(
+            "bytes",
+            SyntaxShape::Int,
+            "starting from the front, the number of bytes to keep",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Keep only the first number of bytes of binary data."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        first_bytes(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the first 64 bytes of an image",
+                example: "open image.png --raw | first-bytes 64",
+                result: None,
+            },
+            Example {
+                description: "Show the bytes 512 to 1024 of a file",
+                example: "open sample.db --raw | skip-bytes 512 | first-bytes 512",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn first_bytes(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (FirstBytesArgs { bytes: wanted }, mut input) = args.process().await?;
+
+    let mut bytes = vec![];
+    let mut tag = name.clone();
+
+    // Large files come in chunks, so the bytes are gathered from as many as needed
+    while bytes.len() < *wanted {
+        match input.next().await {
+            Some(value) => match value.value {
+                UntaggedValue::Primitive(Primitive::Binary(b)) => {
+                    let missing = *wanted - bytes.len();
+                    bytes.extend(b.into_iter().take(missing));
+                    tag = value.tag;
+                }
+                other => {
+                    return Err(ShellError::unexpected_input(
+                        "first-bytes".spanned(name.span),
+                        "binary",
+                        other.type_name().spanned(value.tag.span),
+                    ))
+                }
+            },
+            None => break,
+        }
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::binary(bytes).into_value(tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FirstBytes;
+    use super::ShellError;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(FirstBytes {})
+    }
+}
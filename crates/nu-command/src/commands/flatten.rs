@@ -1,9 +1,7 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{
-    Dictionary, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
-};
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct Command;
@@ -11,6 +9,8 @@ pub struct Command;
 #[derive(Deserialize)]
 pub struct Arguments {
     rest: Vec<Tagged<String>>,
+    depth: Option<Tagged<u64>>,
+    explode: bool,
 }
 
 #[async_trait]
@@ -20,7 +20,19 @@ impl WholeStreamCommand for Command {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("flatten").rest(SyntaxShape::String, "optionally flatten data by column")
+        Signature::build("flatten")
+            .rest(SyntaxShape::String, "optionally flatten data by column")
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many levels of nested records to lift into the row (default: all)",
+                Some('d'),
+            )
+            .switch(
+                "explode",
+                "give each value of the first list found a row of its own",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -39,137 +51,63 @@ impl WholeStreamCommand for Command {
                 result: Some(vec![Value::from("N")]),
             },
             Example {
-                description: "flatten a column having a nested table",
-                example: "echo [[origin, people]; [Ecuador, $(echo [[name, meal]; ['Andres', 'arepa']])]] | flatten | get meal",
+                description: "flatten a column having a nested record into dotted column names",
+                example: "echo [[origin, people]; [Ecuador, $(echo [[name, meal]; ['Andres', 'arepa']])]] | flatten | get people.meal",
                 result: Some(vec![Value::from("arepa")]),
             },
             Example {
                 description: "restrict the flattening by passing column names",
                 example: "echo [[origin, crate, versions]; [World, $(echo [[name]; ['nu-cli']]), ['0.21', '0.22']]] | flatten versions | last | get versions",
                 result: Some(vec![Value::from("0.22")]),
-            }
+            },
+            Example {
+                description: "only lift the first level of nested records",
+                example: "echo '{\"a\": {\"b\": {\"c\": 1}}}' | from json | flatten --depth 1",
+                result: None,
+            },
         ]
     }
 }
 
 async fn flatten(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let tag = args.call_info.name_tag.clone();
-    let (Arguments { rest: columns }, input) = args.process().await?;
+    let (
+        Arguments {
+            rest: columns,
+            depth,
+            explode,
+        },
+        input,
+    ) = args.process().await?;
+    let depth = depth.map(|depth| depth.item as usize);
 
     Ok(input
-        .map(move |item| futures::stream::iter(flat_value(&columns, &item, &tag).into_iter()))
+        .map(move |item| {
+            futures::stream::iter(flat_value(&columns, &item, depth, explode, &tag).into_iter())
+        })
         .flatten()
         .to_output_stream())
 }
 
-enum TableInside<'a> {
-    Entries(&'a str, &'a Tag, Vec<&'a Value>),
+enum TableInside {
+    Entries(String, Tag, Vec<Value>),
 }
 
 fn flat_value(
     columns: &[Tagged<String>],
     item: &Value,
+    depth: Option<usize>,
+    explode: bool,
     name_tag: impl Into<Tag>,
 ) -> Vec<Result<ReturnSuccess, ShellError>> {
-    let tag = item.tag.clone();
     let name_tag = name_tag.into();
 
     let res = {
         if item.is_row() {
-            let mut out = TaggedDictBuilder::new(tag);
-            let mut a_table = None;
-            let mut tables_explicitly_flattened = 0;
-
-            for (column, value) in item.row_entries() {
-                let column_requested = columns.iter().find(|c| c.item == *column);
-
-                if let Value {
-                    value: UntaggedValue::Row(Dictionary { entries: mapa }),
-                    ..
-                } = value
-                {
-                    if column_requested.is_none() && !columns.is_empty() {
-                        if out.contains_key(&column) {
-                            out.insert_value(format!("{}_{}", column, column), value.clone());
-                        } else {
-                            out.insert_value(column, value.clone());
-                        }
-                        continue;
-                    }
-
-                    for (k, v) in mapa.into_iter() {
-                        if out.contains_key(k) {
-                            out.insert_value(format!("{}_{}", column, k), v.clone());
-                        } else {
-                            out.insert_value(k, v.clone());
-                        }
-                    }
-                } else if value.is_table() {
-                    if tables_explicitly_flattened >= 1 && column_requested.is_some() {
-                        let attempted = if let Some(name) = column_requested {
-                            name.span()
-                        } else {
-                            name_tag.span
-                        };
-
-                        let already_flattened =
-                            if let Some(TableInside::Entries(_, column_tag, _)) = a_table {
-                                column_tag.span
-                            } else {
-                                name_tag.span
-                            };
-
-                        return vec![ReturnSuccess::value(
-                            UntaggedValue::Error(ShellError::labeled_error_with_secondary(
-                                "can only flatten one inner table at the same time",
-                                "tried flattening more than one column with inner tables",
-                                attempted,
-                                "...but is flattened already",
-                                already_flattened,
-                            ))
-                            .into_value(name_tag),
-                        )];
-                    }
-
-                    if !columns.is_empty() {
-                        if let Some(requested) = column_requested {
-                            a_table = Some(TableInside::Entries(
-                                &requested.item,
-                                &requested.tag,
-                                value.table_entries().collect(),
-                            ));
-
-                            tables_explicitly_flattened += 1;
-                        } else {
-                            out.insert_value(column, value.clone());
-                        }
-                    } else if a_table.is_none() {
-                        a_table = Some(TableInside::Entries(
-                            &column,
-                            &value.tag,
-                            value.table_entries().collect(),
-                        ))
-                    } else {
-                        out.insert_value(column, value.clone());
-                    }
-                } else {
-                    out.insert_value(column, value.clone());
-                }
-            }
-
-            let mut expanded = vec![];
-
-            if let Some(TableInside::Entries(column, _, entries)) = a_table {
-                for entry in entries.into_iter() {
-                    let mut base = out.clone();
-                    base.insert_value(column, entry.clone());
-                    expanded.push(base.into_value());
-                }
-            } else {
-                expanded.push(out.into_value());
+            match flat_row(columns, item, depth, explode, &name_tag) {
+                Ok(rows) => rows,
+                Err(error) => vec![UntaggedValue::Error(error).into_value(name_tag)],
             }
-
-            expanded
         } else if item.is_table() {
             item.table_entries().map(Clone::clone).collect()
         } else {
@@ -179,3 +117,120 @@ fn flat_value(
 
     res.into_iter().map(ReturnSuccess::value).collect()
 }
+
+fn flat_row(
+    columns: &[Tagged<String>],
+    item: &Value,
+    depth: Option<usize>,
+    explode: bool,
+    name_tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let mut out = IndexMap::new();
+    let mut a_table = None;
+
+    for (column, value) in item.row_entries() {
+        let column_requested = columns.iter().find(|c| c.item == *column);
+
+        if columns.is_empty() || column_requested.is_some() {
+            if value.is_row() {
+                lift_record(&mut out, column, value, depth, name_tag)?;
+                continue;
+            }
+
+            if let Some(requested) = column_requested.filter(|_| value.is_table()) {
+                if let Some(TableInside::Entries(_, already_flattened, _)) = &a_table {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "can only flatten one inner table at the same time",
+                        "tried flattening more than one column with inner tables",
+                        requested.span(),
+                        "...but is flattened already",
+                        already_flattened.span,
+                    ));
+                }
+
+                a_table = Some(TableInside::Entries(
+                    column.clone(),
+                    requested.tag.clone(),
+                    value.table_entries().cloned().collect(),
+                ));
+                continue;
+            }
+        }
+
+        insert(&mut out, column.clone(), value.clone(), name_tag)?;
+    }
+
+    // Without columns to flatten, exploding takes the first list left in the row, lifted or not
+    if a_table.is_none() && explode && columns.is_empty() {
+        if let Some(index) = out.values().position(|value| value.is_table()) {
+            if let Some((column, value)) = out.shift_remove_index(index) {
+                a_table = Some(TableInside::Entries(
+                    column,
+                    value.tag.clone(),
+                    value.table_entries().cloned().collect(),
+                ));
+            }
+        }
+    }
+
+    let mut expanded = vec![];
+
+    if let Some(TableInside::Entries(column, _, entries)) = a_table {
+        for entry in entries.into_iter() {
+            let mut base = out.clone();
+            if entry.is_row() {
+                lift_record(&mut base, &column, &entry, depth, name_tag)?;
+            } else {
+                insert(&mut base, column.clone(), entry, name_tag)?;
+            }
+            expanded.push(row(base, &item.tag));
+        }
+    } else {
+        expanded.push(row(out, &item.tag));
+    }
+
+    Ok(expanded)
+}
+
+/// Moves the fields of a record into the row as `column.field`, and so on for the records
+/// inside it until `depth` levels have been lifted
+fn lift_record(
+    out: &mut IndexMap<String, Value>,
+    column: &str,
+    record: &Value,
+    depth: Option<usize>,
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    if depth == Some(0) || !record.is_row() || record.row_entries().next().is_none() {
+        return insert(out, column.to_string(), record.clone(), name_tag);
+    }
+
+    for (field, value) in record.row_entries() {
+        let name = format!("{}.{}", column, field);
+        lift_record(out, &name, value, depth.map(|depth| depth - 1), name_tag)?;
+    }
+
+    Ok(())
+}
+
+fn insert(
+    out: &mut IndexMap<String, Value>,
+    column: String,
+    value: Value,
+    name_tag: &Tag,
+) -> Result<(), ShellError> {
+    if out.contains_key(&column) {
+        return Err(ShellError::labeled_error(
+            format!("Column '{}' already exists", column),
+            "flattening gives two columns the same name",
+            name_tag,
+        ));
+    }
+
+    out.insert(column, value);
+    Ok(())
+}
+
+fn row(entries: IndexMap<String, Value>, tag: &Tag) -> Value {
+    UntaggedValue::Row(Dictionary { entries }).into_value(tag)
+}
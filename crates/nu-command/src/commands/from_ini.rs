@@ -1,8 +1,8 @@
 use crate::prelude::*;
+use indexmap::IndexMap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, Signature, TaggedDictBuilder, UntaggedValue, Value};
-use std::collections::HashMap;
+use nu_protocol::{Signature, TaggedDictBuilder, UntaggedValue, Value};
 
 pub struct FromINI;
 
@@ -17,47 +17,97 @@ impl WholeStreamCommand for FromINI {
     }
 
     fn usage(&self) -> &str {
-        "Parse text as .ini and create table"
+        "Parse text as .ini and create a record with a column per section"
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         from_ini(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Converts ini formatted string to record",
+            example: "echo \"[foo]\\na=1\\nb=2\" | from ini",
+            result: Some(vec![UntaggedValue::row(indexmap! {
+                "foo".to_string() => UntaggedValue::row(indexmap! {
+                    "a".to_string() => UntaggedValue::string("1").into(),
+                    "b".to_string() => UntaggedValue::string("2").into(),
+                }).into(),
+            })
+            .into()]),
+        }]
+    }
 }
 
-fn convert_ini_second_to_nu_value(v: &HashMap<String, String>, tag: impl Into<Tag>) -> Value {
-    let mut second = TaggedDictBuilder::new(tag);
+/// The entries of an INI document, in the order they first appear. Keys that come before the first
+/// section header are kept at the top level, next to the sections.
+#[derive(Default)]
+struct IniDocument {
+    globals: IndexMap<String, String>,
+    sections: IndexMap<String, IndexMap<String, String>>,
+}
 
-    for (key, value) in v.iter() {
-        second.insert_untagged(key.clone(), Primitive::String(value.clone()));
+fn parse_ini(s: &str) -> Result<IniDocument, String> {
+    let mut document = IniDocument::default();
+    let mut section: Option<String> = None;
+
+    for (index, line) in s.trim_start_matches('\u{feff}').lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            // Git style `[section "subsection"]` headers are kept as they are written
+            let name = line
+                .strip_suffix(']')
+                .map(|name| name[1..].trim().to_string())
+                .ok_or_else(|| {
+                    format!(
+                        "line {} has a section header without a closing ]",
+                        index + 1
+                    )
+                })?;
+
+            document.sections.entry(name.clone()).or_default();
+            section = Some(name);
+            continue;
+        }
+
+        let (key, value) = match line.find('=') {
+            Some(pos) => (line[..pos].trim(), line[pos + 1..].trim()),
+            None => return Err(format!("line {} is not a `key = value` pair", index + 1)),
+        };
+
+        let entries = match &section {
+            Some(name) => document.sections.entry(name.clone()).or_default(),
+            None => &mut document.globals,
+        };
+        entries.insert(key.to_string(), value.to_string());
     }
 
-    second.into_value()
+    Ok(document)
 }
 
-fn convert_ini_top_to_nu_value(
-    v: &HashMap<String, HashMap<String, String>>,
-    tag: impl Into<Tag>,
-) -> Value {
+pub fn from_ini_string_to_value(s: String, tag: impl Into<Tag>) -> Result<Value, String> {
     let tag = tag.into();
-    let mut top_level = TaggedDictBuilder::new(tag.clone());
+    let document = parse_ini(&s)?;
+    let mut top_level = TaggedDictBuilder::new(&tag);
 
-    for (key, value) in v.iter() {
-        top_level.insert_value(
-            key.clone(),
-            convert_ini_second_to_nu_value(value, tag.clone()),
-        );
+    for (key, value) in document.globals {
+        top_level.insert_untagged(key, UntaggedValue::string(value));
     }
 
-    top_level.into_value()
-}
+    for (name, entries) in document.sections {
+        let mut section = TaggedDictBuilder::new(&tag);
+        for (key, value) in entries {
+            section.insert_untagged(key, UntaggedValue::string(value));
+        }
+        top_level.insert_value(name, section.into_value());
+    }
 
-pub fn from_ini_string_to_value(
-    s: String,
-    tag: impl Into<Tag>,
-) -> Result<Value, serde_ini::de::Error> {
-    let v: HashMap<String, HashMap<String, String>> = serde_ini::from_str(&s)?;
-    Ok(convert_ini_top_to_nu_value(&v, tag))
+    Ok(top_level.into_value())
 }
 
 async fn from_ini(args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -67,16 +117,10 @@ async fn from_ini(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let concat_string = input.collect_string(tag.clone()).await?;
 
     match from_ini_string_to_value(concat_string.item, tag.clone()) {
-        Ok(x) => match x {
-            Value {
-                value: UntaggedValue::Table(list),
-                ..
-            } => Ok(futures::stream::iter(list).to_output_stream()),
-            x => Ok(OutputStream::one(x)),
-        },
-        Err(_) => Err(ShellError::labeled_error_with_secondary(
+        Ok(x) => Ok(OutputStream::one(x)),
+        Err(err) => Err(ShellError::labeled_error_with_secondary(
             "Could not parse as INI",
-            "input cannot be parsed as INI",
+            err,
             &tag,
             "value originates from here",
             concat_string.tag,
@@ -24,12 +24,39 @@ impl WholeStreamCommand for FromXML {
     }
 }
 
-fn from_attributes_to_value(attributes: &[roxmltree::Attribute], tag: impl Into<Tag>) -> Value {
+/// The name with the prefix of its namespace, if it has one, like `dc:creator`
+fn prefixed_name(n: &roxmltree::Node, name: &str, namespace: Option<&str>) -> String {
+    match namespace.and_then(|namespace| n.lookup_prefix(namespace)) {
+        Some(prefix) => format!("{}:{}", prefix, name),
+        None => name.to_string(),
+    }
+}
+
+fn from_attributes_to_value(n: &roxmltree::Node, tag: impl Into<Tag>) -> Value {
     let tag = tag.into();
 
     let mut collected = TaggedDictBuilder::new(tag);
-    for a in attributes {
-        collected.insert_untagged(String::from(a.name()), UntaggedValue::string(a.value()));
+
+    // Keep the namespaces declared on the element, so that the prefixes can be written back
+    let inherited = n
+        .parent_element()
+        .map(|parent| parent.namespaces())
+        .unwrap_or_default();
+    for namespace in n.namespaces() {
+        if !inherited.contains(namespace) {
+            let name = match namespace.name() {
+                Some(prefix) => format!("xmlns:{}", prefix),
+                None => String::from("xmlns"),
+            };
+            collected.insert_untagged(name, UntaggedValue::string(namespace.uri()));
+        }
+    }
+
+    for a in n.attributes() {
+        collected.insert_untagged(
+            prefixed_name(n, a.name(), a.namespace()),
+            UntaggedValue::string(a.value()),
+        );
     }
 
     collected.into_value()
@@ -39,7 +66,7 @@ fn from_node_to_value(n: &roxmltree::Node, tag: impl Into<Tag>) -> Value {
     let tag = tag.into();
 
     if n.is_element() {
-        let name = n.tag_name().name().trim().to_string();
+        let name = prefixed_name(n, n.tag_name().name().trim(), n.tag_name().namespace());
 
         let mut children_values = vec![];
         for c in n.children() {
@@ -61,7 +88,7 @@ fn from_node_to_value(n: &roxmltree::Node, tag: impl Into<Tag>) -> Value {
 
         let mut collected = TaggedDictBuilder::new(&tag);
 
-        let attribute_value: Value = from_attributes_to_value(&n.attributes(), &tag);
+        let attribute_value: Value = from_attributes_to_value(n, &tag);
 
         let mut row = TaggedDictBuilder::new(&tag);
         row.insert_untagged(
@@ -112,9 +139,9 @@ async fn from_xml(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     .to_output_stream(),
                 x => OutputStream::one(ReturnSuccess::value(x)),
             },
-            Err(_) => {
+            Err(err) => {
                 return Err(ShellError::labeled_error_with_secondary(
-                    "Could not parse as XML",
+                    format!("Could not parse as XML: {}", err),
                     "input cannot be parsed as XML",
                     &tag,
                     "value originates from here",
@@ -2,6 +2,7 @@ use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use serde::Deserialize;
 
 pub struct FromYAML;
 
@@ -123,14 +124,25 @@ fn convert_yaml_value_to_nu_value(
 
 pub fn from_yaml_string_to_value(s: String, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     let tag = tag.into();
-    let v: serde_yaml::Value = serde_yaml::from_str(&s).map_err(|x| {
-        ShellError::labeled_error(
-            format!("Could not load yaml: {}", x),
-            "could not load yaml from text",
-            &tag,
-        )
-    })?;
-    convert_yaml_value_to_nu_value(&v, tag)
+
+    let mut documents = vec![];
+    for document in serde_yaml::Deserializer::from_str(&s) {
+        let v = serde_yaml::Value::deserialize(document).map_err(|x| {
+            ShellError::labeled_error(
+                format!("Could not load yaml: {}", x),
+                "could not load yaml from text",
+                &tag,
+            )
+        })?;
+        documents.push(convert_yaml_value_to_nu_value(&v, &tag)?);
+    }
+
+    // A file with several documents in it is a table of them
+    match documents.len() {
+        0 => Ok(UntaggedValue::nothing().into_value(tag)),
+        1 => Ok(documents.remove(0)),
+        _ => Ok(UntaggedValue::Table(documents).into_value(tag)),
+    }
 }
 
 async fn from_yaml(args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -163,7 +175,7 @@ mod tests {
     use super::ShellError;
     use super::*;
     use nu_protocol::row;
-    use nu_test_support::value::string;
+    use nu_test_support::value::{int, string, table};
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -190,6 +202,24 @@ mod tests {
                 input: r#"value: {{ something }}"#,
                 expected: Ok(row!["value".to_owned() => string("{{ something }}")]),
             },
+            TestCase {
+                description: "Only true and false are booleans, as in YAML 1.2",
+                input: "a: yes\nb: no\nc: on\nd: true",
+                expected: Ok(row![
+                    "a".to_owned() => string("yes"),
+                    "b".to_owned() => string("no"),
+                    "c".to_owned() => string("on"),
+                    "d".to_owned() => UntaggedValue::boolean(true).into_untagged_value()
+                ]),
+            },
+            TestCase {
+                description: "Several documents become a table of them",
+                input: "a: 1\n---\na: 2",
+                expected: Ok(table(&[
+                    row!["a".to_owned() => int(1)],
+                    row!["a".to_owned() => int(2)],
+                ])),
+            },
         ];
         for tc in tt.into_iter() {
             let actual = from_yaml_string_to_value(tc.input.to_owned(), Tag::default());
@@ -2,12 +2,17 @@ use crate::prelude::*;
 use crate::utils::suggestions::suggestions;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::Tagged;
-use nu_value_ext::as_string;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{SpannedItem, Tagged};
+use nu_value_ext::{as_string, ValueExt};
 
 pub struct Command;
 
+/// The group of the rows that don't have the column being grouped by
+const MISSING_GROUP: &str = "(missing)";
+
 #[derive(Deserialize)]
 pub struct Arguments {
     grouper: Option<Value>,
@@ -225,6 +230,9 @@ pub async fn group_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
     Ok(OutputStream::one(ReturnSuccess::value(group_value?)))
 }
 
+/// Groups the rows of a table by what they have in the given column, or the values
+/// themselves without one. Groups are kept in the order their keys are first seen, and
+/// rows without the column go in a group of their own.
 pub fn group(
     column_name: &Option<Tagged<String>>,
     values: &Value,
@@ -232,31 +240,38 @@ pub fn group(
 ) -> Result<Value, ShellError> {
     let name = tag.into();
 
-    let grouper = if let Some(column_name) = column_name {
-        Grouper::ByColumn(Some(column_name.clone()))
-    } else {
-        Grouper::ByColumn(None)
-    };
-
-    match grouper {
-        Grouper::ByColumn(Some(column_name)) => {
-            let block = Box::new(move |_, row: &Value| {
-                match row.get_data_by_key(column_name.borrow_spanned()) {
-                    Some(group_key) => Ok(as_string(&group_key)?),
-                    None => Err(suggestions(column_name.borrow_tagged(), &row)),
+    match column_name {
+        Some(column_name) => {
+            let path = ColumnPath::build(&column_name.item.clone().spanned(column_name.tag.span));
+            let get_key = move |row: &Value| {
+                row.get_data_by_column_path(&path, Box::new(|_, _, error| error))
+                    .ok()
+            };
+
+            // A misspelled column shouldn't quietly put every row in the missing group, though
+            // without any rows there's no telling whether it is misspelled
+            if let Some(first) = values.table_entries().next() {
+                if !values.table_entries().any(|row| get_key(row).is_some()) {
+                    return Err(suggestions(column_name.borrow_tagged(), first));
                 }
+            }
+
+            let block = Box::new(move |_, row: &Value| match get_key(row) {
+                Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::Nothing),
+                    ..
+                })
+                | None => Ok(MISSING_GROUP.to_string()),
+                Some(group_key) => as_string(&group_key),
             });
 
             nu_data::utils::group(&values, &Some(block), &name)
         }
-        Grouper::ByColumn(None) => {
+        None => {
             let block = Box::new(move |_, row: &Value| as_string(row));
 
             nu_data::utils::group(&values, &Some(block), &name)
         }
-        Grouper::ByBlock => Err(ShellError::unimplemented(
-            "Block not implemented: This should never happen.",
-        )),
     }
 }
 
@@ -325,4 +340,11 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn groups_nothing_without_rows() {
+        let for_key = Some(String::from("country").tagged_unknown());
+
+        assert!(group(&for_key, &table(&[]), Tag::unknown()).is_ok());
+    }
 }
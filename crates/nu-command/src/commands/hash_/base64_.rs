@@ -163,66 +163,70 @@ fn action(
     base64_config: &Base64Config,
     tag: impl Into<Tag>,
 ) -> Result<Value, ShellError> {
-    match &input.value {
-        UntaggedValue::Primitive(Primitive::String(s)) => {
-            let base64_config_enum: base64::Config = if &base64_config.character_set == "standard" {
-                base64::STANDARD
-            } else if &base64_config.character_set == "standard-no-padding" {
-                base64::STANDARD_NO_PAD
-            } else if &base64_config.character_set == "url-safe" {
-                base64::URL_SAFE
-            } else if &base64_config.character_set == "url-safe-no-padding" {
-                base64::URL_SAFE_NO_PAD
-            } else if &base64_config.character_set == "binhex" {
-                base64::BINHEX
-            } else if &base64_config.character_set == "bcrypt" {
-                base64::BCRYPT
-            } else if &base64_config.character_set == "crypt" {
-                base64::CRYPT
-            } else {
-                return Err(ShellError::labeled_error(
-                    "value is not an accepted character set",
-                    format!(
-                        "{} is not a valid character-set.\nPlease use `help hash base64` to see a list of valid character sets.", 
-                        &base64_config.character_set
-                    ),
-                    tag.into().span,
-                ));
-            };
-
-            match base64_config.action_type {
-                ActionType::Encode => Ok(UntaggedValue::string(encode_config(
-                    &s,
-                    base64_config_enum,
-                ))
-                .into_value(tag)),
-                ActionType::Decode => {
-                    let decode_result = decode_config(&s, base64_config_enum);
-
-                    match decode_result {
-                        Ok(decoded_value) => Ok(UntaggedValue::string(
-                            std::string::String::from_utf8_lossy(&decoded_value),
-                        )
-                        .into_value(tag)),
-                        Err(_) => Err(ShellError::labeled_error(
-                            "value could not be base64 decoded",
-                            format!(
-                                "invalid base64 input for character set {}",
-                                &base64_config.character_set
-                            ),
-                            tag.into().span,
-                        )),
-                    }
-                }
-            }
+    let bytes: &[u8] = match &input.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s.as_bytes(),
+        // Binary data can be encoded, so that it can be shown or sent as text
+        UntaggedValue::Primitive(Primitive::Binary(b))
+            if matches!(base64_config.action_type, ActionType::Encode) =>
+        {
+            b
         }
         other => {
             let got = format!("got {}", other.type_name());
-            Err(ShellError::labeled_error(
+            return Err(ShellError::labeled_error(
                 "value is not string",
                 got,
                 tag.into().span,
-            ))
+            ));
+        }
+    };
+
+    let base64_config_enum: base64::Config = if &base64_config.character_set == "standard" {
+        base64::STANDARD
+    } else if &base64_config.character_set == "standard-no-padding" {
+        base64::STANDARD_NO_PAD
+    } else if &base64_config.character_set == "url-safe" {
+        base64::URL_SAFE
+    } else if &base64_config.character_set == "url-safe-no-padding" {
+        base64::URL_SAFE_NO_PAD
+    } else if &base64_config.character_set == "binhex" {
+        base64::BINHEX
+    } else if &base64_config.character_set == "bcrypt" {
+        base64::BCRYPT
+    } else if &base64_config.character_set == "crypt" {
+        base64::CRYPT
+    } else {
+        return Err(ShellError::labeled_error(
+            "value is not an accepted character set",
+            format!(
+                "{} is not a valid character-set.\nPlease use `help hash base64` to see a list of valid character sets.", 
+                &base64_config.character_set
+            ),
+            tag.into().span,
+        ));
+    };
+
+    match base64_config.action_type {
+        ActionType::Encode => {
+            Ok(UntaggedValue::string(encode_config(bytes, base64_config_enum)).into_value(tag))
+        }
+        ActionType::Decode => {
+            let decode_result = decode_config(bytes, base64_config_enum);
+
+            match decode_result {
+                Ok(decoded_value) => Ok(UntaggedValue::string(
+                    std::string::String::from_utf8_lossy(&decoded_value),
+                )
+                .into_value(tag)),
+                Err(_) => Err(ShellError::labeled_error(
+                    "value could not be base64 decoded",
+                    format!(
+                        "invalid base64 input for character set {}",
+                        &base64_config.character_set
+                    ),
+                    tag.into().span,
+                )),
+            }
         }
     }
 }
@@ -234,6 +238,23 @@ mod tests {
     use nu_source::Tag;
     use nu_test_support::value::string;
 
+    #[test]
+    fn base64_encode_binary() {
+        let data = UntaggedValue::binary(b"username:password".to_vec()).into_untagged_value();
+        let expected = UntaggedValue::string("dXNlcm5hbWU6cGFzc3dvcmQ=").into_untagged_value();
+
+        let actual = action(
+            &data,
+            &Base64Config {
+                character_set: "standard".to_string(),
+                action_type: ActionType::Encode,
+            },
+            Tag::unknown(),
+        )
+        .unwrap();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn base64_encode_standard() {
         let word = string("username:password");
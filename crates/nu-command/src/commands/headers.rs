@@ -43,42 +43,29 @@ impl WholeStreamCommand for Headers {
 }
 
 pub async fn headers(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let input = args.input;
-    let rows: Vec<Value> = input.collect().await;
-
-    if rows.is_empty() {
-        return Err(ShellError::untagged_runtime_error(
-            "Couldn't find headers, was the input a properly formatted, non-empty table?",
-        ));
-    }
+    let mut input = args.input;
 
     //the headers are the first row in the table
-    let headers: Vec<String> = match &rows[0].value {
-        UntaggedValue::Row(d) => {
-            Ok(d.entries
-                .iter()
-                .map(|(k, v)| {
-                    match v.as_string() {
-                        Ok(s) => s,
-                        Err(_) => {
-                            //If a cell that should contain a header name is empty, we name the column Column[index]
-                            match d.entries.get_full(k) {
-                                Some((index, _, _)) => format!("Column{}", index),
-                                None => "unknownColumn".to_string(),
-                            }
-                        }
-                    }
-                })
-                .collect())
+    let headers = match input.next().await {
+        Some(Value {
+            value: UntaggedValue::Row(d),
+            ..
+        }) => header_names(&d),
+        Some(first) => {
+            return Err(ShellError::unexpected_eof(
+                "Could not get headers, is the table empty?",
+                first.tag.span,
+            ))
+        }
+        None => {
+            return Err(ShellError::untagged_runtime_error(
+                "Couldn't find headers, was the input a properly formatted, non-empty table?",
+            ))
         }
-        _ => Err(ShellError::unexpected_eof(
-            "Could not get headers, is the table empty?",
-            rows[0].tag.span,
-        )),
-    }?;
-
-    Ok(
-        futures::stream::iter(rows.into_iter().skip(1).map(move |r| {
+    };
+
+    Ok(input
+        .map(move |r| {
             //Each row is a dictionary with the headers as keys
             match &r.value {
                 UntaggedValue::Row(d) => {
@@ -100,9 +87,36 @@ pub async fn headers(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     r.tag.span,
                 )),
             }
-        }))
-        .to_output_stream(),
-    )
+        })
+        .to_output_stream())
+}
+
+/// The column names the cells of the first row give, which are all different and never empty
+fn header_names(first_row: &Dictionary) -> Vec<String> {
+    let mut headers: Vec<String> = vec![];
+
+    for (index, cell) in first_row.entries.values().enumerate() {
+        let name = cell.convert_to_string();
+
+        //If a cell that should contain a header name is empty, we name the column Column[index]
+        let name = if name.trim().is_empty() {
+            format!("Column{}", index)
+        } else {
+            name
+        };
+
+        //A name that's already taken gets the first free number appended, like name1, name2
+        let mut unique = name.clone();
+        let mut number = 1;
+        while headers.contains(&unique) {
+            unique = format!("{}{}", name, number);
+            number += 1;
+        }
+
+        headers.push(unique);
+    }
+
+    headers
 }
 
 #[cfg(test)]
@@ -3,8 +3,10 @@ use nu_engine::command_dict;
 use nu_engine::documentation::generate_docs;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
-use nu_source::{SpannedItem, Tagged};
+use nu_protocol::{
+    closest_match, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::{Spanned, SpannedItem, Tagged};
 use nu_value_ext::ValueExt;
 
 pub struct Help;
@@ -40,6 +42,8 @@ async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     if !rest.is_empty() {
         if rest[0].item == "commands" {
+            let alias_scope = scope.clone();
+            let alias_tag = name.clone();
             let mut sorted_names = scope.get_command_names();
             sorted_names.sort();
 
@@ -151,7 +155,18 @@ async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         ReturnSuccess::value(short_desc.into_value())
                     });
 
-            Ok(futures::stream::iter(iterator).to_output_stream())
+            let aliases = alias_scope
+                .get_aliases()
+                .into_iter()
+                .map(move |(alias, expansion)| {
+                    let mut short_desc = TaggedDictBuilder::new(alias_tag.clone());
+                    short_desc.insert_untagged("name", alias);
+                    short_desc.insert_untagged("description", alias_description(&expansion));
+                    short_desc.insert_untagged("subcommands", UntaggedValue::nothing());
+                    ReturnSuccess::value(short_desc.into_value())
+                });
+
+            Ok(futures::stream::iter(iterator.chain(aliases)).to_output_stream())
         } else if rest[0].item == "generate_docs" {
             Ok(OutputStream::one(ReturnSuccess::value(generate_docs(
                 &scope,
@@ -165,19 +180,23 @@ async fn help(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         .into_value(Tag::unknown()),
                 )))
             } else {
-                Ok(OutputStream::empty())
+                Err(command_not_found(
+                    &command_name,
+                    rest[0].tag.span.until(rest[1].tag.span),
+                    &scope,
+                ))
             }
         } else if let Some(command) = scope.get_command(&rest[0].item) {
             Ok(OutputStream::one(ReturnSuccess::value(
                 UntaggedValue::string(get_full_help(command.stream_command(), &scope))
                     .into_value(Tag::unknown()),
             )))
+        } else if let Some(expansion) = scope.get_aliases().get(&rest[0].item) {
+            Ok(OutputStream::one(ReturnSuccess::value(
+                UntaggedValue::string(alias_description(expansion)).into_value(Tag::unknown()),
+            )))
         } else {
-            Err(ShellError::labeled_error(
-                "Can't find command (use 'help commands' for full list)",
-                "can't find command",
-                rest[0].tag.span,
-            ))
+            Err(command_not_found(&rest[0].item, rest[0].tag.span, &scope))
         }
     } else {
         let msg = r#"Welcome to Nushell.
@@ -208,6 +227,34 @@ You can also learn more at https://www.nushell.sh/book/"#;
     }
 }
 
+fn alias_description(expansion: &[Spanned<String>]) -> String {
+    let expansion = expansion
+        .iter()
+        .map(|part| part.item.clone())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!("Alias for `{}`.", expansion)
+}
+
+fn command_not_found(name: &str, span: Span, scope: &Scope) -> ShellError {
+    let names = scope
+        .get_command_names()
+        .into_iter()
+        .chain(scope.get_aliases().into_iter().map(|(alias, _)| alias));
+
+    let label = match closest_match(names, name) {
+        Some(suggestion) => format!("can't find command, did you mean '{}'?", suggestion),
+        None => "can't find command".to_string(),
+    };
+
+    ShellError::labeled_error(
+        "Can't find command (use 'help commands' for full list)",
+        label,
+        span,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::Help;
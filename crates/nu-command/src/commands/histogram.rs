@@ -1,13 +1,28 @@
+use crate::commands::group_by::group;
 use crate::prelude::*;
+use crate::utils::suggestions::suggestions;
+use nu_data::value::{compute_values, format_leaf};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
-    ColumnPath, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+    hir::Operator, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape,
+    TaggedDictBuilder, UntaggedValue, Value,
 };
 use nu_source::Tagged;
+use std::cmp::Ordering;
 
 pub struct Histogram;
 
+#[derive(Deserialize)]
+pub struct Arguments {
+    column_name: Option<Tagged<String>>,
+    summed_column: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "use"))]
+    valuator: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "frequency-column"))]
+    frequency_column: Option<Tagged<String>>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Histogram {
     fn name(&self) -> &str {
@@ -16,15 +31,27 @@ impl WholeStreamCommand for Histogram {
 
     fn signature(&self) -> Signature {
         Signature::build("histogram")
+            .optional(
+                "column_name",
+                SyntaxShape::String,
+                "the column whose distinct values are counted",
+            )
+            .optional(
+                "summed_column",
+                SyntaxShape::String,
+                "a column to sum for each value, rather than counting its rows",
+            )
             .named(
                 "use",
-                SyntaxShape::ColumnPath,
-                "Use data at the column path given as valuator",
+                SyntaxShape::String,
+                "use the data in the column given as valuator, the same as giving it as summed_column",
                 None,
             )
-            .rest(
-                SyntaxShape::ColumnPath,
-                "column name to give the histogram's frequency column",
+            .named(
+                "frequency-column",
+                SyntaxShape::String,
+                "name to give the column with the bars (defaults to frequency)",
+                Some('f'),
             )
     }
 
@@ -43,10 +70,15 @@ impl WholeStreamCommand for Histogram {
                 example: "ls | histogram type",
                 result: None,
             },
+            Example {
+                description: "Get a histogram of how much space each type of file takes up",
+                example: "ls | histogram type size",
+                result: None,
+            },
             Example {
                 description:
-                    "Get a histogram for the types of files, with frequency column named percentage",
-                example: "ls | histogram type percentage",
+                    "Get a histogram for the types of files, with the bars in a column named probability",
+                example: "ls | histogram type --frequency-column probability",
                 result: None,
             },
             Example {
@@ -58,168 +90,205 @@ impl WholeStreamCommand for Histogram {
     }
 }
 
+struct Bucket {
+    key: String,
+    total: Value,
+    size: f64,
+}
+
 pub async fn histogram(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (input, args) = args.evaluate_once().await?.parts();
+    let term_width = args.host.lock().width();
+    let (
+        Arguments {
+            column_name,
+            summed_column,
+            valuator,
+            frequency_column,
+        },
+        input,
+    ) = args.process().await?;
+
+    let summed_column = summed_column.or(valuator);
 
     let values: Vec<Value> = input.collect().await;
+    let values = UntaggedValue::table(&values).into_value(&name);
 
-    let mut columns = args
-        .positional_iter()
-        .map(|c| c.as_column_path())
-        .filter_map(Result::ok)
-        .collect::<Vec<_>>();
-
-    let evaluate_with = if let Some(path) = args.get("use") {
-        Some(evaluator(path.as_column_path()?.item))
-    } else {
-        None
-    };
-
-    let column_grouper = if !columns.is_empty() {
-        match columns.remove(0).split_last() {
-            Some((key, _)) => Some(key.as_string().tagged(&name)),
-            None => None,
+    // A misspelled column shouldn't quietly sum up to nothing for every value
+    if let (Some(column), Some(first)) = (&summed_column, values.table_entries().next()) {
+        if !values
+            .table_entries()
+            .any(|row| row.get_data_by_key(column.borrow_spanned()).is_some())
+        {
+            return Err(suggestions(column.borrow_tagged(), first));
         }
-    } else {
-        None
-    };
-
-    let frequency_column_name = if columns.is_empty() {
-        "frequency".to_string()
-    } else if let Some((key, _)) = columns[0].split_last() {
-        key.as_string()
-    } else {
-        "frequency".to_string()
-    };
-
-    let column = if let Some(ref column) = column_grouper {
-        column.clone()
-    } else {
-        "value".to_string().tagged(&name)
-    };
-
-    let results = nu_data::utils::report(
-        &UntaggedValue::table(&values).into_value(&name),
-        nu_data::utils::Operation {
-            grouper: Some(Box::new(move |_, _| Ok(String::from("frequencies")))),
-            splitter: Some(splitter(column_grouper)),
-            format: &None,
-            eval: &evaluate_with,
-            reduction: &nu_data::utils::Reduction::Count,
-        },
-        &name,
-    )?;
+    }
 
-    let labels = results.labels.y.clone();
-    let mut idx = 0;
+    let groups = group(&column_name, &values, &name)?;
 
-    Ok(futures::stream::iter(
-        results
-            .data
-            .table_entries()
-            .cloned()
-            .collect::<Vec<_>>()
-            .into_iter()
-            .zip(
-                results
-                    .percentages
-                    .table_entries()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .into_iter(),
-            )
-            .map(move |(counts, percentages)| {
-                let percentage = percentages
-                    .table_entries()
-                    .cloned()
-                    .last()
-                    .unwrap_or_else(|| {
-                        UntaggedValue::decimal_from_float(0.0, name.span).into_value(&name)
-                    });
-                let value = counts
-                    .table_entries()
-                    .cloned()
-                    .last()
-                    .unwrap_or_else(|| UntaggedValue::int(0).into_value(&name));
-
-                let mut fact = TaggedDictBuilder::new(&name);
-                let column_value = labels
-                    .get(idx)
-                    .ok_or_else(|| {
-                        ShellError::labeled_error(
-                            "Unable to load group labels",
-                            "unable to load group labels",
-                            &name,
-                        )
-                    })?
-                    .clone();
-
-                fact.insert_value(&column.item, column_value);
-                fact.insert_untagged("count", value);
-
-                let fmt_percentage = format!(
-                    "{}%",
-                    // Some(2) < the number of digits
-                    // true < group the digits
-                    crate::commands::str_::from::action(&percentage, &name, Some(2), true)?
-                        .as_string()?
-                );
-                fact.insert_untagged("percentage", UntaggedValue::string(fmt_percentage));
-
-                let string = std::iter::repeat("*")
-                    .take(percentage.as_u64().map_err(|_| {
-                        ShellError::labeled_error("expected a number", "expected a number", &name)
-                    })? as usize)
-                    .collect::<String>();
-
-                fact.insert_untagged(&frequency_column_name, UntaggedValue::string(string));
-
-                idx += 1;
-
-                ReturnSuccess::value(fact.into_value())
-            }),
+    let mut buckets = groups
+        .row_entries()
+        .map(|(key, rows)| {
+            let total = match &summed_column {
+                Some(column) => sum(rows, column, &name)?,
+                None => UntaggedValue::int(rows.table_entries().count()).into_value(&name),
+            };
+
+            Ok(Bucket {
+                key: key.clone(),
+                size: magnitude(&total),
+                total,
+            })
+        })
+        .collect::<Result<Vec<_>, ShellError>>()?;
+
+    // The sort is stable, so buckets of the same size stay in the order their values
+    // were first seen in
+    buckets.sort_by(|a, b| b.size.partial_cmp(&a.size).unwrap_or(Ordering::Equal));
+
+    let largest = buckets.iter().map(|bucket| bucket.size).fold(0.0, f64::max);
+
+    let key_column = column_name
+        .map(|column| column.item)
+        .unwrap_or_else(|| "value".to_string());
+    let total_column = summed_column
+        .map(|column| column.item)
+        .unwrap_or_else(|| "count".to_string());
+    let frequency_column = frequency_column
+        .map(|column| column.item)
+        .unwrap_or_else(|| "frequency".to_string());
+
+    let width = bar_width(
+        term_width,
+        &[
+            buckets.len().saturating_sub(1).to_string().len(),
+            buckets
+                .iter()
+                .map(|bucket| bucket.key.chars().count())
+                .chain(std::iter::once(key_column.chars().count()))
+                .max()
+                .unwrap_or(0),
+            buckets
+                .iter()
+                .map(|bucket| cell_width(&bucket.total))
+                .chain(std::iter::once(total_column.chars().count()))
+                .max()
+                .unwrap_or(0),
+            "percentage".len(),
+        ],
+    );
+
+    let rows = buckets.into_iter().map(move |bucket| {
+        let ratio = if largest > 0.0 {
+            bucket.size / largest
+        } else {
+            0.0
+        };
+
+        let mut fact = TaggedDictBuilder::new(&name);
+        fact.insert_untagged(&key_column, UntaggedValue::string(bucket.key));
+        fact.insert_value(&total_column, bucket.total);
+        fact.insert_untagged(
+            "percentage",
+            UntaggedValue::string(format!("{:.2}%", ratio * 100.0)),
+        );
+        fact.insert_untagged(&frequency_column, UntaggedValue::string(bar(ratio, width)));
+
+        ReturnSuccess::value(fact.into_value())
+    });
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// Adds up the numbers a group of rows has in the column, skipping the rows without one
+fn sum(rows: &Value, column: &Tagged<String>, tag: &Tag) -> Result<Value, ShellError> {
+    let mut total: Option<Value> = None;
+
+    for cell in rows
+        .table_entries()
+        .filter_map(|row| row.get_data_by_key(column.borrow_spanned()))
+    {
+        if !is_number(&cell) {
+            return Err(ShellError::labeled_error(
+                format!("Can't sum the {} column", column.item),
+                format!("found {}", cell.type_name()),
+                cell.tag.span,
+            ));
+        }
+
+        total = Some(match total {
+            None => cell,
+            Some(total) => match compute_values(Operator::Plus, &total, &cell) {
+                Ok(sum) => sum.into_value(tag),
+                Err((left_type, right_type)) => {
+                    return Err(ShellError::coerce_error(
+                        left_type.spanned(total.tag.span),
+                        right_type.spanned(cell.tag.span),
+                    ))
+                }
+            },
+        });
+    }
+
+    Ok(total.unwrap_or_else(|| UntaggedValue::int(0).into_value(tag)))
+}
+
+fn is_number(value: &Value) -> bool {
+    matches!(
+        value.value,
+        UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Filesize(_))
+            | UntaggedValue::Primitive(Primitive::Duration(_))
     )
-    .to_output_stream())
 }
 
-fn evaluator(by: ColumnPath) -> Box<dyn Fn(usize, &Value) -> Result<Value, ShellError> + Send> {
-    Box::new(move |_: usize, value: &Value| {
-        let path = by.clone();
+/// How big a bucket's total is, for sizing its bar
+fn magnitude(total: &Value) -> f64 {
+    match &total.value {
+        UntaggedValue::Primitive(Primitive::Int(n))
+        | UntaggedValue::Primitive(Primitive::Filesize(n))
+        | UntaggedValue::Primitive(Primitive::Duration(n)) => n.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(n)) => n.to_f64(),
+        _ => None,
+    }
+    .unwrap_or(0.0)
+}
 
-        let eval = nu_value_ext::get_data_by_column_path(value, &path, move |_, _, error| error);
+fn cell_width(value: &Value) -> usize {
+    format_leaf(&value.value)
+        .plain_string(100_000)
+        .chars()
+        .count()
+}
 
-        match eval {
-            Ok(with_value) => Ok(with_value),
-            Err(reason) => Err(reason),
-        }
-    })
+/// What's left of the table's width for the bars once the other columns, padded by a
+/// space on each side and split by a separator, are drawn. Narrow tables still get a
+/// bar wide enough to compare buckets by.
+fn bar_width(table_width: usize, other_columns: &[usize]) -> usize {
+    let taken = other_columns.iter().map(|width| width + 3).sum::<usize>() + 2;
+
+    table_width.saturating_sub(taken).max(10)
 }
 
-fn splitter(
-    by: Option<Tagged<String>>,
-) -> Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send> {
-    match by {
-        Some(column) => Box::new(move |_, row: &Value| {
-            let key = &column;
-
-            match row.get_data_by_key(key.borrow_spanned()) {
-                Some(key) => nu_value_ext::as_string(&key),
-                None => Err(ShellError::labeled_error(
-                    "unknown column",
-                    "unknown column",
-                    key.tag(),
-                )),
-            }
-        }),
-        None => Box::new(move |_, row: &Value| nu_value_ext::as_string(&row)),
+/// A bar of block characters filling `ratio` of `width`, down to an eighth of a character
+fn bar(ratio: f64, width: usize) -> String {
+    const PARTIAL_BLOCKS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    let eighths = (ratio.max(0.0).min(1.0) * (width * 8) as f64).round() as usize;
+
+    let mut bar = "█".repeat(eighths / 8);
+    if eighths % 8 != 0 {
+        bar.push(PARTIAL_BLOCKS[eighths % 8]);
     }
+
+    bar
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Histogram;
-    use super::ShellError;
+    use super::{bar, bar_width, Histogram};
+    use nu_errors::ShellError;
 
     #[test]
     fn examples_work_as_expected() -> Result<(), ShellError> {
@@ -227,4 +296,18 @@ mod tests {
 
         test_examples(Histogram {})
     }
+
+    #[test]
+    fn bars_are_sized_to_the_largest_bucket() {
+        assert_eq!(bar(1.0, 4), "████");
+        assert_eq!(bar(0.5, 4), "██");
+        assert_eq!(bar(0.25, 3), "▊");
+        assert_eq!(bar(0.0, 4), "");
+    }
+
+    #[test]
+    fn bars_take_what_the_other_columns_leave() {
+        assert_eq!(bar_width(80, &[1, 5, 5, 10]), 80 - 4 - 8 - 8 - 13 - 2);
+        assert_eq!(bar_width(20, &[1, 5, 5, 10]), 10);
+    }
 }
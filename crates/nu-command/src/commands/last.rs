@@ -55,7 +55,6 @@ impl WholeStreamCommand for Last {
 
 async fn last(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (LastArgs { rows }, input) = args.process().await?;
-    let v: Vec<_> = input.into_vec().await;
 
     let end_rows_desired = if let Some(quantity) = rows {
         *quantity as usize
@@ -63,15 +62,23 @@ async fn last(args: CommandArgs) -> Result<OutputStream, ShellError> {
         1
     };
 
-    let beginning_rows_to_skip = if end_rows_desired < v.len() {
-        v.len() - end_rows_desired
-    } else {
-        0
-    };
-
-    let iter = v.into_iter().skip(beginning_rows_to_skip);
+    // Only the rows that may still end up in the output are held on to, however long the input
+    let last_rows = input
+        .fold(
+            VecDeque::with_capacity(end_rows_desired),
+            move |mut last_rows, row| {
+                if end_rows_desired > 0 {
+                    if last_rows.len() == end_rows_desired {
+                        last_rows.pop_front();
+                    }
+                    last_rows.push_back(row);
+                }
+                futures::future::ready(last_rows)
+            },
+        )
+        .await;
 
-    Ok(futures::stream::iter(iter).to_output_stream())
+    Ok(futures::stream::iter(last_rows).to_output_stream())
 }
 
 #[cfg(test)]
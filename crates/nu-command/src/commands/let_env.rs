@@ -2,7 +2,9 @@ use crate::prelude::*;
 use nu_engine::{evaluate_baseline_expr, WholeStreamCommand};
 
 use nu_errors::ShellError;
-use nu_protocol::{hir::CapturedBlock, hir::ClassifiedCommand, Signature, SyntaxShape};
+use nu_protocol::{
+    hir::CapturedBlock, hir::ClassifiedCommand, Primitive, Signature, SyntaxShape, UntaggedValue,
+};
 use nu_source::Tagged;
 
 pub struct LetEnv;
@@ -91,7 +93,12 @@ pub async fn set_env(args: CommandArgs) -> Result<OutputStream, ShellError> {
     ctx.scope.exit_scope();
 
     let value = value?;
-    let value = value.as_string()?;
+
+    // Environment variables are strings, so a boolean is stored as `true` or `false`
+    let value = match value.value {
+        UntaggedValue::Primitive(Primitive::Boolean(boolean)) => boolean.to_string(),
+        _ => value.as_string()?,
+    };
 
     let name = name.item.clone();
 
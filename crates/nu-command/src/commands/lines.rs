@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value};
 use parking_lot::Mutex;
 
 pub struct Lines;
@@ -66,13 +66,14 @@ async fn lines(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 } => {
                     let mut leftover_string = leftover_string.lock();
 
-                    let lo_lines = (&*leftover_string).lines().map(|x| x.to_string());
-                    let st_lines = st.lines().map(|x| x.to_string());
-                    let mut lines: Vec<String> = lo_lines.chain(st_lines).collect();
+                    // The leftover is the start of a line, so a `\r\n` split across two
+                    // strings is still a single line ending
+                    let mut text = std::mem::take(&mut *leftover_string);
+                    text.push_str(&st);
 
-                    leftover_string.clear();
+                    let mut lines: Vec<String> = text.lines().map(|x| x.to_string()).collect();
 
-                    if !ends_with_line_ending(&st) {
+                    if !ends_with_line_ending(&text) {
                         if let Some(last) = lines.pop() {
                             leftover_string.push_str(&last);
                         }
@@ -100,14 +101,10 @@ async fn lines(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         futures::stream::iter(vec![])
                     }
                 }
-                Value {
-                    tag: value_span, ..
-                } => futures::stream::iter(vec![Err(ShellError::labeled_error_with_secondary(
-                    "Expected a string from pipeline",
-                    "requires string input",
-                    name_span,
-                    "value originates from here",
-                    value_span,
+                value => futures::stream::iter(vec![Err(ShellError::unexpected_input(
+                    "lines".spanned(name_span),
+                    "string",
+                    value.type_name().spanned(value.tag.span),
                 ))]),
             }
         })
@@ -24,6 +24,7 @@ impl WholeStreamCommand for Ls {
                 "List all available columns for each entry",
                 Some('l'),
             )
+            .switch("full", "The same as --long", None)
             .switch(
                 "short-names",
                 "Only print the file names and not the path",
@@ -34,6 +35,11 @@ impl WholeStreamCommand for Ls {
                 "Display the apparent directory size in place of the directory metadata size",
                 Some('d'),
             )
+            .switch(
+                "recursive",
+                "List the contents of subdirectories too, without following symlinks or going into the ones that can't be read",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -65,6 +71,21 @@ impl WholeStreamCommand for Ls {
                 example: "ls *.rs",
                 result: None,
             },
+            Example {
+                description: "List all rust files in a directory and its subdirectories",
+                example: "ls src/**/*.rs",
+                result: None,
+            },
+            Example {
+                description: "List everything in a directory and its subdirectories",
+                example: "ls --recursive src",
+                result: None,
+            },
+            Example {
+                description: "List all files with the times they were created and accessed, their permissions and where symlinks point to",
+                example: "ls --long",
+                result: None,
+            },
         ]
     }
 }
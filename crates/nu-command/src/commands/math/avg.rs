@@ -1,13 +1,13 @@
 use crate::prelude::*;
 
 use crate::commands::math::reducers::{reducer_for, Reduce};
-use crate::commands::math::utils::run_with_function;
+use crate::commands::math::utils::{expect_numbers, run_with_function_on_column};
 use nu_engine::WholeStreamCommand;
 
 use nu_errors::ShellError;
 use nu_protocol::{
     hir::{convert_number_to_u64, Number, Operator},
-    Primitive, Signature, UntaggedValue, Value,
+    Primitive, Signature, SyntaxShape, UntaggedValue, Value,
 };
 
 use bigdecimal::FromPrimitive;
@@ -21,7 +21,11 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("math avg")
+        Signature::build("math avg").optional(
+            "column",
+            SyntaxShape::ColumnPath,
+            "the column to average",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -29,19 +33,7 @@ impl WholeStreamCommand for SubCommand {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        run_with_function(
-            RunnableContext {
-                input: args.input,
-                scope: args.scope.clone(),
-                shell_manager: args.shell_manager,
-                host: args.host,
-                ctrl_c: args.ctrl_c,
-                current_errors: args.current_errors,
-                name: args.call_info.name_tag,
-            },
-            average,
-        )
-        .await
+        run_with_function_on_column(args, average).await
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -82,6 +74,8 @@ pub fn average(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
         })?
         .is_filesize();
 
+    expect_numbers(values, "average")?;
+
     let total = if are_bytes {
         to_byte(&sum(
             UntaggedValue::int(0).into_untagged_value(),
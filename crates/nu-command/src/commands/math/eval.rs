@@ -1,7 +1,9 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
 use nu_source::Tagged;
 
 pub struct SubCommand;
@@ -83,10 +85,10 @@ pub async fn eval(args: CommandArgs) -> Result<OutputStream, ShellError> {
                         )),
                     }
                 } else {
-                    Err(ShellError::labeled_error(
-                        "Expected a string from pipeline",
-                        "requires string input",
-                        name,
+                    Err(ShellError::unexpected_input(
+                        "math eval".spanned(name),
+                        "string",
+                        input.type_name().spanned(input.tag.span),
                     ))
                 }
             })
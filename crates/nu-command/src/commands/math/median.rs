@@ -72,7 +72,7 @@ pub fn median(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
         sorted.push(item.clone());
     }
 
-    crate::commands::sort_by::sort(&mut sorted, &[], name, false)?;
+    crate::commands::sort_by::sort(&mut sorted, &[], name, false, false)?;
 
     match take {
         Pick::Median => {
@@ -1,10 +1,10 @@
 use crate::commands::math::reducers::{reducer_for, Reduce};
-use crate::commands::math::utils::run_with_function;
+use crate::commands::math::utils::{expect_numbers, run_with_function_on_column};
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 
-use nu_protocol::{Primitive, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
 
 pub struct SubCommand;
 
@@ -15,7 +15,11 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("math sum")
+        Signature::build("math sum").optional(
+            "column",
+            SyntaxShape::ColumnPath,
+            "the column to sum up",
+        )
     }
 
     fn usage(&self) -> &str {
@@ -23,19 +27,7 @@ impl WholeStreamCommand for SubCommand {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        run_with_function(
-            RunnableContext {
-                input: args.input,
-                scope: args.scope.clone(),
-                shell_manager: args.shell_manager,
-                host: args.host,
-                ctrl_c: args.ctrl_c,
-                current_errors: args.current_errors,
-                name: args.call_info.name_tag,
-            },
-            summation,
-        )
-        .await
+        run_with_function_on_column(args, sum_or_zero).await
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -45,6 +37,11 @@ impl WholeStreamCommand for SubCommand {
                 example: "echo [1 2 3] | math sum",
                 result: Some(vec![UntaggedValue::int(6).into()]),
             },
+            Example {
+                description: "Sum the sizes of the files in the current directory",
+                example: "ls | math sum size",
+                result: None,
+            },
             Example {
                 description: "Get the disk usage for the current directory",
                 example: "ls --all --du | get size | math sum",
@@ -63,6 +60,15 @@ fn to_byte(value: &Value) -> Option<Value> {
     }
 }
 
+/// Summing nothing up is 0, where the other aggregates have no answer for empty data
+fn sum_or_zero(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    if values.is_empty() {
+        Ok(UntaggedValue::int(0).into_value(name))
+    } else {
+        summation(values, name)
+    }
+}
+
 pub fn summation(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
     let sum = reducer_for(Reduce::Summation);
 
@@ -74,6 +80,8 @@ pub fn summation(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
         )
     })?;
 
+    expect_numbers(values, "sum")?;
+
     match first {
         v if v.is_filesize() => to_byte(&sum(
             UntaggedValue::int(0).into_untagged_value(),
@@ -1,6 +1,11 @@
 use crate::prelude::*;
+use nu_engine::CommandArgs;
 use nu_errors::ShellError;
-use nu_protocol::{Dictionary, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::{
+    ColumnPath, Dictionary, Primitive, ReturnSuccess, ShellTypeName, UntaggedValue, Value,
+};
+
+use crate::commands::get::get_column_path;
 
 use indexmap::map::IndexMap;
 
@@ -14,7 +19,36 @@ pub async fn run_with_function(
 ) -> Result<OutputStream, ShellError> {
     let values: Vec<Value> = input.drain_vec().await;
 
-    let res = calculate(&values, &name, mf);
+    output(calculate(&values, &name, mf))
+}
+
+#[derive(Deserialize)]
+struct ColumnArgs {
+    column: Option<ColumnPath>,
+}
+
+/// Like `run_with_function`, but first takes the values out of the column given as the command's
+/// argument, if there is one.
+pub async fn run_with_function_on_column(
+    args: CommandArgs,
+    mf: MathFunction,
+) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (ColumnArgs { column }, mut input) = args.process().await?;
+    let values: Vec<Value> = input.drain_vec().await;
+
+    let values = match column {
+        Some(column) => values
+            .iter()
+            .map(|row| get_column_path(&column, row))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => values,
+    };
+
+    output(calculate(&values, &name, mf))
+}
+
+fn output(res: Result<Value, ShellError>) -> Result<OutputStream, ShellError> {
     match res {
         Ok(v) => {
             if v.value.is_table() {
@@ -31,6 +65,30 @@ pub async fn run_with_function(
     }
 }
 
+/// Errors on the first value that isn't a number, a file size or a duration, naming its row.
+pub fn expect_numbers(values: &[Value], operation: &str) -> Result<(), ShellError> {
+    let not_a_number = values.iter().position(|value| {
+        !matches!(
+            value.value,
+            UntaggedValue::Primitive(Primitive::Int(_))
+                | UntaggedValue::Primitive(Primitive::Decimal(_))
+                | UntaggedValue::Primitive(Primitive::Filesize(_))
+                | UntaggedValue::Primitive(Primitive::Duration(_))
+                | UntaggedValue::Primitive(Primitive::Nothing)
+        )
+    });
+
+    match not_a_number {
+        Some(row) => Err(ShellError::untagged_runtime_error(format!(
+            "Cannot {} row {}, it is a {} rather than a number",
+            operation,
+            row,
+            values[row].type_name()
+        ))),
+        None => Ok(()),
+    }
+}
+
 pub type IntFunction = fn(val: BigInt) -> Value;
 
 pub type DecimalFunction = fn(val: BigDecimal) -> Value;
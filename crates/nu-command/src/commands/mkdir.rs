@@ -28,11 +28,23 @@ impl WholeStreamCommand for Mkdir {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Make a directory named foo",
-            example: "mkdir foo",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Make a directory named foo",
+                example: "mkdir foo",
+                result: None,
+            },
+            Example {
+                description: "Make the directories build, build/debug and build/debug/artifacts",
+                example: "mkdir build/debug/artifacts",
+                result: None,
+            },
+            Example {
+                description: "Make several directories at once",
+                example: "mkdir docs tests",
+                result: None,
+            },
+        ]
     }
 }
 
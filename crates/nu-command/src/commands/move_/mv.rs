@@ -23,6 +23,11 @@ impl WholeStreamCommand for Mv {
                 SyntaxShape::FilePath,
                 "the location to move files/directories to",
             )
+            .switch(
+                "force",
+                "overwrite anything already at the destination",
+                Some('f'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -50,6 +55,11 @@ impl WholeStreamCommand for Mv {
                 example: "mv *.txt my/subdirectory",
                 result: None,
             },
+            Example {
+                description: "Rename a file, replacing the one already there",
+                example: "mv --force draft.txt final.txt",
+                result: None,
+            },
         ]
     }
 }
@@ -6,8 +6,10 @@ use log::debug;
 use nu_engine::StringOrBinary;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
+use nu_protocol::hir::{Expression, NamedArguments, SpannedExpression};
 use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::{AnchorLocation, Span, Tagged};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 pub struct Open;
@@ -30,7 +32,7 @@ impl WholeStreamCommand for Open {
             .required(
                 "path",
                 SyntaxShape::FilePath,
-                "the file path to load values from",
+                "the file path or URL to load values from",
             )
             .switch(
                 "raw",
@@ -69,6 +71,11 @@ documentation link at https://docs.rs/encoding_rs/0.8.28/encoding_rs/#statics"#
                 example: "open users.csv",
                 result: None,
             },
+            Example {
+                description: "Fetches a JSON document from the web and creates a table from it",
+                example: "open https://api.github.com/repos/nushell/nushell",
+                result: None,
+            },
             Example {
                 description: "Opens file with iso-8859-1 encoding",
                 example: "open file.csv --encoding iso-8859-1 | from csv",
@@ -100,6 +107,11 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let cwd = PathBuf::from(args.shell_manager.path());
     let shell_manager = args.shell_manager.clone();
 
+    let host = args.host.clone();
+    let ctrl_c = args.ctrl_c.clone();
+    let current_errors = args.current_errors.clone();
+    let mut fetch_call = args.call_info.clone();
+
     let (
         OpenArgs {
             path,
@@ -109,18 +121,48 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
         _,
     ) = args.process().await?;
 
+    if is_url(&path.item) {
+        // URLs are left to `fetch`, which gets the very same arguments along with the configured timeout
+        if let Some(seconds) = configured_fetch_timeout() {
+            let span = fetch_call.name_tag.span;
+            fetch_call
+                .args
+                .named
+                .get_or_insert_with(NamedArguments::new)
+                .insert_mandatory(
+                    "timeout",
+                    span,
+                    SpannedExpression::new(Expression::integer(seconds.into()), span),
+                );
+        }
+
+        let fetch_args = CommandArgs {
+            host,
+            ctrl_c,
+            current_errors,
+            shell_manager,
+            call_info: fetch_call,
+            scope: scope.clone(),
+            input: InputStream::empty(),
+        };
+
+        return match scope.get_command("fetch") {
+            Some(fetch) => fetch.run(fetch_args).await,
+            None => Err(ShellError::labeled_error(
+                "Opening a URL needs the fetch plugin",
+                "can't open URLs without fetch",
+                path.tag.span,
+            )),
+        };
+    }
+
     // TODO: Remove once Streams are supported everywhere!
     // As a short term workaround for getting AutoConvert and Bat functionality (Those don't currently support Streams)
 
     // Check if the extension has a "from *" command OR "bat" supports syntax highlighting
     // AND the user doesn't want the raw output
     // In these cases, we will collect the Stream
-    let ext = if raw.item {
-        None
-    } else {
-        path.extension()
-            .map(|name| name.to_string_lossy().to_string())
-    };
+    let ext = if raw.item { None } else { file_format(&path) };
 
     if let Some(ext) = ext {
         // Check if we have a conversion command
@@ -179,6 +221,50 @@ async fn open(args: CommandArgs) -> Result<OutputStream, ShellError> {
     Ok(OutputStream::new(final_stream))
 }
 
+fn is_url(path: &Path) -> bool {
+    path.to_str()
+        .map(|path| path.starts_with("http://") || path.starts_with("https://"))
+        .unwrap_or(false)
+}
+
+/// The timeout set with `config set fetch.timeout <seconds>`, if any
+fn configured_fetch_timeout() -> Option<u64> {
+    let config = nu_data::config::config(Tag::unknown()).ok()?;
+
+    config
+        .get("fetch")?
+        .row_entries()
+        .find(|(key, _)| *key == "timeout")
+        .and_then(|(_, seconds)| seconds.as_u64().ok())
+}
+
+/// The first bytes of every SQLite database
+const SQLITE_MAGIC_BYTES: &[u8; 16] = b"SQLite format 3\0";
+
+/// The format of the file. SQLite databases are recognized by their contents and a few well known
+/// files without an extension by their name, everything else goes by its extension.
+fn file_format(path: &Path) -> Option<String> {
+    if is_sqlite_database(path) {
+        return Some("sqlite".to_string());
+    }
+
+    match path.file_name().and_then(|name| name.to_str()) {
+        Some(".gitconfig") | Some(".gitmodules") => Some("ini".to_string()),
+        _ => path
+            .extension()
+            .map(|name| name.to_string_lossy().to_string()),
+    }
+}
+
+fn is_sqlite_database(path: &Path) -> bool {
+    let mut magic = [0; 16];
+
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .map(|_| &magic == SQLITE_MAGIC_BYTES)
+        .unwrap_or(false)
+}
+
 // Note that we do not output a Stream in "fetch" since it is only used by "enter" command
 // Which we expect to use a concrete Value a not a Stream
 pub async fn fetch(
@@ -207,9 +293,7 @@ pub async fn fetch(
     })?;
 
     // The extension may be used in AutoConvert later on
-    let ext = location
-        .extension()
-        .map(|name| name.to_string_lossy().to_string());
+    let ext = file_format(&nice_location);
 
     // The tag that will used when returning a Value
     let file_tag = Tag {
@@ -222,6 +306,11 @@ pub async fn fetch(
     let res = std::fs::read(location)
         .map_err(|_| ShellError::labeled_error("Can't open filename given", "can't open", span))?;
 
+    // Databases are binary, however much of them happens to decode as text
+    if ext.as_deref() == Some("sqlite") {
+        return Ok((ext, UntaggedValue::binary(res).into_value(file_tag)));
+    }
+
     // If no encoding is provided we try to guess the encoding to read the file with
     let encoding = if encoding_choice.is_none() {
         UTF_8
@@ -3,6 +3,7 @@ use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
     merge_descriptors, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
 };
 use nu_source::{SpannedItem, Tagged};
 use nu_value_ext::ValueExt;
@@ -49,6 +50,36 @@ impl WholeStreamCommand for Pivot {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         pivot(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        use nu_protocol::row;
+
+        vec![
+            Example {
+                description: "Pivot a record into a table of its column names and values",
+                example: "echo [[name, version]; [nu, '0.28.0']] | pivot",
+                result: Some(vec![
+                    row! { "key".into() => Value::from("name"), "value".into() => Value::from("nu")},
+                    row! { "key".into() => Value::from("version"), "value".into() => Value::from("0.28.0")},
+                ]),
+            },
+            Example {
+                description: "Pivot a table, giving each of its rows a column",
+                example: "echo [[name, stars]; [nu, 5] [bat, 4]] | pivot",
+                result: Some(vec![
+                    row! { "Column0".into() => Value::from("name"), "Column1".into() => Value::from("nu"), "Column2".into() => Value::from("bat")},
+                    row! { "Column0".into() => Value::from("stars"), "Column1".into() => UntaggedValue::int(5).into(), "Column2".into() => UntaggedValue::int(4).into()},
+                ]),
+            },
+            Example {
+                description: "Pivot a table back, using the column names that were pivoted into the first column",
+                example: "echo [[name, stars]; [nu, 5]] | pivot | pivot --header-row",
+                result: Some(vec![
+                    row! { "name".into() => Value::from("nu"), "stars".into() => UntaggedValue::int(5).into()},
+                ]),
+            },
+        ]
+    }
 }
 
 pub async fn pivot(args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -99,6 +130,12 @@ pub async fn pivot(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 ));
             }
         }
+    } else if input.len() == 1 && args.rest.is_empty() {
+        // A single record pivots into the names of its columns and their values
+        if !args.ignore_titles {
+            headers.push("key".to_string());
+        }
+        headers.push("value".to_string());
     } else {
         for i in 0..=input.len() {
             if let Some(name) = args.rest.get(i) {
@@ -1,7 +1,8 @@
 use crate::prelude::*;
+use crate::utils::columns::{spread, unify_columns, with_columns};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{merge_descriptors, Signature, SyntaxShape, UntaggedValue, Value};
 
 #[derive(Deserialize)]
 struct PrependArgs {
@@ -25,7 +26,7 @@ impl WholeStreamCommand for Prepend {
     }
 
     fn usage(&self) -> &str {
-        "Prepend the given row to the front of the table."
+        "Prepend the given rows to the front of the table, giving every row the columns of the others."
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -33,25 +34,54 @@ impl WholeStreamCommand for Prepend {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Add something to the beginning of a list or table",
-            example: "echo [2 3 4] | prepend 1",
-            result: Some(vec![
-                UntaggedValue::int(1).into(),
-                UntaggedValue::int(2).into(),
-                UntaggedValue::int(3).into(),
-                UntaggedValue::int(4).into(),
-            ]),
-        }]
+        use nu_protocol::row;
+
+        vec![
+            Example {
+                description: "Add something to the beginning of a list or table",
+                example: "echo [2 3 4] | prepend 1",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description:
+                    "Add rows having other columns, leaving the cells they are missing empty",
+                example: "echo [[name, stars]; [bat, 5]] | prepend [[name]; [nu]]",
+                result: Some(vec![
+                    row! { "name".into() => Value::from("nu"), "stars".into() => UntaggedValue::nothing().into()},
+                    row! { "name".into() => Value::from("bat"), "stars".into() => UntaggedValue::int(5).into()},
+                ]),
+            },
+        ]
     }
 }
 
 async fn prepend(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let (PrependArgs { row }, input) = args.process().await?;
+    let (PrependArgs { row }, mut input) = args.process().await?;
+
+    // Only the first row of the table is looked at for its columns, so the rest can stream by
+    let first = input.next().await;
+    let head = unify_columns(spread(row).into_iter().chain(first).collect());
+
+    let columns = if head.iter().all(Value::is_row) {
+        merge_descriptors(&head)
+    } else {
+        vec![]
+    };
 
-    let bos = futures::stream::iter(vec![row]);
+    let rest = input.map(move |value| {
+        if columns.is_empty() {
+            value
+        } else {
+            with_columns(value, &columns)
+        }
+    });
 
-    Ok(bos.chain(input).to_output_stream())
+    Ok(futures::stream::iter(head).chain(rest).to_output_stream())
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ use crate::prelude::*;
 use nu_engine::deserializer::NumericRange;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{RangeInclusion, ReturnSuccess, Signature, SyntaxShape};
+use nu_protocol::{RangeInclusion, ReturnSuccess, Signature, SyntaxShape, Value};
 use nu_source::Tagged;
 
 #[derive(Deserialize)]
@@ -20,9 +20,9 @@ impl WholeStreamCommand for Range {
 
     fn signature(&self) -> Signature {
         Signature::build("range").required(
-            "rows ",
+            "rows",
             SyntaxShape::Range,
-            "range of rows to return: Eg) 4..7 (=> from 4 to 7)",
+            "range of rows to return: Eg) 4..7 (=> from 4 to 7), 4.. or ..7",
         )
     }
 
@@ -33,32 +33,54 @@ impl WholeStreamCommand for Range {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         range(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Return the rows 1 through 3",
+                example: "echo [a b c d e] | range 1..3",
+                result: Some(vec![Value::from("b"), Value::from("c"), Value::from("d")]),
+            },
+            Example {
+                description: "Return the rows up to and including row 1",
+                example: "echo [a b c d e] | range ..1",
+                result: Some(vec![Value::from("a"), Value::from("b")]),
+            },
+            Example {
+                description: "Return the rows from row 3 on",
+                example: "echo [a b c d e] | range 3..",
+                result: Some(vec![Value::from("d"), Value::from("e")]),
+            },
+        ]
+    }
 }
 
 async fn range(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (RangeArgs { area }, input) = args.process().await?;
     let range = area.item;
-    let (from, left_inclusive) = range.from;
-    let (to, right_inclusive) = range.to;
-    let from = from.map(|from| *from as usize).unwrap_or(0).saturating_add(
-        if left_inclusive == RangeInclusion::Inclusive {
-            0
-        } else {
-            1
-        },
-    );
-    let to = to
-        .map(|to| *to as usize)
-        .unwrap_or(usize::MAX)
-        .saturating_sub(if right_inclusive == RangeInclusion::Inclusive {
-            0
-        } else {
-            1
-        });
 
+    if let (Some(from), Some(to)) = (range.from.0, range.to.0) {
+        if *to < *from {
+            return Err(ShellError::labeled_error(
+                "Range ends before it starts",
+                format!("{} comes after {}", *from, *to),
+                area.tag.span,
+            ));
+        }
+    }
+
+    let from = range.min() as usize;
+    // One past the last row, so that an exclusive end of 0 selects nothing
+    let end = match range.to {
+        (Some(to), RangeInclusion::Inclusive) => (*to as usize).saturating_add(1),
+        (Some(to), RangeInclusion::Exclusive) => *to as usize,
+        (None, _) => usize::MAX,
+    };
+
+    // Taking stops pulling rows from upstream once the last one is reached
     Ok(input
         .skip(from)
-        .take(to - from + 1)
+        .take(end.saturating_sub(from))
         .map(ReturnSuccess::value)
         .to_output_stream())
 }
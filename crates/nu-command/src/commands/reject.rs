@@ -3,7 +3,9 @@ use nu_data::base::reject_fields;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_source::{SpannedItem, Tagged};
+use nu_value_ext::ValueExt;
+use parking_lot::Mutex;
 
 #[derive(Deserialize)]
 pub struct RejectArgs {
@@ -31,16 +33,24 @@ impl WholeStreamCommand for Reject {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Lists the files in a directory without showing the modified column",
-            example: "ls | reject modified",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Lists the files in a directory without showing the modified column",
+                example: "ls | reject modified",
+                result: None,
+            },
+            Example {
+                description: "Remove a column from a single record",
+                example: "open Cargo.toml | get package | reject authors",
+                result: None,
+            },
+        ]
     }
 }
 
 async fn reject(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
+    let host = args.host.clone();
     let (RejectArgs { rest: fields }, input) = args.process().await?;
     if fields.is_empty() {
         return Err(ShellError::labeled_error(
@@ -52,9 +62,31 @@ async fn reject(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
     let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
 
-    Ok(input
-        .map(move |item| ReturnSuccess::value(reject_fields(&item, &fields, &item.tag)))
-        .to_output_stream())
+    // The columns no row has turned out to have so far
+    let unseen = Arc::new(Mutex::new(fields.clone()));
+
+    let rejected = {
+        let unseen = unseen.clone();
+        input.map(move |item| {
+            unseen
+                .lock()
+                .retain(|field| item.get_data_by_key(field[..].spanned_unknown()).is_none());
+            ReturnSuccess::value(reject_fields(&item, &fields, &item.tag))
+        })
+    };
+
+    // Rejecting a column that isn't there is harmless, though likely a typo
+    let warnings = futures::stream::once(async move {
+        for field in unseen.lock().iter() {
+            host.lock().stderr(&format!(
+                "warning: no row has a column named '{}' to reject",
+                field
+            ));
+        }
+    })
+    .filter_map(|_| futures::future::ready(None));
+
+    Ok(rejected.chain(warnings).to_output_stream())
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
 use crate::prelude::*;
-use indexmap::indexmap;
+use indexmap::{indexmap, IndexSet};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
+use parking_lot::Mutex;
 
 pub struct Rename;
 
@@ -11,6 +12,7 @@ pub struct Rename;
 pub struct Arguments {
     column_name: Tagged<String>,
     rest: Vec<Tagged<String>>,
+    all: bool,
 }
 
 #[async_trait]
@@ -24,9 +26,17 @@ impl WholeStreamCommand for Rename {
             .required(
                 "column_name",
                 SyntaxShape::String,
-                "the new name for the first column",
+                "the column to rename (or with --all, the new name for the first column)",
+            )
+            .rest(
+                SyntaxShape::String,
+                "the new name, followed by more columns and their new names",
+            )
+            .switch(
+                "all",
+                "give every column a new name, in the order of the columns",
+                Some('a'),
             )
-            .rest(SyntaxShape::String, "the new name for additional columns")
     }
 
     fn usage(&self) -> &str {
@@ -41,7 +51,7 @@ impl WholeStreamCommand for Rename {
         vec![
             Example {
                 description: "Rename a column",
-                example: "echo [[a, b]; [1, 2]] | rename my_column",
+                example: "echo [[a, b]; [1, 2]] | rename a my_column",
                 result: Some(vec![UntaggedValue::row(indexmap! {
                         "my_column".to_string() => UntaggedValue::int(1).into(),
                         "b".to_string() => UntaggedValue::int(2).into(),
@@ -50,7 +60,17 @@ impl WholeStreamCommand for Rename {
             },
             Example {
                 description: "Rename many columns",
-                example: "echo [[a, b, c]; [1, 2, 3]] | rename eggs ham bacon",
+                example: "echo [[a, b, c]; [1, 2, 3]] | rename a eggs c bacon",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "eggs".to_string() => UntaggedValue::int(1).into(),
+                        "b".to_string() => UntaggedValue::int(2).into(),
+                        "bacon".to_string() => UntaggedValue::int(3).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Give every column a new name",
+                example: "echo [[a, b, c]; [1, 2, 3]] | rename --all eggs ham bacon",
                 result: Some(vec![UntaggedValue::row(indexmap! {
                         "eggs".to_string() => UntaggedValue::int(1).into(),
                         "ham".to_string() => UntaggedValue::int(2).into(),
@@ -64,46 +84,138 @@ impl WholeStreamCommand for Rename {
 
 pub async fn rename(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
-    let (Arguments { column_name, rest }, input) = args.process().await?;
-    let mut new_column_names = vec![vec![column_name]];
-    new_column_names.push(rest);
+    let (
+        Arguments {
+            column_name,
+            rest,
+            all,
+        },
+        input,
+    ) = args.process().await?;
+    let names = std::iter::once(column_name).chain(rest).collect::<Vec<_>>();
+
+    if all {
+        return Ok(input
+            .map(move |item| match item {
+                Value {
+                    value: UntaggedValue::Row(row),
+                    tag,
+                } => {
+                    if row.entries.len() != names.len() {
+                        return Err(ShellError::labeled_error(
+                            format!(
+                                "{} new names given for {} columns",
+                                names.len(),
+                                row.entries.len()
+                            ),
+                            "--all needs a new name for every column",
+                            &name,
+                        ));
+                    }
+
+                    let renamed_row = row
+                        .entries
+                        .into_iter()
+                        .zip(names.iter())
+                        .map(|((_, value), new_name)| (new_name.item.clone(), value))
+                        .collect::<IndexMap<_, _>>();
+
+                    ReturnSuccess::value(UntaggedValue::Row(renamed_row.into()).into_value(tag))
+                }
+                _ => ReturnSuccess::value(no_columns(&name)),
+            })
+            .to_output_stream());
+    }
+
+    if names.len() % 2 == 1 {
+        let last = &names[names.len() - 1];
+        return Err(ShellError::labeled_error(
+            format!("Missing the new name for column '{}'", last.item),
+            "needs a new name",
+            &last.tag,
+        ));
+    }
+
+    let renames = names
+        .chunks(2)
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect::<Vec<_>>();
+
+    // The columns to rename that no row has turned out to have so far, and the columns seen
+    let unseen = Arc::new(Mutex::new(renames.clone()));
+    let available = Arc::new(Mutex::new(IndexSet::new()));
 
-    let new_column_names = new_column_names.into_iter().flatten().collect::<Vec<_>>();
+    let renamed = {
+        let unseen = unseen.clone();
+        let available = available.clone();
 
-    Ok(input
-        .map(move |item| {
-            if let Value {
+        input.map(move |item| match item {
+            Value {
                 value: UntaggedValue::Row(row),
                 tag,
-            } = item
-            {
+            } => {
+                available.lock().extend(row.entries.keys().cloned());
+                unseen
+                    .lock()
+                    .retain(|(from, _)| !row.entries.contains_key(&from.item));
+
                 let mut renamed_row = IndexMap::new();
 
-                for (idx, (key, value)) in row.entries.iter().enumerate() {
-                    let key = if idx < new_column_names.len() {
-                        &new_column_names[idx].item
-                    } else {
-                        key
+                for (key, value) in row.entries.iter() {
+                    let key = match renames.iter().find(|(from, _)| from.item == *key) {
+                        Some((_, to)) => {
+                            // Unless it is renamed too, the column already there would be lost
+                            if row.entries.contains_key(&to.item)
+                                && !renames.iter().any(|(from, _)| from.item == to.item)
+                            {
+                                return Err(ShellError::labeled_error(
+                                    format!("Column '{}' already exists", to.item),
+                                    "renaming onto it would lose its values",
+                                    &to.tag,
+                                ));
+                            }
+
+                            &to.item
+                        }
+                        None => key,
                     };
 
                     renamed_row.insert(key.clone(), value.clone());
                 }
 
-                let out = UntaggedValue::Row(renamed_row.into()).into_value(tag);
-
-                ReturnSuccess::value(out)
-            } else {
-                ReturnSuccess::value(
-                    UntaggedValue::Error(ShellError::labeled_error(
-                        "no column names available",
-                        "can't rename",
-                        &name,
-                    ))
-                    .into_untagged_value(),
-                )
+                ReturnSuccess::value(UntaggedValue::Row(renamed_row.into()).into_value(tag))
             }
+            _ => ReturnSuccess::value(no_columns(&name)),
+        })
+    };
+
+    // Not finding the column on any row at all is likely a typo
+    let missing = futures::stream::once(async move {
+        let available = available.lock().iter().cloned().collect::<Vec<_>>();
+        if available.is_empty() {
+            return None;
+        }
+
+        unseen.lock().first().map(|(from, _)| -> ReturnValue {
+            Err(ShellError::labeled_error(
+                format!("Unknown column '{}'", from.item),
+                format!("available columns: {}", available.join(", ")),
+                &from.tag,
+            ))
         })
-        .to_output_stream())
+    })
+    .filter_map(futures::future::ready);
+
+    Ok(renamed.chain(missing).to_output_stream())
+}
+
+fn no_columns(name: &Tag) -> Value {
+    UntaggedValue::Error(ShellError::labeled_error(
+        "no column names available",
+        "can't rename",
+        name,
+    ))
+    .into_untagged_value()
 }
 
 #[cfg(test)]
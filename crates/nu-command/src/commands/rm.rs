@@ -2,7 +2,8 @@ use crate::prelude::*;
 use nu_engine::shell::RemoveArgs;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
+use nu_protocol::{ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
+use std::path::PathBuf;
 
 pub struct Remove;
 
@@ -26,7 +27,10 @@ impl WholeStreamCommand for Remove {
             )
             .switch("recursive", "delete subdirectories recursively", Some('r'))
             .switch("force", "suppress error when no file", Some('f'))
-            .rest(SyntaxShape::GlobPattern, "the file path(s) to remove")
+            .rest(
+                SyntaxShape::GlobPattern,
+                "the file path(s) to remove (taken from the input rows if none are given)",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -58,7 +62,17 @@ impl WholeStreamCommand for Remove {
                 description: "Delete a file, and suppress errors if no file is found",
                 example: "rm --force file.txt",
                 result: None,
-            }
+            },
+            Example {
+                description: "Delete a directory and everything in it",
+                example: "rm --recursive build",
+                result: None,
+            },
+            Example {
+                description: "Delete the empty files in the current directory, carrying on past any that can't be deleted (unlike `each { rm $it }`, which stops at the first)",
+                example: "ls | where size == 0b | select name | rm",
+                result: None,
+            },
         ]
     }
 }
@@ -66,7 +80,7 @@ impl WholeStreamCommand for Remove {
 async fn rm(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name = args.call_info.name_tag.clone();
     let shell_manager = args.shell_manager.clone();
-    let (args, _): (RemoveArgs, _) = args.process().await?;
+    let (args, input): (RemoveArgs, _) = args.process().await?;
 
     if args.trash.item && args.permanent.item {
         return Ok(OutputStream::one(Err(ShellError::labeled_error(
@@ -76,7 +90,99 @@ async fn rm(args: CommandArgs) -> Result<OutputStream, ShellError> {
         ))));
     }
 
-    shell_manager.rm(args, name)
+    if !args.rest.is_empty() {
+        return shell_manager.rm(args, name);
+    }
+
+    let rows: Vec<Value> = input.collect().await;
+    if rows.is_empty() {
+        return shell_manager.rm(args, name);
+    }
+
+    // Each row is removed on its own, so that a path that can't be removed doesn't stop the
+    // ones after it. Everything that failed is listed in an error at the end. Only rows piped
+    // in get this: a path given as an argument, as with `each { rm $it }`, is a single removal
+    // whose error ends the pipeline like any other.
+    let total = rows.len();
+    let mut output = vec![];
+    let mut failures = vec![];
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let target = match path_from(&row) {
+            Ok(target) => target,
+            Err(err) => {
+                failures.push(format!("row {}: {}", index, reason(err)));
+                continue;
+            }
+        };
+
+        // Paths from rows are taken as they are, rather than as patterns
+        let pattern = glob::Pattern::escape(&target.to_string_lossy());
+        let args = RemoveArgs {
+            rest: vec![PathBuf::from(pattern).tagged(&row.tag)],
+            recursive: args.recursive.clone(),
+            trash: args.trash.clone(),
+            permanent: args.permanent.clone(),
+            force: args.force.clone(),
+        };
+
+        let results: Vec<ReturnValue> = match shell_manager.rm(args, name.clone()) {
+            Ok(stream) => stream.collect().await,
+            Err(err) => vec![Err(err)],
+        };
+
+        for result in results {
+            match result {
+                Ok(value) => output.push(Ok(value)),
+                Err(err) => failures.push(format!("{}: {}", target.display(), reason(err))),
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        output.push(Err(ShellError::labeled_error(
+            format!(
+                "Could not remove {} of {} paths:\n{}",
+                failures.len(),
+                total,
+                failures.join("\n")
+            ),
+            "some paths could not be removed",
+            name,
+        )));
+    }
+
+    Ok(futures::stream::iter(output).to_output_stream())
+}
+
+/// The path a row names, either as a string, a row with a single column or the name column
+/// `ls` gives
+fn path_from(row: &Value) -> Result<PathBuf, ShellError> {
+    if let UntaggedValue::Row(dict) = &row.value {
+        let cell = if dict.entries.len() == 1 {
+            dict.entries.values().next()
+        } else {
+            dict.entries.get("name")
+        };
+
+        return match cell {
+            Some(cell) => cell.as_path(),
+            None => Err(ShellError::labeled_error(
+                "Expected a path",
+                "a row needs a single column or a name column",
+                &row.tag,
+            )),
+        };
+    }
+
+    row.as_path()
+}
+
+fn reason(err: ShellError) -> String {
+    match err.clone().into_diagnostic() {
+        Some(diagnostic) => diagnostic.message,
+        None => err.to_string(),
+    }
 }
 
 #[cfg(test)]
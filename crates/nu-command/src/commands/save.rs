@@ -87,6 +87,7 @@ macro_rules! process_string_return_success {
                 })) => {
                     result_string.push_str(&s);
                 }
+                Err(err) => break $scope Err(err),
                 _ => {
                     break $scope Err(ShellError::labeled_error(
                         "Save could not successfully save",
@@ -113,6 +114,7 @@ macro_rules! process_binary_return_success {
                         result_binary.push(u);
                     }
                 }
+                Err(err) => break $scope Err(err),
                 _ => {
                     break $scope Err(ShellError::labeled_error(
                         "Save could not successfully save",
@@ -130,6 +132,7 @@ macro_rules! process_binary_return_success {
 pub struct SaveArgs {
     path: Option<Tagged<PathBuf>>,
     raw: bool,
+    append: bool,
 }
 
 #[async_trait]
@@ -150,6 +153,11 @@ impl WholeStreamCommand for Save {
                 "treat values as-is rather than auto-converting based on file extension",
                 Some('r'),
             )
+            .switch(
+                "append",
+                "append to the end of the file instead of replacing it",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -176,6 +184,7 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         SaveArgs {
             path,
             raw: save_raw,
+            append,
         },
         input,
     ) = raw_args.process().await?;
@@ -200,10 +209,20 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
                 name_tag.clone(),
             ));
         }
-    } else if let Some(file) = path {
+    } else if let Some(file) = &path {
         full_path.push(file.item());
     }
 
+    if let Some(directory) = full_path.parent() {
+        if !directory.exists() {
+            return Err(ShellError::labeled_error(
+                format!("Directory {} does not exist", directory.display()),
+                "the directory to save into must exist",
+                path.map(|path| path.tag).unwrap_or_else(|| name_tag.clone()),
+            ));
+        }
+    }
+
     // TODO use label_break_value once it is stable:
     // https://github.com/rust-lang/rust/issues/48594
     #[allow(clippy::never_loop)]
@@ -248,7 +267,7 @@ async fn save(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
         };
     };
 
-    shell_manager.save(&full_path, &content?, name.span)
+    shell_manager.save(&full_path, &content?, name.span, append)
 }
 
 fn string_from(input: &[Value]) -> String {
@@ -262,9 +281,7 @@ fn string_from(input: &[Value]) -> String {
             } else {
                 first = false;
             }
-            if let Ok(data) = &i.as_string() {
-                save_data.push_str(data);
-            }
+            save_data.push_str(&i.convert_to_string());
         }
     }
 
@@ -3,10 +3,11 @@ use crate::utils::arguments::arguments;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
-    PathMember, Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder,
-    UnspannedPathMember, UntaggedValue, Value,
+    ColumnPath, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
 };
-use nu_value_ext::{as_string, get_data_by_column_path};
+use nu_value_ext::get_data_by_column_path;
+
+use super::get::get_column_path;
 
 #[derive(Deserialize)]
 struct Arguments {
@@ -45,6 +46,11 @@ impl WholeStreamCommand for Command {
                 example: "ls | select name size",
                 result: None,
             },
+            Example {
+                description: "Select a nested column, which is named after its last member",
+                example: "open Cargo.toml | select package.version",
+                result: None,
+            },
         ]
     }
 }
@@ -62,99 +68,96 @@ async fn select(args: CommandArgs) -> Result<OutputStream, ShellError> {
         ));
     }
 
-    let mut bring_back: indexmap::IndexMap<String, Vec<Value>> = indexmap::IndexMap::new();
+    let names = column_names(&columns);
+    let mut found = vec![false; columns.len()];
+    let mut rows = vec![];
 
     while let Some(value) = input.next().await {
-        for path in &columns {
-            let fetcher = get_data_by_column_path(
-                &value,
-                &path,
-                move |obj_source, path_member_tried, error| {
-                    if let PathMember {
-                        unspanned: UnspannedPathMember::String(column),
-                        ..
-                    } = path_member_tried
-                    {
-                        return ShellError::labeled_error_with_secondary(
-                        "No data to fetch.",
-                        format!("Couldn't select column \"{}\"", column),
-                        path_member_tried.span,
-                        "How about exploring it with \"get\"? Check the input is appropriate originating from here",
-                        obj_source.tag.span);
-                    }
-
-                    error
-                },
-            );
-
-            let field = path.clone();
-            let key = as_string(
-                &UntaggedValue::Primitive(Primitive::ColumnPath(field.clone()))
-                    .into_untagged_value(),
-            )?;
-
-            match fetcher {
-                Ok(results) => match results.value {
-                    UntaggedValue::Table(records) => {
-                        for x in records {
-                            let mut out = TaggedDictBuilder::new(name.clone());
-                            out.insert_untagged(&key, x.value.clone());
-                            let group = bring_back.entry(key.clone()).or_insert(vec![]);
-                            group.push(out.into_value());
-                        }
-                    }
-                    x => {
-                        let mut out = TaggedDictBuilder::new(name.clone());
-                        out.insert_untagged(&key, x.clone());
-                        let group = bring_back.entry(key.clone()).or_insert(vec![]);
-                        group.push(out.into_value());
-                    }
-                },
-                Err(reason) => {
-                    // At the moment, we can't add switches, named flags
-                    // and the like while already using .rest since it
-                    // breaks the parser.
-                    //
-                    // We allow flexibility for now and skip the error
-                    // if a given column isn't present.
-                    let strict: Option<bool> = None;
-
-                    if strict.is_some() {
-                        return Err(reason);
-                    }
-
-                    bring_back.entry(key.clone()).or_insert(vec![]);
+        let mut selected = vec![];
+
+        for (index, path) in columns.iter().enumerate() {
+            match get_data_by_column_path(&value, &path, |_, _, error| error) {
+                Ok(results) => {
+                    found[index] = true;
+                    selected.push(results.value);
                 }
+                // A column can be missing from some of the rows, those get an empty cell
+                Err(_) => selected.push(UntaggedValue::nothing()),
             }
         }
-    }
 
-    let mut max = 0;
+        rows.push((value, selected));
+    }
 
-    if let Some(max_column) = bring_back.values().max() {
-        max = max_column.len();
+    // Not finding the column on any row at all is likely a typo
+    if let (Some(missing), Some((first, _))) = (found.iter().position(|found| !found), rows.first())
+    {
+        get_column_path(&columns[missing], first)?;
     }
 
-    let keys = bring_back.keys().cloned().collect::<Vec<String>>();
+    // A path into a table gives a row for each of its values
+    let selected = rows.into_iter().flat_map(move |(value, selected)| {
+        let height = selected
+            .iter()
+            .map(|value| match value {
+                UntaggedValue::Table(rows) => rows.len(),
+                _ => 1,
+            })
+            .max()
+            .unwrap_or(1);
+
+        let names = names.clone();
+        let tag = value.tag;
+        (0..height).map(move |current| {
+            let mut out = TaggedDictBuilder::new(&tag);
+
+            for (name, value) in names.iter().zip(selected.iter()) {
+                let value = match value {
+                    UntaggedValue::Table(rows) => rows
+                        .get(current)
+                        .map(|row| row.value.clone())
+                        .unwrap_or_else(UntaggedValue::nothing),
+                    value if current == 0 => value.clone(),
+                    _ => UntaggedValue::nothing(),
+                };
+
+                out.insert_untagged(name, value);
+            }
 
-    Ok(futures::stream::iter((0..max).map(move |current| {
-        let mut out = TaggedDictBuilder::new(name.clone());
+            ReturnSuccess::value(out.into_value())
+        })
+    });
 
-        for k in &keys {
-            let new_key = k.replace(".", "_");
-            let nothing = UntaggedValue::Primitive(Primitive::Nothing).into_untagged_value();
-            let subsets = bring_back.get(k);
+    Ok(futures::stream::iter(selected).to_output_stream())
+}
 
-            match subsets {
-                Some(set) => match set.get(current) {
-                    Some(row) => out.insert_untagged(new_key, row.get_data(k).borrow().clone()),
-                    None => out.insert_untagged(new_key, nothing.clone()),
-                },
-                None => out.insert_untagged(new_key, nothing.clone()),
+/// The selected columns are named after the last member of their path, unless that would give
+/// two of them the same name
+fn column_names(columns: &[ColumnPath]) -> Vec<String> {
+    let last_member = |path: &ColumnPath| {
+        path.last()
+            .map(|member| member.as_string())
+            .unwrap_or_default()
+    };
+
+    columns
+        .iter()
+        .map(|path| {
+            let name = last_member(path);
+            let clashes = columns
+                .iter()
+                .filter(|other| *other != path && last_member(other) == name)
+                .count()
+                > 0;
+
+            if clashes {
+                path.iter()
+                    .map(|member| member.as_string())
+                    .collect::<Vec<_>>()
+                    .join("_")
+            } else {
+                name
             }
-        }
-
-        ReturnSuccess::value(out.into_value())
-    }))
-    .to_output_stream())
+        })
+        .collect()
 }
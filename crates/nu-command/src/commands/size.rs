@@ -4,9 +4,17 @@ use crate::prelude::*;
 use indexmap::indexmap;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, TaggedDictBuilder, UntaggedValue, Value,
+};
 use unicode_segmentation::UnicodeSegmentation;
 
+#[derive(Deserialize)]
+struct SizeArgs {
+    #[serde(rename(deserialize = "per-row"))]
+    per_row: bool,
+}
+
 pub struct Size;
 
 #[async_trait]
@@ -16,7 +24,11 @@ impl WholeStreamCommand for Size {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("size")
+        Signature::build("size").switch(
+            "per-row",
+            "gather the statistics for each row rather than across all of them",
+            Some('r'),
+        )
     }
 
     fn usage(&self) -> &str {
@@ -24,7 +36,7 @@ impl WholeStreamCommand for Size {
     }
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
-        Ok(size(args))
+        size(args).await
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -51,33 +63,180 @@ impl WholeStreamCommand for Size {
                 })
                 .into()]),
             },
+            Example {
+                description: "Gather the statistics across all the lines",
+                example: r#"echo "one two" "three" | size"#,
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                        "lines".to_string() => UntaggedValue::int(2).into(),
+                        "words".to_string() => UntaggedValue::int(3).into(),
+                        "chars".to_string() => UntaggedValue::int(12).into(),
+                        "bytes".to_string() => UntaggedValue::int(12).into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Gather the statistics of each line",
+                example: r#"echo "one two" "three" | size --per-row | get words"#,
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
         ]
     }
 }
 
-fn size(args: CommandArgs) -> OutputStream {
-    let input = args.input;
-    let tag = args.call_info.name_tag;
-    let name_span = tag.span;
-
-    input
-        .map(move |v| {
-            if let Ok(s) = v.as_string() {
-                ReturnSuccess::value(count(&s, &v.tag))
-            } else {
-                Err(ShellError::labeled_error_with_secondary(
-                    "Expected a string from pipeline",
-                    "requires string input",
-                    name_span,
-                    "value originates from here",
-                    v.tag.span,
-                ))
+#[derive(Default, Clone, Copy)]
+struct TextCounts {
+    lines: i64,
+    words: i64,
+    chars: i64,
+}
+
+/// The statistics gathered on a value. Binary data only has a byte count.
+struct Counts {
+    bytes: i64,
+    text: Option<TextCounts>,
+}
+
+impl Counts {
+    fn add(&mut self, other: Counts) {
+        self.bytes += other.bytes;
+        self.text = match (self.text, other.text) {
+            (Some(a), Some(b)) => Some(TextCounts {
+                lines: a.lines + b.lines,
+                words: a.words + b.words,
+                chars: a.chars + b.chars,
+            }),
+            (a, b) => a.or(b),
+        };
+    }
+
+    fn into_value(self, tag: impl Into<Tag>) -> Value {
+        let text_column = |count: Option<i64>| match count {
+            Some(count) => UntaggedValue::int(count),
+            None => UntaggedValue::nothing(),
+        };
+
+        let mut dict = TaggedDictBuilder::new(tag);
+        //TODO: add back in name when we have it in the tag
+        //dict.insert("name", value::string(name));
+        dict.insert_untagged("lines", text_column(self.text.map(|t| t.lines)));
+        dict.insert_untagged("words", text_column(self.text.map(|t| t.words)));
+        dict.insert_untagged("chars", text_column(self.text.map(|t| t.chars)));
+        dict.insert_untagged("bytes", UntaggedValue::int(self.bytes));
+
+        dict.into_value()
+    }
+}
+
+async fn size(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag.clone();
+    let (SizeArgs { per_row }, input) = args.process().await?;
+
+    if per_row {
+        return Ok(input
+            .map(move |v| {
+                let counts = count_value(&v, name.span)?;
+                ReturnSuccess::value(counts.into_value(&v.tag))
+            })
+            .to_output_stream());
+    }
+
+    let pieces = join_pieces(input.collect::<Vec<_>>().await);
+    let several = pieces.len() > 1;
+
+    let mut total = Counts {
+        bytes: 0,
+        text: if pieces.is_empty() {
+            Some(TextCounts::default())
+        } else {
+            None
+        },
+    };
+
+    for piece in &pieces {
+        total.add(count_value(piece, name.span)?);
+
+        // Each of several strings is a line of its own, as if `lines` had split them
+        if several {
+            if let (Ok(s), Some(text)) = (piece.as_string(), total.text.as_mut()) {
+                if !s.ends_with('\n') {
+                    text.lines += 1;
+                }
+            }
+        }
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        total.into_value(&name),
+    )))
+}
+
+/// Files and URLs are streamed in chunks which all carry the tag of where they came from. The
+/// chunks are joined back together so that words and lines aren't cut in two, and a file that is
+/// partly binary is counted as binary.
+fn join_pieces(values: Vec<Value>) -> Vec<Value> {
+    let mut pieces: Vec<Value> = vec![];
+
+    for value in values {
+        let last = match pieces.last_mut() {
+            Some(last) if last.tag.anchor.is_some() && last.tag == value.tag => last,
+            _ => {
+                pieces.push(value);
+                continue;
+            }
+        };
+
+        match (&mut last.value, value.value) {
+            (
+                UntaggedValue::Primitive(Primitive::String(s)),
+                UntaggedValue::Primitive(Primitive::String(more)),
+            ) => s.push_str(&more),
+            (
+                UntaggedValue::Primitive(Primitive::Binary(b)),
+                UntaggedValue::Primitive(Primitive::Binary(more)),
+            ) => b.extend(more),
+            (
+                UntaggedValue::Primitive(Primitive::String(s)),
+                UntaggedValue::Primitive(Primitive::Binary(more)),
+            ) => {
+                let mut bytes = std::mem::take(s).into_bytes();
+                bytes.extend(more);
+                last.value = UntaggedValue::binary(bytes);
             }
-        })
-        .to_output_stream()
+            (
+                UntaggedValue::Primitive(Primitive::Binary(b)),
+                UntaggedValue::Primitive(Primitive::String(more)),
+            ) => b.extend(more.into_bytes()),
+            (_, other) => pieces.push(Value {
+                value: other,
+                tag: value.tag,
+            }),
+        }
+    }
+
+    pieces
 }
 
-fn count(contents: &str, tag: impl Into<Tag>) -> Value {
+fn count_value(value: &Value, name_span: Span) -> Result<Counts, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Binary(b)) => Ok(Counts {
+            bytes: b.len() as i64,
+            text: None,
+        }),
+        _ => match value.as_string() {
+            Ok(s) => Ok(count(&s)),
+            Err(_) => Err(ShellError::unexpected_input(
+                "size".spanned(name_span),
+                "string or binary",
+                value.type_name().spanned(value.tag.span),
+            )),
+        },
+    }
+}
+
+fn count(contents: &str) -> Counts {
     let mut lines: i64 = 0;
     let mut words: i64 = 0;
     let mut chars: i64 = 0;
@@ -88,7 +247,7 @@ fn count(contents: &str, tag: impl Into<Tag>) -> Value {
         chars += 1;
 
         match c {
-            "\n" => {
+            "\n" | "\r\n" => {
                 lines += 1;
                 end_of_word = true;
             }
@@ -102,15 +261,14 @@ fn count(contents: &str, tag: impl Into<Tag>) -> Value {
         }
     }
 
-    let mut dict = TaggedDictBuilder::new(tag);
-    //TODO: add back in name when we have it in the tag
-    //dict.insert("name", value::string(name));
-    dict.insert_untagged("lines", UntaggedValue::int(lines));
-    dict.insert_untagged("words", UntaggedValue::int(words));
-    dict.insert_untagged("chars", UntaggedValue::int(chars));
-    dict.insert_untagged("bytes", UntaggedValue::int(bytes));
-
-    dict.into_value()
+    Counts {
+        bytes,
+        text: Some(TextCounts {
+            lines,
+            words,
+            chars,
+        }),
+    }
 }
 
 #[cfg(test)]
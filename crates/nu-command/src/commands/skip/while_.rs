@@ -1,12 +1,16 @@
 use crate::prelude::*;
-use log::trace;
-use nu_engine::evaluate_baseline_expr;
+use crate::utils::condition::{skip_while, Condition};
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{hir::ClassifiedCommand, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{hir::CapturedBlock, Signature, SyntaxShape};
 
 pub struct SubCommand;
 
+#[derive(Deserialize)]
+pub struct Arguments {
+    block: CapturedBlock,
+}
+
 #[async_trait]
 impl WholeStreamCommand for SubCommand {
     fn name(&self) -> &str {
@@ -29,74 +33,26 @@ impl WholeStreamCommand for SubCommand {
 
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         let ctx = Arc::new(EvaluationContext::from_args(&args));
-        let call_info = args.evaluate_once().await?;
-
-        let block = call_info.args.expect_nth(0)?.clone();
-
-        let (condition, captured) = match block {
-            Value {
-                value: UntaggedValue::Block(captured_block),
-                tag,
-            } => {
-                if captured_block.block.block.len() != 1 {
-                    return Err(ShellError::labeled_error(
-                        "Expected a condition",
-                        "expected a condition",
-                        tag,
-                    ));
-                }
-                match captured_block.block.block[0].pipelines.get(0) {
-                    Some(item) => match item.list.get(0) {
-                        Some(ClassifiedCommand::Expr(expr)) => {
-                            (Arc::new(expr.clone()), captured_block.captured.clone())
-                        }
-                        _ => {
-                            return Err(ShellError::labeled_error(
-                                "Expected a condition",
-                                "expected a condition",
-                                tag,
-                            ));
-                        }
-                    },
-                    None => {
-                        return Err(ShellError::labeled_error(
-                            "Expected a condition",
-                            "expected a condition",
-                            tag,
-                        ));
-                    }
-                }
-            }
-            Value { tag, .. } => {
-                return Err(ShellError::labeled_error(
-                    "Expected a condition",
-                    "expected a condition",
-                    tag,
-                ));
-            }
-        };
+        let tag = args.call_info.name_tag.clone();
+        let (Arguments { block }, input) = args.process().await?;
+        let condition = Condition::new(block, tag)?;
 
-        Ok(call_info
-            .input
-            .skip_while(move |item| {
-                let item = item.clone();
-                let condition = condition.clone();
-                let ctx = ctx.clone();
-
-                ctx.scope.enter_scope();
-                ctx.scope.add_vars(&captured.entries);
-                ctx.scope.add_var("$it", item.clone());
-                trace!("ITEM = {:?}", item);
-
-                async move {
-                    let result = evaluate_baseline_expr(&*condition, &*ctx).await;
-                    ctx.scope.exit_scope();
-                    trace!("RESULT = {:?}", result);
+        Ok(skip_while(input, condition, true, ctx))
+    }
 
-                    matches!(result, Ok(ref v) if v.is_true())
-                }
-            })
-            .to_output_stream())
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Skip the comment lines at the start of a file",
+                example: r##"open --raw log.txt | lines | skip while $it =~ "#""##,
+                result: None,
+            },
+            Example {
+                description: "Skip the files smaller than 1kb, until the first one that isn't",
+                example: r#"ls | skip while { $it.size < 1kb }"#,
+                result: None,
+            },
+        ]
     }
 }
 
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+
+pub struct SkipBytes;
+
+#[derive(Deserialize)]
+pub struct SkipBytesArgs {
+    bytes: Tagged<usize>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SkipBytes {
+    fn name(&self) -> &str {
+        "skip-bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("skip-bytes").required(
+            "bytes",
+            SyntaxShape::Int,
+            "starting from the front, the number of bytes to skip",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Skip some number of bytes of binary data."
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        skip_bytes(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show an image without its 8 byte header",
+            example: "open image.png --raw | skip-bytes 8",
+            result: None,
+        }]
+    }
+}
+
+async fn skip_bytes(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_span = args.call_info.name_tag.span;
+    let (SkipBytesArgs { bytes }, input) = args.process().await?;
+
+    // Large files come in chunks, so the bytes are skipped across as many as needed
+    let mut to_skip = *bytes;
+
+    Ok(input
+        .map(move |value| {
+            let result = match value.value {
+                UntaggedValue::Primitive(Primitive::Binary(b)) if b.len() <= to_skip => {
+                    to_skip -= b.len();
+                    vec![]
+                }
+                UntaggedValue::Primitive(Primitive::Binary(b)) => {
+                    let rest = b[to_skip..].to_vec();
+                    to_skip = 0;
+                    vec![ReturnSuccess::value(Value {
+                        value: UntaggedValue::binary(rest),
+                        tag: value.tag,
+                    })]
+                }
+                other => vec![Err(ShellError::unexpected_input(
+                    "skip-bytes".spanned(name_span),
+                    "binary",
+                    other.type_name().spanned(value.tag.span),
+                ))],
+            };
+
+            futures::stream::iter(result)
+        })
+        .flatten()
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::SkipBytes;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(SkipBytes {})
+    }
+}
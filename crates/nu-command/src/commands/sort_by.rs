@@ -2,9 +2,12 @@ use crate::prelude::*;
 use nu_data::base::coerce_compare;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::Tagged;
+use nu_protocol::{
+    did_you_mean, ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{SpannedItem, Tagged};
 use nu_value_ext::ValueExt;
+use std::cmp::Ordering;
 
 pub struct SortBy;
 
@@ -29,7 +32,10 @@ impl WholeStreamCommand for SortBy {
                 Some('i'),
             )
             .switch("reverse", "Sort in reverse order", Some('r'))
-            .rest(SyntaxShape::String, "the column(s) to sort by")
+            .rest(
+                SyntaxShape::String,
+                "the column(s) to sort by, which may be nested paths like package.name",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -125,20 +131,20 @@ async fn sort_by(args: CommandArgs) -> Result<OutputStream, ShellError> {
     ) = args.process().await?;
     let mut vec = input.drain_vec().await;
 
-    sort(&mut vec, &rest, &tag, insensitive)?;
-
-    if reverse {
-        vec.reverse()
-    }
+    sort(&mut vec, &rest, &tag, insensitive, reverse)?;
 
     Ok(futures::stream::iter(vec.into_iter()).to_output_stream())
 }
 
+/// Sorts the values by the given columns (or by the values themselves when there are none),
+/// where later columns break ties between earlier ones. Rows missing a column go last, even when
+/// sorting in reverse.
 pub fn sort(
     vec: &mut [Value],
     keys: &[Tagged<String>],
     tag: impl Into<Tag>,
     insensitive: bool,
+    reverse: bool,
 ) -> Result<(), ShellError> {
     let tag = tag.into();
 
@@ -150,81 +156,108 @@ pub fn sort(
         ));
     }
 
-    for sort_arg in keys.iter() {
-        let match_test = &vec[0].get_data_by_key(sort_arg.borrow_spanned());
-        if match_test.is_none() {
+    let paths: Vec<ColumnPath> = keys
+        .iter()
+        .map(|key| ColumnPath::build(&key.item.clone().spanned(key.tag.span)))
+        .collect();
+
+    for (key, path) in keys.iter().zip(&paths) {
+        if !vec.iter().any(|row| get_key(row, path).is_some()) {
+            let label = match did_you_mean(&vec[0], key.item.clone()) {
+                Some(suggestions) => format!("invalid column (did you mean '{}'?)", suggestions[0]),
+                None => "invalid column".to_string(),
+            };
+
             return Err(ShellError::labeled_error(
                 "Can not find column to sort by",
-                "invalid column",
-                sort_arg.borrow_spanned().span,
+                label,
+                key.tag.span,
             ));
         }
     }
 
-    match &vec[0] {
-        Value {
-            value: UntaggedValue::Primitive(_),
-            ..
-        } => {
-            let should_sort_case_insensitively = insensitive && vec.iter().all(|x| x.is_string());
-
-            if let Some(values) = vec
-                .windows(2)
-                .map(|elem| coerce_compare(&elem[0], &elem[1]))
-                .find(|elem| elem.is_err())
-            {
-                let (type_1, type_2) = values
-                    .err()
-                    .expect("An error occurred in the checking of types");
-                return Err(ShellError::labeled_error(
-                    "Not all values can be compared",
-                    format!(
-                        "Unable to sort values, as \"{}\" cannot compare against \"{}\"",
-                        type_1, type_2
-                    ),
-                    tag,
-                ));
-            }
-
-            vec.sort_by(|a, b| {
-                if should_sort_case_insensitively {
-                    let lowercase_a_string = a.expect_string().to_ascii_lowercase();
-                    let lowercase_b_string = b.expect_string().to_ascii_lowercase();
-
-                    lowercase_a_string.cmp(&lowercase_b_string)
-                } else {
-                    coerce_compare(a, b).expect("Unimplemented BUG: What about primitives that don't have an order defined?").compare()
-                }
-            });
-        }
-        _ => {
-            let calc_key = |item: &Value| {
-                keys.iter()
-                    .map(|f| {
-                        let mut value_option = item.get_data_by_key(f.borrow_spanned());
-
-                        if insensitive {
-                            if let Some(value) = &value_option {
-                                if let Ok(string_value) = value.as_string() {
-                                    value_option = Some(
-                                        UntaggedValue::string(string_value.to_ascii_lowercase())
-                                            .into_value(value.tag.clone()),
-                                    )
-                                }
-                            }
-                        }
-
-                        value_option
-                    })
-                    .collect::<Vec<Option<Value>>>()
-            };
-            vec.sort_by_cached_key(calc_key);
+    let order = |ordering: Ordering| {
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
         }
     };
 
+    if paths.is_empty() {
+        vec.sort_by(|a, b| order(compare_values(a, b, insensitive)));
+    } else {
+        let mut keyed: Vec<(Vec<Option<Value>>, Value)> = vec
+            .iter()
+            .map(|row| {
+                let row_keys = paths.iter().map(|path| get_key(row, path)).collect();
+                (row_keys, row.clone())
+            })
+            .collect();
+
+        keyed.sort_by(|(left, _), (right, _)| {
+            left.iter()
+                .zip(right)
+                .map(|keys| match keys {
+                    (Some(left), Some(right)) => order(compare_values(left, right, insensitive)),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        for (slot, (_, row)) in vec.iter_mut().zip(keyed) {
+            *slot = row;
+        }
+    }
+
     Ok(())
 }
 
+fn get_key(row: &Value, path: &ColumnPath) -> Option<Value> {
+    row.get_data_by_column_path(path, Box::new(|_, _, error| error))
+        .ok()
+}
+
+/// Compares values of the same kind by what they stand for (numbers numerically, sizes by
+/// magnitude, dates chronologically, strings lexicographically). Values that can't be compared
+/// with each other are put in order of their kind instead.
+fn compare_values(left: &Value, right: &Value, insensitive: bool) -> Ordering {
+    if insensitive && left.is_string() && right.is_string() {
+        return left
+            .expect_string()
+            .to_ascii_lowercase()
+            .cmp(&right.expect_string().to_ascii_lowercase());
+    }
+
+    match coerce_compare(left, right) {
+        Ok(values) => values.compare(),
+        Err(_) => kind_order(left)
+            .cmp(&kind_order(right))
+            .then_with(|| left.cmp(right)),
+    }
+}
+
+fn kind_order(value: &Value) -> u8 {
+    match &value.value {
+        UntaggedValue::Primitive(primitive) => match primitive {
+            Primitive::Boolean(_) => 0,
+            Primitive::Int(_) | Primitive::Decimal(_) | Primitive::Filesize(_) => 1,
+            Primitive::Duration(_) => 2,
+            Primitive::Date(_) => 3,
+            Primitive::String(_) | Primitive::FilePath(_) | Primitive::GlobPattern(_) => 4,
+            Primitive::Binary(_) => 5,
+            Primitive::Nothing => 10,
+            _ => 6,
+        },
+        UntaggedValue::Row(_) => 7,
+        UntaggedValue::Table(_) => 8,
+        UntaggedValue::Block(_) | UntaggedValue::Error(_) => 9,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ShellError;
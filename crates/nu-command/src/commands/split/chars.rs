@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, Value};
+use nu_protocol::{ReturnSuccess, ShellTypeName, Signature, Value};
 
 pub struct SubCommand;
 
@@ -52,12 +52,10 @@ async fn split_chars(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 )
                 .to_output_stream()
             } else {
-                OutputStream::one(Err(ShellError::labeled_error_with_secondary(
-                    "Expected a string from pipeline",
-                    "requires string input",
-                    name.span,
-                    "value originates from here",
-                    v.tag.span,
+                OutputStream::one(Err(ShellError::unexpected_input(
+                    "split chars".spanned(name.span),
+                    "string",
+                    v.type_name().spanned(v.tag.span),
                 )))
             }
         })
@@ -3,7 +3,8 @@ use log::trace;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{
-    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
 };
 use nu_source::Tagged;
 
@@ -13,6 +14,7 @@ struct SplitColumnArgs {
     rest: Vec<Tagged<String>>,
     #[serde(rename(deserialize = "collapse-empty"))]
     collapse_empty: bool,
+    strict: bool,
 }
 
 pub struct SubCommand;
@@ -31,6 +33,11 @@ impl WholeStreamCommand for SubCommand {
                 "the character that denotes what separates columns",
             )
             .switch("collapse-empty", "remove empty columns", Some('c'))
+            .switch(
+                "strict",
+                "error on strings with more pieces than column names, rather than keeping the rest in the last column",
+                Some('s'),
+            )
             .rest(SyntaxShape::String, "column names to give the new columns")
     }
 
@@ -41,6 +48,31 @@ impl WholeStreamCommand for SubCommand {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         split_column(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a string into columns named Column1, Column2, and so on",
+                example: "echo 'a:b:c' | split column ':'",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "Column1".to_string() => Value::from("a"),
+                    "Column2".to_string() => Value::from("b"),
+                    "Column3".to_string() => Value::from("c"),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Name the columns, keeping what's left over in the last one",
+                example: "echo 'root:x:0:0:root' | split column ':' user password rest",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "user".to_string() => Value::from("root"),
+                    "password".to_string() => Value::from("x"),
+                    "rest".to_string() => Value::from("0:0:root"),
+                })
+                .into()]),
+            },
+        ]
+    }
 }
 
 async fn split_column(args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -50,6 +82,7 @@ async fn split_column(args: CommandArgs) -> Result<OutputStream, ShellError> {
             separator,
             rest,
             collapse_empty,
+            strict,
         },
         input,
     ) = args.process().await?;
@@ -60,10 +93,18 @@ async fn split_column(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 let splitter = separator.replace("\\n", "\n");
                 trace!("splitting with {:?}", splitter);
 
-                let split_result: Vec<_> = if collapse_empty {
-                    s.split(&splitter).filter(|s| !s.is_empty()).collect()
+                // Where each piece starts, so the rest can be taken from the string as it was
+                let mut start = 0;
+                let pieces = s.split(&splitter).map(|piece| {
+                    let at = start;
+                    start += piece.len() + splitter.len();
+                    (at, piece)
+                });
+
+                let (starts, split_result): (Vec<_>, Vec<_>) = if collapse_empty {
+                    pieces.filter(|(_, s)| !s.is_empty()).unzip()
                 } else {
-                    s.split(&splitter).collect()
+                    pieces.unzip()
                 };
 
                 trace!("split result = {:?}", split_result);
@@ -84,22 +125,53 @@ async fn split_column(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
                     ReturnSuccess::value(dict.into_value())
                 } else {
-                    let mut dict = TaggedDictBuilder::new(&v.tag);
-                    for (&k, v) in split_result.iter().zip(positional.iter()) {
+                    let mut split_result = split_result;
+
+                    if split_result.len() > positional.len() {
+                        if strict {
+                            return Err(ShellError::labeled_error_with_secondary(
+                                format!(
+                                    "Found {} pieces for {} columns",
+                                    split_result.len(),
+                                    positional.len()
+                                ),
+                                "more pieces than column names",
+                                name_span,
+                                "value originates from here",
+                                v.tag.span,
+                            ));
+                        }
+
+                        // The pieces beyond the last column are kept in it as they were
+                        let rest = s[starts[positional.len() - 1]..].to_string();
+                        split_result.truncate(positional.len() - 1);
+                        let mut dict = TaggedDictBuilder::new(&v.tag);
+                        for (&k, v) in split_result.iter().zip(positional.iter()) {
+                            dict.insert_untagged(v, Primitive::String(k.into()));
+                        }
                         dict.insert_untagged(
-                            v,
-                            UntaggedValue::Primitive(Primitive::String(k.into())),
+                            &positional[positional.len() - 1],
+                            Primitive::String(rest),
                         );
+
+                        return ReturnSuccess::value(dict.into_value());
+                    }
+
+                    // Columns without a piece are left empty
+                    let mut dict = TaggedDictBuilder::new(&v.tag);
+                    for (i, column) in positional.iter().enumerate() {
+                        match split_result.get(i) {
+                            Some(&k) => dict.insert_untagged(column, Primitive::String(k.into())),
+                            None => dict.insert_untagged(column, UntaggedValue::nothing()),
+                        }
                     }
                     ReturnSuccess::value(dict.into_value())
                 }
             } else {
-                Err(ShellError::labeled_error_with_secondary(
-                    "Expected a string from pipeline",
-                    "requires string input",
-                    name_span,
-                    "value originates from here",
-                    v.tag.span,
+                Err(ShellError::unexpected_input(
+                    "split column".spanned(name_span),
+                    "string",
+                    v.type_name().spanned(v.tag.span),
                 ))
             }
         })
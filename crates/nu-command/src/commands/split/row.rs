@@ -2,7 +2,9 @@ use crate::prelude::*;
 use log::trace;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
 use nu_source::Tagged;
 
 #[derive(Deserialize)]
@@ -33,6 +35,21 @@ impl WholeStreamCommand for SubCommand {
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         split_row(args).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a string into rows of strings",
+                example: "echo 'a,b,c' | split row ','",
+                result: Some(vec![Value::from("a"), Value::from("b"), Value::from("c")]),
+            },
+            Example {
+                description: "Split a sentence into words, skipping empty ones",
+                example: "echo 'hello  world' | split row ' '",
+                result: Some(vec![Value::from("hello"), Value::from("world")]),
+            },
+        ]
+    }
 }
 
 async fn split_row(args: CommandArgs) -> Result<OutputStream, ShellError> {
@@ -63,12 +80,10 @@ async fn split_row(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 }))
                 .to_output_stream()
             } else {
-                OutputStream::one(Err(ShellError::labeled_error_with_secondary(
-                    "Expected a string from pipeline",
-                    "requires string input",
-                    name.span,
-                    "value originates from here",
-                    v.tag.span,
+                OutputStream::one(Err(ShellError::unexpected_input(
+                    "split row".spanned(name.span),
+                    "string",
+                    v.type_name().spanned(v.tag.span),
                 )))
             }
         })
@@ -8,6 +8,8 @@ use nu_protocol::{
 use nu_source::Tag;
 use nu_value_ext::ValueExt;
 
+use super::expect_string_in_row;
+
 #[derive(Deserialize)]
 struct Arguments {
     rest: Vec<ColumnPath>,
@@ -51,7 +53,8 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let column_paths: Vec<_> = rest;
 
     Ok(input
-        .map(move |v| {
+        .enumerate()
+        .map(move |(row, v)| {
             if column_paths.is_empty() {
                 ReturnSuccess::value(action(&v, v.tag())?)
             } else {
@@ -60,7 +63,10 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 for path in &column_paths {
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, old.tag())),
+                        Box::new(move |old| {
+                            expect_string_in_row(row, old)?;
+                            action(old, old.tag())
+                        }),
                     )?;
                 }
 
@@ -73,7 +79,7 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
 fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::string(s.to_ascii_lowercase()).into_value(tag))
+            Ok(UntaggedValue::string(s.to_lowercase()).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -108,4 +114,13 @@ mod tests {
         let actual = action(&word, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn downcases_letters_outside_of_ascii() {
+        let word = string("ÅNGSTRÖM");
+        let expected = string("ångström");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
 }
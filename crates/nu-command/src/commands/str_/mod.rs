@@ -47,3 +47,19 @@ pub use trim::Trim as StrTrim;
 pub use trim::TrimLeft as StrTrimLeft;
 pub use trim::TrimRight as StrTrimRight;
 pub use upcase::SubCommand as StrUpcase;
+
+use nu_errors::ShellError;
+use nu_protocol::{ShellTypeName, Value};
+
+/// Checks that the cell a column path led to holds a string, naming the row it is in if not
+fn expect_string_in_row(row: usize, cell: &Value) -> Result<(), ShellError> {
+    if cell.value.is_string() {
+        Ok(())
+    } else {
+        Err(ShellError::labeled_error(
+            format!("Value in row {} is not a string", row),
+            format!("got {}", cell.type_name()),
+            cell.tag.span,
+        ))
+    }
+}
@@ -8,6 +8,7 @@ use nu_protocol::{
 use nu_source::Tag;
 use nu_value_ext::{as_string, ValueExt};
 
+use super::expect_string_in_row;
 use std::cmp::Ordering;
 use std::convert::TryInto;
 
@@ -97,7 +98,8 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let options = process_arguments(range, name)?.into();
 
     Ok(input
-        .map(move |v| {
+        .enumerate()
+        .map(move |(row, v)| {
             if column_paths.is_empty() {
                 ReturnSuccess::value(action(&v, &options, v.tag())?)
             } else {
@@ -108,7 +110,10 @@ async fn operate(args: CommandArgs) -> Result<OutputStream, ShellError> {
 
                     ret = ret.swap_data_by_column_path(
                         path,
-                        Box::new(move |old| action(old, &options, old.tag())),
+                        Box::new(move |old| {
+                            expect_string_in_row(row, old)?;
+                            action(old, &options, old.tag())
+                        }),
                     )?;
                 }
 
@@ -123,7 +128,7 @@ fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Val
 
     match &input.value {
         UntaggedValue::Primitive(Primitive::String(s)) => {
-            let len: isize = s.len().try_into().map_err(|_| {
+            let len: isize = s.chars().count().try_into().map_err(|_| {
                 ShellError::labeled_error(
                     "could not perform substring",
                     "could not perform substring",
@@ -132,7 +137,7 @@ fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Val
             })?;
 
             let start: isize = if options.0 < 0 {
-                options.0 + len
+                std::cmp::max(len + options.0, 0)
             } else {
                 options.0
             };
@@ -334,6 +339,26 @@ mod tests {
             expectation("", (6, -4)),
             expectation("", (6, -5)),
             expectation("", (6, -6)),
+            expectation("andres", (-10, isize::max_value())),
+            expectation("andres", (0, 100)),
+        ];
+
+        for expectation in cases.iter() {
+            let expected = expectation.expected;
+            let actual = action(&word, &expectation.options(), Tag::unknown()).unwrap();
+
+            assert_eq!(actual, string(expected));
+        }
+    }
+
+    #[test]
+    fn substrings_characters_rather_than_bytes() {
+        let word = string("año nuevo");
+
+        let cases = vec![
+            expectation("año", (0, 3)),
+            expectation("nuevo", (-5, isize::max_value())),
+            expectation("o nu", (2, 6)),
         ];
 
         for expectation in cases.iter() {
@@ -8,6 +8,8 @@ use nu_protocol::ShellTypeName;
 use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
 use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
+
+use super::expect_string_in_row;
 use std::iter::FromIterator;
 
 pub use trim_both_ends::SubCommand as Trim;
@@ -35,7 +37,8 @@ where
     let to_trim = char_.map(|tagged| tagged.item);
 
     Ok(input
-        .map(move |v| {
+        .enumerate()
+        .map(move |(row, v)| {
             if column_paths.is_empty() {
                 ReturnSuccess::value(action(
                     &v,
@@ -51,6 +54,7 @@ where
                     ret = ret.swap_data_by_column_path(
                         path,
                         Box::new(move |old| {
+                            expect_string_in_row(row, old)?;
                             action(old, old.tag(), to_trim, &trim_operation, ActionMode::Local)
                         }),
                     )?;
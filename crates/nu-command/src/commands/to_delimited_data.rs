@@ -2,7 +2,7 @@ use crate::prelude::*;
 use csv::WriterBuilder;
 use indexmap::{indexset, IndexSet};
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, ShellTypeName, UntaggedValue, Value};
 use nu_source::Spanned;
 use nu_value_ext::{as_string, ValueExt};
 
@@ -150,6 +150,24 @@ fn to_string_tagged_value(v: &Value) -> Result<String, ShellError> {
     }
 }
 
+/// The first cell holding a table or a row, which can't be written as a single cell, along with
+/// the name of its column
+fn find_nested_cell(value: &Value) -> Option<(&str, &Value)> {
+    let rows = match &value.value {
+        UntaggedValue::Table(rows) => &rows[..],
+        _ => std::slice::from_ref(value),
+    };
+
+    rows.iter().find_map(|row| match &row.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .iter()
+            .find(|(_, cell)| matches!(cell.value, UntaggedValue::Table(_) | UntaggedValue::Row(_)))
+            .map(|(column, cell)| (&column[..], cell)),
+        _ => None,
+    })
+}
+
 fn merge_descriptors(values: &[Value]) -> Vec<Spanned<String>> {
     let mut ret: Vec<Spanned<String>> = vec![];
     let mut seen: IndexSet<String> = indexset! {};
@@ -190,6 +208,18 @@ pub async fn to_delimited_data(
 
     Ok(
         futures::stream::iter(to_process_input.into_iter().map(move |value| {
+            if let Some((column, cell)) = find_nested_cell(&value) {
+                return Err(ShellError::labeled_error(
+                    format!("{} can't hold nested data", format_name),
+                    format!(
+                        "column '{}' holds a {}, try flattening it first",
+                        column,
+                        cell.type_name()
+                    ),
+                    name_span,
+                ));
+            }
+
             match from_value_to_delimited_string(&clone_tagged_value(&value), sep) {
                 Ok(mut x) => {
                     if noheaders {
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+use nu_engine::WholeStreamCommand;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Dictionary, Primitive, ReturnSuccess, ShellTypeName, Signature, UntaggedValue, Value,
+};
+
+pub struct ToINI;
+
+#[async_trait]
+impl WholeStreamCommand for ToINI {
+    fn name(&self) -> &str {
+        "to ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to ini")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a record of sections into .ini text"
+    }
+
+    async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
+        to_ini(args).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Outputs an INI string representing the contents of the record",
+            example: "echo [[name version]; [nu 0.28.0]] | to ini",
+            result: Some(vec![
+                UntaggedValue::string("name = nu\nversion = 0.28.0\n").into()
+            ]),
+        }]
+    }
+}
+
+/// The text of a value that can stand on the right hand side of `key = value`
+fn ini_value(key: &str, value: &Value) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => Ok(String::new()),
+        UntaggedValue::Primitive(Primitive::Date(date)) => Ok(date.to_rfc3339()),
+        UntaggedValue::Primitive(Primitive::String(_))
+        | UntaggedValue::Primitive(Primitive::Boolean(_))
+        | UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Decimal(_))
+        | UntaggedValue::Primitive(Primitive::Filesize(_))
+        | UntaggedValue::Primitive(Primitive::FilePath(_))
+        | UntaggedValue::Primitive(Primitive::ColumnPath(_)) => Ok(value.convert_to_string()),
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => Err(ShellError::labeled_error(
+            "INI can't hold nested data",
+            format!(
+                "'{}' is a {}, try flattening it first",
+                key,
+                value.type_name()
+            ),
+            &value.tag,
+        )),
+        _ => Err(ShellError::labeled_error(
+            "INI can't hold this value",
+            format!("'{}' is a {}", key, value.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+fn write_entries(out: &mut String, entries: &Dictionary) -> Result<(), ShellError> {
+    for (key, value) in entries.entries.iter() {
+        out.push_str(&format!("{} = {}\n", key, ini_value(key, value)?));
+    }
+
+    Ok(())
+}
+
+/// Writes the record the way `from ini` reads it: plain values come first as keys outside of any
+/// section, followed by one section per nested record, all in the order of the columns.
+pub fn value_to_ini_string(value: &Value) -> Result<String, ShellError> {
+    let dict = match &value.value {
+        UntaggedValue::Row(dict) => dict,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a record to write as INI",
+                format!("requires a record, found a {}", value.type_name()),
+                &value.tag,
+            ))
+        }
+    };
+
+    let mut out = String::new();
+
+    for (key, value) in dict.entries.iter() {
+        if !matches!(value.value, UntaggedValue::Row(_)) {
+            out.push_str(&format!("{} = {}\n", key, ini_value(key, value)?));
+        }
+    }
+
+    for (name, value) in dict.entries.iter() {
+        if let UntaggedValue::Row(entries) = &value.value {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[{}]\n", name));
+            write_entries(&mut out, &entries)?;
+        }
+    }
+
+    Ok(out)
+}
+
+async fn to_ini(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let args = args.evaluate_once().await?;
+    let name_tag = args.name_tag();
+    let input: Vec<Value> = args.input.collect().await;
+
+    let mut input = input.into_iter();
+    let value = match (input.next(), input.next()) {
+        (Some(value), None) => value,
+        (None, _) => return Ok(OutputStream::empty()),
+        (Some(_), Some(_)) => {
+            return Err(ShellError::labeled_error(
+                "Expected a single record to write as INI",
+                "requires a single record",
+                &name_tag,
+            ))
+        }
+    };
+
+    let ini = value_to_ini_string(&value)?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(ini).into_value(&name_tag),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShellError;
+    use super::ToINI;
+
+    #[test]
+    fn examples_work_as_expected() -> Result<(), ShellError> {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToINI {})
+    }
+}
@@ -64,7 +64,7 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
         UntaggedValue::Primitive(Primitive::Duration(i)) => {
             serde_json::Value::String(i.to_string())
         }
-        UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_string()),
+        UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_rfc3339()),
         UntaggedValue::Primitive(Primitive::EndOfStream) => serde_json::Value::Null,
         UntaggedValue::Primitive(Primitive::BeginningOfStream) => serde_json::Value::Null,
         UntaggedValue::Primitive(Primitive::Decimal(f)) => {
@@ -122,22 +122,13 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
         UntaggedValue::Block(_) | UntaggedValue::Primitive(Primitive::Range(_)) => {
             serde_json::Value::Null
         }
-        UntaggedValue::Primitive(Primitive::Binary(b)) => serde_json::Value::Array(
-            b.iter()
-                .map(|x| {
-                    serde_json::Number::from_f64(*x as f64).ok_or_else(|| {
-                        ShellError::labeled_error(
-                            "Can not convert number from floating point",
-                            "can not convert to number",
-                            &v.tag,
-                        )
-                    })
-                })
-                .collect::<Result<Vec<serde_json::Number>, ShellError>>()?
-                .into_iter()
-                .map(serde_json::Value::Number)
-                .collect(),
-        ),
+        UntaggedValue::Primitive(Primitive::Binary(_)) => {
+            return Err(ShellError::labeled_error(
+                "Binary data can't be written as JSON",
+                "encode it as text first, for example with `hash base64`",
+                &v.tag,
+            ))
+        }
         UntaggedValue::Row(o) => {
             let mut m = serde_json::Map::new();
             for (k, v) in o.entries.iter() {
@@ -243,11 +234,7 @@ async fn to_json(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     )),
                 }
             }
-            _ => Err(ShellError::labeled_error(
-                "Expected a table with JSON-compatible structure from pipeline",
-                "requires JSON-compatible input",
-                &name_tag,
-            )),
+            Err(err) => Err(err),
         },
     ))
     .to_output_stream())
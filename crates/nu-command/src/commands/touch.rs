@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use filetime::FileTime;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape};
@@ -29,7 +30,7 @@ impl WholeStreamCommand for Touch {
             .rest(SyntaxShape::FilePath, "additional files to create")
     }
     fn usage(&self) -> &str {
-        "Creates one or more files."
+        "Creates one or more files, or updates the modification time of existing ones."
     }
     async fn run(&self, args: CommandArgs) -> Result<OutputStream, ShellError> {
         touch(args).await
@@ -47,6 +48,11 @@ impl WholeStreamCommand for Touch {
                 example: "touch a b c",
                 result: None,
             },
+            Example {
+                description: "Marks \"Cargo.toml\" as modified just now",
+                example: "touch Cargo.toml",
+                result: None,
+            },
         ]
     }
 }
@@ -55,15 +61,23 @@ async fn touch(args: CommandArgs) -> Result<OutputStream, ShellError> {
     let (TouchArgs { target, rest }, _) = args.process().await?;
 
     for item in vec![target].into_iter().chain(rest.into_iter()) {
-        match OpenOptions::new().write(true).create(true).open(&item) {
-            Ok(_) => continue,
-            Err(err) => {
-                return Err(ShellError::labeled_error(
-                    "File Error",
-                    err.to_string(),
-                    &item.tag,
-                ))
-            }
+        // Files already there only get their modification time updated
+        let touched = if item.item.exists() {
+            filetime::set_file_mtime(&item.item, FileTime::now())
+        } else {
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .open(&item.item)
+                .map(|_| ())
+        };
+
+        if let Err(err) = touched {
+            return Err(ShellError::labeled_error(
+                format!("Could not touch {}", item.item.display()),
+                err.to_string(),
+                &item.tag,
+            ));
         }
     }
 
@@ -1,10 +1,18 @@
 use crate::prelude::*;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, UntaggedValue};
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+
+use super::get::get_column_path;
 
 pub struct Uniq;
 
+#[derive(Deserialize)]
+pub struct UniqArgs {
+    column: Option<ColumnPath>,
+    count: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Uniq {
     fn name(&self) -> &str {
@@ -12,7 +20,13 @@ impl WholeStreamCommand for Uniq {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("uniq").switch("count", "Count the unique rows", Some('c'))
+        Signature::build("uniq")
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "only compare this column, keeping the first row of each value",
+            )
+            .switch("count", "Count the unique rows", Some('c'))
     }
 
     fn usage(&self) -> &str {
@@ -50,27 +64,57 @@ impl WholeStreamCommand for Uniq {
                     .into(),
                 ]),
             },
+            Example {
+                description: "Keep the first row of every type",
+                example: "echo [[name type]; [a x] [b y] [c x]] | uniq type | get name",
+                result: Some(vec![
+                    UntaggedValue::string("a").into(),
+                    UntaggedValue::string("b").into(),
+                ]),
+            },
         ]
     }
 }
 
 async fn uniq(args: CommandArgs) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once().await?;
-    let should_show_count = args.has("count");
-    let input = args.input;
+    let (UniqArgs { column, count }, input) = args.process().await?;
+    let should_show_count = count;
+    let values = input.into_vec().await;
+
+    let keys = match &column {
+        Some(column) => {
+            let keys: Vec<_> = values
+                .iter()
+                .map(|row| get_column_path(column, row))
+                .collect();
+
+            // Rows without the column are kept together, unless no row has it at all
+            if keys.iter().all(|key| key.is_err()) {
+                if let Some(Err(err)) = keys.into_iter().next() {
+                    return Err(err);
+                }
+                Vec::new()
+            } else {
+                keys.into_iter()
+                    .map(|key| key.unwrap_or_else(|_| UntaggedValue::nothing().into()))
+                    .collect()
+            }
+        }
+        None => values.clone(),
+    };
+
     let uniq_values = {
-        let mut counter = IndexMap::<nu_protocol::Value, usize>::new();
-        for line in input.into_vec().await {
-            *counter.entry(line).or_insert(0) += 1;
+        let mut counter = IndexMap::<Value, (Value, usize)>::new();
+        for (key, line) in keys.into_iter().zip(values) {
+            counter.entry(key).or_insert((line, 0)).1 += 1;
         }
-        counter
+        counter.into_iter().map(|(_, item)| item)
     };
 
     let mut values_vec_deque = VecDeque::new();
 
     if should_show_count {
         for item in uniq_values {
-            use nu_protocol::Value;
             let value = {
                 match item.0.value {
                     UntaggedValue::Row(mut row) => {
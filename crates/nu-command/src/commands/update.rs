@@ -125,11 +125,7 @@ async fn process_row(
                             ..
                         } => match obj.replace_data_at_column_path(&field, result) {
                             Some(v) => OutputStream::one(ReturnSuccess::value(v)),
-                            None => OutputStream::one(Err(ShellError::labeled_error(
-                                "update could not find place to insert column",
-                                "column name",
-                                obj.tag,
-                            ))),
+                            None => OutputStream::one(Err(missing_column(&field, &obj.tag))),
                         },
                         _ => OutputStream::one(Err(ShellError::labeled_error(
                             "Unrecognized type in stream",
@@ -152,26 +148,32 @@ async fn process_row(
                 .replace_data_at_column_path(&field, replacement.clone())
             {
                 Some(v) => OutputStream::one(ReturnSuccess::value(v)),
-                None => OutputStream::one(Err(ShellError::labeled_error(
-                    "update could not find place to insert column",
-                    "column name",
-                    field.maybe_span().unwrap_or(tag.span),
-                ))),
+                None => OutputStream::one(Err(missing_column(&field, tag))),
             },
             Value { value: _, ref tag } => {
                 match input.replace_data_at_column_path(&field, replacement.clone()) {
                     Some(v) => OutputStream::one(ReturnSuccess::value(v)),
-                    None => OutputStream::one(Err(ShellError::labeled_error(
-                        "update could not find place to insert column",
-                        "column name",
-                        field.maybe_span().unwrap_or(tag.span),
-                    ))),
+                    None => OutputStream::one(Err(missing_column(&field, tag))),
                 }
             }
         },
     })
 }
 
+fn missing_column(field: &ColumnPath, tag: &Tag) -> ShellError {
+    let path = field
+        .iter()
+        .map(|member| member.as_string())
+        .collect::<Vec<_>>()
+        .join(".");
+
+    ShellError::labeled_error(
+        format!("Cannot update {}, the column doesn't exist", path),
+        "column not found, use insert to add it",
+        field.maybe_span().unwrap_or(tag.span),
+    )
+}
+
 async fn update(raw_args: CommandArgs) -> Result<OutputStream, ShellError> {
     let name_tag = Arc::new(raw_args.call_info.name_tag.clone());
     let context = Arc::new(EvaluationContext::from_args(&raw_args));
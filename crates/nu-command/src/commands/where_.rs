@@ -1,10 +1,8 @@
 use crate::prelude::*;
-use nu_engine::evaluate_baseline_expr;
+use crate::utils::condition::Condition;
 use nu_engine::WholeStreamCommand;
 use nu_errors::ShellError;
-use nu_protocol::{
-    hir::CapturedBlock, hir::ClassifiedCommand, ReturnSuccess, Signature, SyntaxShape,
-};
+use nu_protocol::{hir::CapturedBlock, ReturnSuccess, Signature, SyntaxShape};
 
 pub struct Where;
 
@@ -64,60 +62,18 @@ async fn where_command(raw_args: CommandArgs) -> Result<OutputStream, ShellError
     let ctx = Arc::new(EvaluationContext::from_args(&raw_args));
     let tag = raw_args.call_info.name_tag.clone();
     let (WhereArgs { block }, input) = raw_args.process().await?;
-    let condition = {
-        if block.block.block.len() != 1 {
-            return Err(ShellError::labeled_error(
-                "Expected a condition",
-                "expected a condition",
-                tag,
-            ));
-        }
-        match block.block.block[0].pipelines.get(0) {
-            Some(item) => match item.list.get(0) {
-                Some(ClassifiedCommand::Expr(expr)) => expr.clone(),
-                _ => {
-                    return Err(ShellError::labeled_error(
-                        "Expected a condition",
-                        "expected a condition",
-                        tag,
-                    ));
-                }
-            },
-            None => {
-                return Err(ShellError::labeled_error(
-                    "Expected a condition",
-                    "expected a condition",
-                    tag,
-                ));
-            }
-        }
-    };
+    let condition = Condition::new(block, tag)?;
 
     Ok(input
-        .filter_map(move |input| {
+        .enumerate()
+        .filter_map(move |(index, input)| {
             let condition = condition.clone();
             let ctx = ctx.clone();
 
-            ctx.scope.enter_scope();
-            ctx.scope.add_vars(&block.captured.entries);
-            ctx.scope.add_var("$it", input.clone());
-
             async move {
-                //FIXME: should we use the scope that's brought in as well?
-                let condition = evaluate_baseline_expr(&condition, &*ctx).await;
-                ctx.scope.exit_scope();
-
-                match condition {
-                    Ok(condition) => match condition.as_bool() {
-                        Ok(b) => {
-                            if b {
-                                Some(Ok(ReturnSuccess::Value(input)))
-                            } else {
-                                None
-                            }
-                        }
-                        Err(e) => Some(Err(e)),
-                    },
+                match condition.check(&input, index, &ctx).await {
+                    Ok(true) => Some(Ok(ReturnSuccess::Value(input))),
+                    Ok(false) => None,
                     Err(e) => Some(Err(e)),
                 }
             }
@@ -74,6 +74,15 @@ impl WholeStreamCommand for Wrap {
                     .into(),
                 ]),
             },
+            Example {
+                description: "Unwrap the column again with get",
+                example: "echo [1 2 3] | wrap MyColumn | get MyColumn",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
         ]
     }
 }
@@ -89,11 +98,13 @@ async fn wrap(args: CommandArgs) -> Result<OutputStream, ShellError> {
                 value: UntaggedValue::Row(_),
                 ..
             } => {
+                // Rows among bare values are already structured, so they are left as they are
                 result_table.push(value);
             }
             _ => {
                 are_all_rows = false;
 
+                let tag = value.tag.clone();
                 let mut index_map = IndexMap::new();
                 index_map.insert(
                     match &column {
@@ -103,7 +114,7 @@ async fn wrap(args: CommandArgs) -> Result<OutputStream, ShellError> {
                     value,
                 );
 
-                result_table.push(UntaggedValue::row(index_map).into_value(Tag::unknown()));
+                result_table.push(UntaggedValue::row(index_map).into_value(tag));
             }
         }
     }
@@ -146,6 +146,39 @@ pub fn test(cmd: impl WholeStreamCommand + 'static) -> Result<(), ShellError> {
     Ok(())
 }
 
+/// Checks that the examples of every command in the context parse cleanly, reporting all the
+/// failures together. Expected results are checked by `test`, which runs them in a context with
+/// just the commands they need.
+pub fn test_examples_parse(base_context: &EvaluationContext) -> Result<(), ShellError> {
+    let mut failures = vec![];
+
+    for name in base_context.scope.get_command_names() {
+        let command = match base_context.scope.get_command(&name) {
+            Some(command) => command,
+            None => continue,
+        };
+
+        for sample_pipeline in command.examples() {
+            let block = parse_line(sample_pipeline.example, base_context)?;
+
+            if let Some(err) = block.failed {
+                failures.push(format!(
+                    "{}: `{}` {:?}",
+                    name,
+                    sample_pipeline.example,
+                    err.reason()
+                ));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("examples don't parse:\n{}", failures.join("\n"));
+    }
+
+    Ok(())
+}
+
 pub fn test_anchors(cmd: Command) -> Result<(), ShellError> {
     let examples = cmd.examples();
 
@@ -1,13 +1,16 @@
 use crate::maybe_print_errors;
 use crate::prelude::*;
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity};
 use futures_codec::FramedRead;
 use nu_engine::path::canonicalize;
 use nu_engine::run_block;
 use nu_engine::EvaluationContext;
 use nu_engine::{MaybeTextCodec, StringOrBinary};
 use nu_errors::ShellError;
-use nu_protocol::hir::{ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments};
-use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::hir::{
+    Block, ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments, NamedValue,
+};
+use nu_protocol::{Deprecation, Primitive, ReturnSuccess, UntaggedValue, Value};
 use nu_stream::ToInputStream;
 
 use log::{debug, trace};
@@ -48,6 +51,70 @@ pub fn print_err(err: ShellError, source: &Text, ctx: &EvaluationContext) {
     }
 }
 
+struct DeprecationWarning {
+    message: String,
+    span: Span,
+    deprecation: Deprecation,
+}
+
+impl DeprecationWarning {
+    fn into_shell_error(self, severity: Severity) -> ShellError {
+        ShellError::diagnostic(
+            Diagnostic::new(severity)
+                .with_message(self.message)
+                .with_labels(vec![
+                    Label::primary(0, self.span).with_message(self.deprecation.describe())
+                ]),
+        )
+    }
+}
+
+/// Finds the deprecated commands and flags used in the block, including in its nested blocks
+fn deprecation_warnings(block: &Block, scope: &Scope) -> Vec<DeprecationWarning> {
+    let mut warnings = vec![];
+
+    for command in block.get_internal_commands() {
+        let signature = match scope.get_command(&command.name) {
+            Some(internal_command) => internal_command.signature(),
+            None => continue,
+        };
+
+        if let Some(deprecation) = signature.deprecated {
+            warnings.push(DeprecationWarning {
+                message: format!("`{}` is deprecated", command.name),
+                span: command.name_span,
+                deprecation,
+            });
+        }
+
+        if let Some(named) = &command.args.named {
+            for (name, value) in named.iter() {
+                let span = match value {
+                    NamedValue::PresentSwitch(span) | NamedValue::Value(span, _) => *span,
+                    NamedValue::AbsentSwitch | NamedValue::AbsentValue => continue,
+                };
+
+                if let Some(deprecation) = signature.deprecated_flags.get(name) {
+                    warnings.push(DeprecationWarning {
+                        message: format!("`--{}` of `{}` is deprecated", name, command.name),
+                        span,
+                        deprecation: deprecation.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+fn deprecations_are_errors() -> bool {
+    crate::config::config(Tag::unknown())
+        .ok()
+        .and_then(|config| config.get("deprecation_errors").map(|val| val.is_true()))
+        .unwrap_or(false)
+}
+
 /// Process the line by parsing the text to turn it into commands, classify those commands so that we understand what is being called in the pipeline, and then run this pipeline
 pub async fn process_script(
     script_text: &str,
@@ -70,6 +137,22 @@ pub async fn process_script(
             return LineResult::Error(line.to_string(), failure.into());
         }
 
+        let mut warnings = deprecation_warnings(&block, &ctx.scope);
+        if !warnings.is_empty() {
+            if deprecations_are_errors() {
+                let error = warnings.remove(0).into_shell_error(Severity::Error);
+                return LineResult::Error(line.to_string(), error);
+            }
+
+            for warning in warnings {
+                print_err(
+                    warning.into_shell_error(Severity::Warning),
+                    &Text::from(line.to_string()),
+                    ctx,
+                );
+            }
+        }
+
         // There's a special case to check before we process the pipeline:
         // If we're giving a path by itself
         // ...and it's not a command in the path
@@ -270,3 +353,85 @@ pub async fn run_script_standalone(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::deprecation_warnings;
+    use crate::commands::Each;
+    use crate::prelude::*;
+    use nu_engine::{whole_stream_command, WholeStreamCommand};
+    use nu_errors::ShellError;
+    use nu_protocol::Signature;
+
+    struct Rows;
+
+    #[async_trait]
+    impl WholeStreamCommand for Rows {
+        fn name(&self) -> &str {
+            "rows"
+        }
+
+        fn signature(&self) -> Signature {
+            Signature::build("rows")
+                .switch("all", "every row", None)
+                .switch("everything", "every row", None)
+                .deprecated("0.28.0", Some("length"))
+                .deprecated_flag("everything", "0.28.0", Some("all"))
+        }
+
+        fn usage(&self) -> &str {
+            "Counts the rows."
+        }
+
+        async fn run(&self, _args: CommandArgs) -> Result<OutputStream, ShellError> {
+            Ok(OutputStream::empty())
+        }
+    }
+
+    fn warnings(src: &str) -> Vec<(String, String, Span)> {
+        let scope = Scope::new();
+        scope.add_command("rows".into(), whole_stream_command(Rows));
+        scope.add_command("each".into(), whole_stream_command(Each));
+
+        let (block, err) = nu_parser::parse(src, 0, &scope);
+        assert!(err.is_none());
+
+        deprecation_warnings(&block, &scope)
+            .into_iter()
+            .map(|warning| {
+                (
+                    warning.message,
+                    warning.deprecation.describe(),
+                    warning.span,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deprecated_commands_and_flags_are_found() {
+        assert_eq!(
+            warnings("rows --everything"),
+            vec![
+                (
+                    "`rows` is deprecated".to_string(),
+                    "deprecated since 0.28.0, use `length` instead".to_string(),
+                    Span::new(0, 4)
+                ),
+                (
+                    "`--everything` of `rows` is deprecated".to_string(),
+                    "deprecated since 0.28.0, use `--all` instead".to_string(),
+                    Span::new(5, 17)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn deprecations_in_nested_blocks_are_found() {
+        let found = warnings("each { rows --all }");
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].2, Span::new(7, 11));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod arguments;
+pub mod columns;
+pub mod condition;
 pub mod suggestions;
 pub mod test_bins;
@@ -0,0 +1,60 @@
+use indexmap::IndexMap;
+use nu_protocol::{merge_descriptors, Dictionary, UntaggedValue, Value};
+
+/// The values a table stands for when attached to another one. A table gives each of
+/// its rows, and anything else is a single value.
+pub fn spread(value: Value) -> Vec<Value> {
+    match value {
+        Value {
+            value: UntaggedValue::Table(values),
+            ..
+        } => values,
+        other => vec![other],
+    }
+}
+
+/// Gives every row the columns of all the others, in the order they are first seen,
+/// leaving the cells of the columns a row didn't have empty. Values that aren't all
+/// rows are left as they are.
+pub fn unify_columns(values: Vec<Value>) -> Vec<Value> {
+    if values.is_empty() || !values.iter().all(Value::is_row) {
+        return values;
+    }
+
+    let columns = merge_descriptors(&values);
+
+    values
+        .into_iter()
+        .map(|row| with_columns(row, &columns))
+        .collect()
+}
+
+/// Gives a row the given columns first, with empty cells for those it doesn't have,
+/// followed by any columns of its own.
+pub fn with_columns(row: Value, columns: &[String]) -> Value {
+    let dict = match &row.value {
+        UntaggedValue::Row(dict) => dict,
+        _ => return row,
+    };
+
+    let mut entries: IndexMap<String, Value> = columns
+        .iter()
+        .map(|column| {
+            let cell = dict
+                .entries
+                .get(column)
+                .cloned()
+                .unwrap_or_else(|| UntaggedValue::nothing().into_value(&row.tag));
+
+            (column.clone(), cell)
+        })
+        .collect();
+
+    for (column, cell) in dict.entries.iter() {
+        if !entries.contains_key(column) {
+            entries.insert(column.clone(), cell.clone());
+        }
+    }
+
+    UntaggedValue::Row(Dictionary { entries }).into_value(&row.tag)
+}
@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use nu_engine::evaluate_baseline_expr;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::{CapturedBlock, ClassifiedCommand, SpannedExpression},
+    Primitive, ReturnSuccess, ShellTypeName, UntaggedValue, Value,
+};
+
+/// The condition of a command like `where` or `skip while`, taken out of the block it was
+/// given in so that it can be checked against one row at a time.
+#[derive(Clone)]
+pub struct Condition {
+    expr: Arc<SpannedExpression>,
+    captured: Arc<CapturedBlock>,
+}
+
+impl Condition {
+    /// Takes the condition out of a block, which must hold a single expression
+    pub fn new(block: CapturedBlock, tag: impl Into<Tag>) -> Result<Condition, ShellError> {
+        let expr = match block.block.block.as_slice() {
+            [group] => match group
+                .pipelines
+                .get(0)
+                .and_then(|pipeline| pipeline.list.get(0))
+            {
+                Some(ClassifiedCommand::Expr(expr)) => Some(expr.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match expr {
+            Some(expr) => Ok(Condition {
+                expr: Arc::new(*expr),
+                captured: Arc::new(block),
+            }),
+            None => Err(ShellError::labeled_error(
+                "Expected a condition",
+                "expected a condition",
+                tag.into(),
+            )),
+        }
+    }
+
+    /// Checks the condition against the row at `index`, with `$it` set to it. Anything but
+    /// true or false is an error naming the row.
+    pub async fn check(
+        &self,
+        row: &Value,
+        index: usize,
+        ctx: &EvaluationContext,
+    ) -> Result<bool, ShellError> {
+        ctx.scope.enter_scope();
+        ctx.scope.add_vars(&self.captured.captured.entries);
+        ctx.scope.add_var("$it", row.clone());
+
+        let result = evaluate_baseline_expr(&self.expr, ctx).await;
+        ctx.scope.exit_scope();
+
+        match result? {
+            Value {
+                value: UntaggedValue::Primitive(Primitive::Boolean(b)),
+                ..
+            } => Ok(b),
+            other => Err(ShellError::labeled_error(
+                format!("Condition for row {} isn't true or false", index),
+                format!("gave {}", other.type_name()),
+                self.captured.block.span,
+            )),
+        }
+    }
+}
+
+/// Skips rows for as long as the condition gives `expected`. Once a row doesn't, it and
+/// every row after it are passed along without checking the condition again.
+pub fn skip_while(
+    input: InputStream,
+    condition: Condition,
+    expected: bool,
+    ctx: Arc<EvaluationContext>,
+) -> OutputStream {
+    futures::stream::unfold((Some(input), true, 0), move |(input, skipping, index)| {
+        let condition = condition.clone();
+        let ctx = ctx.clone();
+
+        async move {
+            let mut input = input?;
+            let mut index = index;
+
+            loop {
+                let row = input.next().await?;
+
+                if !skipping {
+                    return Some((ReturnSuccess::value(row), (Some(input), false, index)));
+                }
+
+                match condition.check(&row, index, &ctx).await {
+                    Ok(matched) if matched == expected => index += 1,
+                    Ok(_) => return Some((ReturnSuccess::value(row), (Some(input), false, index))),
+                    Err(err) => return Some((Err(err), (None, false, index))),
+                }
+            }
+        }
+    })
+    .to_output_stream()
+}
+
+/// Keeps rows for as long as the condition gives `expected`, ending the stream at the
+/// first row that doesn't without reading any further.
+pub fn take_while(
+    input: InputStream,
+    condition: Condition,
+    expected: bool,
+    ctx: Arc<EvaluationContext>,
+) -> OutputStream {
+    futures::stream::unfold((Some(input), 0), move |(input, index)| {
+        let condition = condition.clone();
+        let ctx = ctx.clone();
+
+        async move {
+            let mut input = input?;
+            let row = input.next().await?;
+
+            match condition.check(&row, index, &ctx).await {
+                Ok(matched) if matched == expected => {
+                    Some((ReturnSuccess::value(row), (Some(input), index + 1)))
+                }
+                Ok(_) => None,
+                Err(err) => Some((Err(err), (None, index))),
+            }
+        }
+    })
+    .to_output_stream()
+}
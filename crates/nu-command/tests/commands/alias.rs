@@ -0,0 +1,72 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn alias_expands_to_a_pipeline() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        alias names = ls | get name | str collect ',';
+        names
+        "#
+    ));
+
+    let expected = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | get name | str collect ','"
+    );
+
+    assert_eq!(actual.out, expected.out);
+}
+
+#[test]
+fn alias_can_refer_to_another_alias() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        alias lss = ls | sort-by size;
+        alias l = lss | first 2;
+        l | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn alias_referring_to_itself_expands_once() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        alias echo = echo hello;
+        echo world | str collect ' '
+        "#
+    ));
+
+    assert_eq!(actual.out, "hello world");
+}
+
+#[test]
+fn alias_without_arguments_lists_the_aliases() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        alias lss = ls | sort-by size;
+        alias | where name == lss | get expansion
+        "#
+    ));
+
+    assert_eq!(actual.out, "ls | sort-by size");
+}
+
+#[test]
+fn help_describes_an_alias() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        alias lss = ls | sort-by size;
+        help commands | where name == lss | get description
+        "#
+    ));
+
+    assert_eq!(actual.out, "Alias for `ls | sort-by size`.");
+}
@@ -0,0 +1,142 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn and_runs_rhs_when_lhs_succeeds() {
+    Playground::setup("and_or_test_1", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "touch i_will_be_created_and.txt && echo done"
+        );
+
+        let path = dirs.test().join("i_will_be_created_and.txt");
+
+        assert!(path.exists());
+        assert_eq!(actual.out, "done");
+    })
+}
+
+#[test]
+fn and_skips_rhs_when_lhs_fails() {
+    Playground::setup("and_or_test_2", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open i_do_not_exist.txt && touch i_will_not_be_created.txt"
+        );
+
+        let path = dirs.test().join("i_will_not_be_created.txt");
+
+        assert!(!path.exists());
+        assert!(actual.err.contains("Cannot find file"));
+    })
+}
+
+#[test]
+fn or_runs_rhs_when_lhs_fails() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        open i_do_not_exist.txt || echo recovered
+    "#
+    ));
+
+    assert_eq!(actual.out, "recovered");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn or_keeps_lhs_output_when_lhs_succeeds() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo first || echo second
+    "#
+    ));
+
+    assert_eq!(actual.out, "first");
+}
+
+#[test]
+fn and_looks_at_every_row_of_lhs() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo [1 2 x] | each { = $it + 1 } && echo never
+    "#
+    ));
+
+    assert!(!actual.out.contains("never"));
+}
+
+#[test]
+fn or_looks_at_every_row_of_lhs() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo [1 2 x] | each { = $it + 1 } || echo recovered
+    "#
+    ));
+
+    assert!(actual.out.ends_with("recovered"));
+}
+
+#[test]
+fn and_or_chain_falls_through_to_or() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        open i_do_not_exist.txt && echo never || echo fallback
+    "#
+    ));
+
+    assert_eq!(actual.out, "fallback");
+}
+
+#[test]
+fn pipes_bind_tighter_than_and() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo 1 2 3 | math sum && echo [4 5] | math sum
+    "#
+    ));
+
+    assert_eq!(actual.out, "9");
+}
+
+#[test]
+fn and_in_where_is_still_a_logical_operator() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo [[a b]; [1 2] [1 3]] | where a == 1 && b == 3 | get b
+    "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn single_ampersand_is_a_parse_error() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo done & echo again
+    "#
+    ));
+
+    assert!(actual.err.contains("'&&' or '||' to chain commands"));
+    assert!(actual.err.contains("a single '&' is not supported"));
+}
+
+#[test]
+fn dangling_and_is_a_parse_error() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+    r#"
+        echo done &&
+    "#
+    ));
+
+    assert!(actual.err.contains("&&"));
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -27,3 +27,61 @@ fn adds_a_row_to_the_end() {
         assert_eq!(actual.out, "pollo loco");
     })
 }
+
+#[test]
+fn adds_a_literal_row_to_ls_output() {
+    Playground::setup("append_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt"), EmptyFile("jonathan.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | sort-by name
+                | select name type
+                | append [[name, comment]; [yehuda.txt, "not made yet"]]
+                | get comment
+                | compact
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "1");
+    })
+}
+
+#[test]
+fn gives_every_row_the_columns_of_the_others() {
+    Playground::setup("append_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | select name
+                | append [[name, comment]; [yehuda.txt, "not made yet"]]
+                | first
+                | get
+                | str collect ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "name,comment");
+    })
+}
+
+#[test]
+fn adds_every_row_of_a_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | append [[name]; [bat] [fd]]
+            | get name
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,bat,fd");
+}
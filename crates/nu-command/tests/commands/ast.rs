@@ -0,0 +1,54 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn prints_the_commands_of_a_pipeline() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast "echo abc | count"
+            | get pipelines
+            | get list
+            | get Internal.name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["echo","count"]"#);
+}
+
+#[test]
+fn spans_point_into_the_command_line() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ast "echo abc"
+            | get pipelines
+            | get list
+            | get Internal
+            | each { = $it.args.positional.0.span.start - $it.name_span.start }
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn does_not_run_the_pipeline() {
+    Playground::setup("ast_test_1", |dirs, _sandbox| {
+        nu!(
+            cwd: dirs.test(),
+            r#"ast "touch i_will_not_be_created.txt""#
+        );
+
+        let path = dirs.test().join("i_will_not_be_created.txt");
+        assert!(!path.exists());
+    })
+}
+
+#[test]
+fn reports_parse_errors_like_the_shell() {
+    let actual = nu!(cwd: ".", r#"ast "echo [abc""#);
+
+    assert!(actual.err.contains("'[' is never closed"));
+}
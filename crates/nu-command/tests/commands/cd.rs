@@ -417,6 +417,35 @@ fn valuesystem_change_to_a_path_containing_spaces() {
     })
 }
 
+#[test]
+fn filesystem_quoted_tilde_is_not_the_home_directory() {
+    Playground::setup("cd_test_21", |dirs, sandbox| {
+        sandbox.mkdir("~");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                cd "~"
+                echo $(pwd)
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), dirs.test().join("~"));
+    })
+}
+
+#[test]
+fn filesystem_other_users_home_directory_is_unsupported() {
+    Playground::setup("cd_test_22", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cd ~otheruser/projects"
+        );
+
+        assert!(actual.err.contains("'~user' is not supported"));
+    })
+}
+
 #[test]
 fn valuesystem_path_not_found() {
     Playground::setup("cd_test_19", |dirs, _| {
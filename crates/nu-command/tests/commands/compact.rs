@@ -32,6 +32,7 @@ fn discards_rows_where_given_column_is_empty() {
         assert_eq!(actual.out, "3");
     });
 }
+
 #[test]
 fn discards_empty_rows_by_default() {
     Playground::setup("compact_test_2", |dirs, _| {
@@ -48,3 +49,33 @@ fn discards_empty_rows_by_default() {
         assert_eq!(actual.out, "4");
     });
 }
+
+#[test]
+fn discards_rows_where_given_column_is_an_empty_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "email": "nu@example.com"}, {"name": "bat", "email": ""}]'
+            | from json
+            | compact email
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn discards_rows_where_given_nested_column_is_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "config": {"retries": 3}}, {"name": "bat", "config": {}}, {"name": "fd"}]'
+            | from json
+            | compact config.retries
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
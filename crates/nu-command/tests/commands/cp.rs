@@ -177,7 +177,7 @@ fn copies_same_file_twice() {
 
         nu!(
             cwd: dirs.root(),
-            "cp \"{}\" cp_test_8/sample.ini",
+            "cp --force \"{}\" cp_test_8/sample.ini",
             dirs.formats().join("sample.ini")
         );
 
@@ -235,3 +235,68 @@ fn copy_file_and_dir_from_two_parents_up_using_multiple_dots_to_current_dir_recu
         assert!(files_exist_at(vec!["hello_there", "hello_again"], expected));
     })
 }
+
+#[test]
+fn errors_if_destination_exists_without_force() {
+    Playground::setup("cp_test_11", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt"), EmptyFile("jonathan.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp andres.txt jonathan.txt"
+        );
+
+        assert!(actual.err.contains("already exists"));
+    })
+}
+
+#[test]
+fn copies_nothing_if_any_destination_exists_without_force() {
+    Playground::setup("cp_test_11_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                EmptyFile("andres.txt"),
+                EmptyFile("jonathan.txt"),
+                EmptyFile("yehuda.txt"),
+            ])
+            .within("copies")
+            .with_files(vec![EmptyFile("yehuda.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp *.txt copies"
+        );
+
+        assert!(actual.err.contains("already exists"));
+        assert!(!dirs.test().join("copies").join("andres.txt").exists());
+        assert!(!dirs.test().join("copies").join("jonathan.txt").exists());
+    })
+}
+
+#[test]
+fn errors_if_destination_directory_does_not_exist() {
+    Playground::setup("cp_test_12", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp andres.txt missing/andres.txt"
+        );
+
+        assert!(actual.err.contains("Destination directory does not exist"));
+    })
+}
+
+#[test]
+fn errors_if_copying_a_directory_without_recursive() {
+    Playground::setup("cp_test_13", |dirs, sandbox| {
+        sandbox.mkdir("originals");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp originals copies"
+        );
+
+        assert!(actual.err.contains("--recursive"));
+    })
+}
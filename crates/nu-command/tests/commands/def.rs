@@ -1,5 +1,5 @@
-use nu_test_support::nu;
 use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
 use std::fs;
 #[test]
 fn def_with_comment() {
@@ -17,3 +17,57 @@ def e [arg] {echo $arg}
         assert!(actual.out.contains("My echo\\n\\n"));
     });
 }
+
+#[test]
+fn def_binds_arguments_to_parameters() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        def biggest [n] { ls | sort-by size | last $n };
+        biggest 3 | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn def_checks_the_number_of_arguments() {
+    let actual = nu!(
+        cwd: ".",
+        "def biggest [n] { ls | sort-by size | last $n }; biggest"
+    );
+
+    assert!(actual
+        .err
+        .contains("requires n parameter (usage: biggest <n>)"));
+
+    let actual = nu!(
+        cwd: ".",
+        "def biggest [n] { ls | sort-by size | last $n }; biggest 1 2"
+    );
+
+    assert!(actual
+        .err
+        .contains("unexpected argument (usage: biggest <n>)"));
+}
+
+#[test]
+fn def_replaces_an_earlier_definition() {
+    let actual = nu!(
+        cwd: ".",
+        "def greet [] { echo hello }; def greet [] { echo goodbye }; greet"
+    );
+
+    assert_eq!(actual.out, "goodbye");
+}
+
+#[test]
+fn def_shows_its_parameters_in_help() {
+    let actual = nu!(
+        cwd: ".",
+        "def biggest [n: int] { ls | sort-by size | last $n }; help biggest"
+    );
+
+    assert!(actual.out.contains("biggest <n>"));
+}
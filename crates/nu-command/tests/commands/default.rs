@@ -33,3 +33,36 @@ fn adds_row_data_if_column_missing() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn keeps_the_values_present() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "retries": 3}, {"name": "bat"}, {"name": "fd", "retries": 5}]'
+            | from json
+            | default retries 0
+            | where retries > 2
+            | get name
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu,fd");
+}
+
+#[test]
+fn adds_nested_column_if_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "config": {"retries": 3}}, {"name": "bat", "config": {}}, {"name": "fd"}]'
+            | from json
+            | default config.retries 0
+            | get config.retries
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[3,0,0]");
+}
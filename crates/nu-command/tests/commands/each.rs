@@ -71,3 +71,53 @@ fn each_implicit_it_in_block() {
 
     assert_eq!(actual.out, "ace");
 }
+
+#[test]
+fn each_reads_nested_members_of_it() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo '[{"package": {"file name": "nu.rs"}}]'
+        | from json
+        | each { echo $it.package."file name" }
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu.rs");
+}
+
+#[test]
+fn each_errors_on_missing_members_of_it() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo '[{"name": "nu"}]' | from json | each { echo $it.nmae }
+        "#
+    ));
+
+    assert!(actual.err.contains("did you mean 'name'?"));
+}
+
+#[test]
+fn each_names_the_row_the_block_failed_on() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name size]; [a 1] [b "two"]] | each { = $it.size + 1 }
+        "#
+    ));
+
+    assert!(actual.err.contains("the block failed on row 1"));
+}
+
+#[test]
+fn each_drops_rows_the_block_returns_nothing_for() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [1 2 3 4] | each { if $it > 2 { echo $it } { echo [] } } | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[3,4]");
+}
@@ -59,3 +59,169 @@ fn echo_range_handles_exclusive_down() {
 
     assert_eq!(actual.out, "[3,2]");
 }
+
+#[test]
+fn echo_decodes_escapes_in_double_quotes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo "\u{41}\tB\"" | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""A\tB\"""#);
+}
+
+#[test]
+fn echo_keeps_single_quotes_literal() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo '\u{41}\t' | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""\\u{41}\\t""#);
+}
+
+#[test]
+fn echo_errors_on_malformed_unicode_escape() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo "\u{ZZ}"
+        "#
+    ));
+
+    assert!(actual.err.contains("unicode escape"));
+}
+
+#[test]
+fn echo_single_quoted_windows_path_is_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo 'C:\Users\me\file.txt'
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"C:\Users\me\file.txt"#);
+}
+
+#[test]
+fn echo_single_and_double_quotes_produce_the_same_string() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo 'nu' "nu" | uniq | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn echo_negative_numbers_are_positionals() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo -1 -2.5 | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "-3.5");
+}
+
+#[test]
+fn echo_unknown_short_flag_is_still_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo -r
+        "#
+    ));
+
+    assert!(actual.err.contains("unexpected flag"));
+}
+
+#[test]
+fn echo_rejects_a_dangling_decimal_point() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo 1.
+        "#
+    ));
+
+    assert!(actual.err.contains("a digit after the decimal point"));
+}
+
+#[test]
+fn echo_empty_and_nested_lists() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo [] | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo [[1 2] [3]] | flatten | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn echo_unclosed_list_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo [1 2
+        "#
+    ));
+
+    assert!(actual.err.contains("expected ']' before the end"));
+}
+
+#[test]
+fn echo_keeps_punctuation_heavy_words_whole() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo https://example.com/data.json git@github.com:nushell/nushell.git 1.2.3-beta+build
+        | str collect ' '
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "https://example.com/data.json git@github.com:nushell/nushell.git 1.2.3-beta+build"
+    );
+}
+
+#[test]
+fn echo_outputs_each_of_its_arguments() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo a b c | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo a b c | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
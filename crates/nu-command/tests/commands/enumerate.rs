@@ -0,0 +1,59 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn adds_an_index_to_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu] [bat] [git]]
+            | enumerate
+            | where index > 0
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["bat","git"]"#);
+}
+
+#[test]
+fn adds_the_index_at_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [nu]]
+            | enumerate --at-end
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"nu","index":0}"#);
+}
+
+#[test]
+fn errors_if_the_rows_already_have_an_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[index]; [a]]
+            | enumerate
+        "#
+    ));
+
+    assert!(actual.err.contains("already have an index column"));
+}
+
+#[test]
+fn replaces_the_index_with_force() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[index]; [a] [b]]
+            | enumerate --force
+            | get index
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,1]");
+}
@@ -65,3 +65,17 @@ fn gets_first_row_when_no_amount_given() {
         assert_eq!(actual.out, "1");
     })
 }
+
+#[test]
+fn stops_pulling_rows_once_it_has_enough() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..
+            | first 3
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
@@ -0,0 +1,45 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_the_first_bytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | first-bytes 16
+        "#
+    ));
+
+    assert!(actual.out.contains("53 51 4c 69"));
+    assert!(!actual.out.contains("more bytes"));
+}
+
+#[test]
+fn keeps_all_bytes_when_asked_for_more() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | first-bytes 100000
+            | size
+            | get bytes
+        "#
+    ));
+
+    assert_eq!(actual.out, "16384");
+}
+
+#[test]
+fn errors_on_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nushell"
+            | first-bytes 2
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("first-bytes expected binary, but received string"));
+}
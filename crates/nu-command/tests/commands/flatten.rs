@@ -10,7 +10,7 @@ fn flatten_nested_tables_with_columns() {
             echo [[origin, people]; [Ecuador, $(= 'Andres' | wrap name)]]
                  [[origin, people]; [Nu, $(= 'nuno' | wrap name)]]
             | flatten
-            | get name
+            | get people.name
             | str collect ','
         "#
     ));
@@ -26,7 +26,7 @@ fn flatten_nested_tables_that_have_many_columns() {
             echo [[origin, people]; [Ecuador, $(echo [[name, meal]; ['Andres', 'arepa']])]]
                  [[origin, people]; [USA, $(echo [[name, meal]; ['Katz', 'nurepa']])]] 
             | flatten
-            | get meal
+            | get people.meal
             | str collect ','
         "#
     ));
@@ -71,7 +71,7 @@ fn flatten_row_column_explicitly() {
 
         let actual = nu!(
             cwd: dirs.test(),
-            "open katz.json | flatten people | where name == Andres | count"
+            "open katz.json | flatten people | where people.name == Andres | count"
         );
 
         assert_eq!(actual.out, "1");
@@ -79,7 +79,7 @@ fn flatten_row_column_explicitly() {
 }
 
 #[test]
-fn flatten_row_columns_having_same_column_names_flats_separately() {
+fn flatten_explodes_lists_and_lifts_the_records_in_them() {
     Playground::setup("flatten_test_2", |dirs, sandbox| {
         sandbox.with_files(vec![FileWithContentToBeTrimmed(
             "katz.json",
@@ -105,7 +105,7 @@ fn flatten_row_columns_having_same_column_names_flats_separately() {
 
         let actual = nu!(
             cwd: dirs.test(),
-            "open katz.json | flatten | flatten people city | get city_name | count"
+            "open katz.json | flatten --explode | get city.name | count"
         );
 
         assert_eq!(actual.out, "4");
@@ -182,3 +182,134 @@ fn flatten_more_than_one_column_that_are_subtables_not_supported() {
         assert!(actual.err.contains("but is flattened already"));
     })
 }
+
+#[test]
+fn flatten_lifts_nested_records_into_dotted_columns() {
+    Playground::setup("flatten_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "glossary.json",
+            r#"
+                {
+                    "glossary": {
+                        "title": "example glossary",
+                        "GlossDiv": {
+                            "title": "S",
+                            "GlossList": ["SGML", "XML"]
+                        }
+                    }
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open glossary.json
+                | flatten
+                | select glossary.GlossDiv.title glossary.title
+                | get glossary.GlossDiv.title
+            "#
+        ));
+
+        assert_eq!(actual.out, "S");
+    })
+}
+
+#[test]
+fn flatten_leaves_lists_as_they_are_without_explode() {
+    Playground::setup("flatten_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "glossary.json",
+            r#"
+                {
+                    "glossary": {
+                        "GlossDiv": {
+                            "GlossList": ["SGML", "XML"]
+                        }
+                    }
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open glossary.json
+                | flatten
+                | get glossary.GlossDiv.GlossList
+                | str collect ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "SGML,XML");
+    })
+}
+
+#[test]
+fn flatten_stops_lifting_at_the_depth_given() {
+    Playground::setup("flatten_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "glossary.json",
+            r#"
+                {
+                    "glossary": {
+                        "GlossDiv": {
+                            "title": "S"
+                        }
+                    }
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open glossary.json
+                | flatten --depth 1
+                | get
+                | str collect ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "glossary.GlossDiv");
+    })
+}
+
+#[test]
+fn flatten_explodes_a_list_into_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, tags]; [nu, [shell, rust]]]
+            | flatten --explode
+            | where tags == rust
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn flatten_errors_when_a_lifted_column_already_exists() {
+    Playground::setup("flatten_test_8", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.json",
+            r#"
+                {
+                    "people": {
+                        "name": "Andres"
+                    },
+                    "people.name": "Katz"
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open katz.json | flatten"
+        );
+
+        assert!(actual.err.contains("Column 'people.name' already exists"));
+    })
+}
@@ -95,3 +95,51 @@ fn errors_if_block_given_evaluates_more_than_one_row() {
         assert!(actual.err.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_nested_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "meta": {"kind": "x"}}, {"name": "b", "meta": {"kind": "y"}}, {"name": "c", "meta": {"kind": "x"}}]'
+            | from json
+            | group-by meta.kind
+            | get x.name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "a,c");
+}
+
+#[test]
+fn groups_rows_missing_the_column_together() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "kind": "x"}, {"name": "b"}, {"name": "c", "kind": null}]'
+            | from json
+            | group-by kind
+            | get "(missing)".name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "b,c");
+}
+
+#[test]
+fn groups_can_be_selected_by_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, kind]; [a, x] [b, y] [c, x]]
+            | group-by kind
+            | select y
+            | get y.name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "b");
+}
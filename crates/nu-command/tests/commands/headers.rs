@@ -29,3 +29,47 @@ fn headers_adds_missing_column_name() {
 
     assert_eq!(actual.out, "r1c1r2c1")
 }
+
+#[test]
+fn headers_numbers_duplicate_names() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo "name name age name|a b 1 c"
+            | split row "|"
+            | split column " "
+            | headers
+            | get
+            | to json"#
+    ));
+
+    assert_eq!(actual.out, r#"["name","name1","age","name2"]"#)
+}
+
+#[test]
+fn headers_renders_cells_that_are_not_strings() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo [[a b]; [2020 2021] [up down]]
+            | headers
+            | get
+            | to json"#
+    ));
+
+    assert_eq!(actual.out, r#"["2020","2021"]"#)
+}
+
+#[test]
+fn headers_from_csv_without_headers() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo "name,stars\nnu,5"
+            | from csv --noheaders
+            | headers
+            | get stars"#
+    ));
+
+    assert_eq!(actual.out, "5")
+}
@@ -29,3 +29,26 @@ fn help_generate_docs_count() {
     let is_positive = output_int.is_positive();
     assert!(is_positive);
 }
+
+#[test]
+fn help_for_an_unknown_command_suggests_a_close_match() {
+    let actual = nu!(cwd: ".", "help opn");
+
+    assert!(actual
+        .err
+        .contains("can't find command, did you mean 'open'?"));
+
+    let actual = nu!(cwd: ".", "help config gett");
+
+    assert!(actual
+        .err
+        .contains("can't find command, did you mean 'config get'?"));
+}
+
+#[test]
+fn help_for_an_unknown_command_without_a_close_match() {
+    let actual = nu!(cwd: ".", "help ferris_the_crab");
+
+    assert!(actual.err.contains("Can't find command"));
+    assert!(!actual.err.contains("did you mean"));
+}
@@ -2,6 +2,14 @@ use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
+// The lengths of the bars in a list of them given as json
+fn bar_lengths(json: &str) -> Vec<usize> {
+    json.trim_matches(|c| c == '[' || c == ']')
+        .split(',')
+        .map(|bar| bar.trim_matches('"').chars().count())
+        .collect()
+}
+
 #[test]
 fn summarizes_by_column_given() {
     Playground::setup("histogram_test_1", |dirs, sandbox| {
@@ -19,17 +27,17 @@ fn summarizes_by_column_given() {
             cwd: dirs.test(), pipeline(
             r#"
                 open los_tres_caballeros.csv
-                | histogram rusty_at countries
-                | where rusty_at == "Ecuador"
+                | histogram rusty_at --frequency-column countries
                 | get countries
+                | to json
             "#
         ));
 
-        assert_eq!(
-            actual.out,
-            "**************************************************"
-        );
-        // 50%
+        let bars = bar_lengths(&actual.out);
+
+        // Estados Unidos comes first with a full bar, and Ecuador gets half of one
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[1], (bars[0] + 1) / 2);
     })
 }
 
@@ -107,3 +115,98 @@ fn count() {
 
     assert_eq!(actual.out, bit_json);
 }
+
+#[test]
+fn sorts_by_count_keeping_ties_in_the_order_first_seen() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo b a c a b d a
+            | histogram
+            | get value
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c","d"]"#);
+}
+
+#[test]
+fn sums_the_column_given_for_each_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[type lines]; [rs 10] [md 3] [rs 5] [toml 15]]
+            | histogram type lines
+            | reject frequency
+            | to json
+        "#
+    ));
+
+    let json = r#"[{"type":"rs","lines":15,"percentage":"100.00%"},{"type":"toml","lines":15,"percentage":"100.00%"},{"type":"md","lines":3,"percentage":"20.00%"}]"#;
+
+    assert_eq!(actual.out, json);
+}
+
+#[test]
+fn sums_the_valuator_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[type lines]; [rs 10] [md 3] [rs 5]]
+            | histogram type --use lines
+            | get lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[15,3]");
+}
+
+#[test]
+fn draws_bars_of_blocks_proportional_to_the_largest_bucket() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo a a a a b b c
+            | histogram
+            | get frequency
+            | to json
+        "#
+    ));
+
+    let bars = bar_lengths(&actual.out);
+
+    assert!(actual.out.contains('█'));
+    assert!(!actual.out.contains('*'));
+    assert!(bars[0] >= bars[1] && bars[1] >= bars[2]);
+    assert_eq!(bars[1], (bars[0] + 1) / 2);
+}
+
+#[test]
+fn summing_a_column_that_is_not_numbers_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[type name]; [rs main.rs]]
+            | histogram type name
+        "#
+    ));
+
+    assert!(actual.err.contains("Can't sum the name column"));
+}
+
+#[test]
+fn is_empty_without_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | histogram type
+            | count
+        "#
+    ));
+
+    assert!(actual.err.is_empty());
+    assert_eq!(actual.out, "0");
+}
@@ -48,3 +48,32 @@ fn condition_is_met() {
         assert_eq!(actual.out, "4");
     })
 }
+
+#[test]
+fn stops_at_the_first_row_that_fails_the_condition() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1 2 5 1 a
+            | keep while { $it < 3 }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
+#[test]
+fn condition_that_is_not_true_or_false_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo $true 3
+            | keep while { = $it }
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Condition for row 1 isn't true or false"));
+}
@@ -64,3 +64,41 @@ fn requests_more_rows_than_table_has() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn gets_the_last_rows_of_a_long_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..100000
+            | last 2
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "199999");
+}
+
+#[test]
+fn gets_no_rows_when_asked_for_none() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] | last 0 | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn rejects_an_amount_that_is_not_a_number() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] | last foo
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected number"));
+}
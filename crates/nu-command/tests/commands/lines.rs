@@ -48,3 +48,60 @@ fn lines_multi_value_split() {
 
     assert_eq!(actual.out, "6");
 }
+
+#[test]
+fn lines_without_a_trailing_empty_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "one\r\ntwo\r\n"
+            | lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["one","two"]"#);
+}
+
+#[test]
+fn lines_ending_split_across_strings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["one\r" "\ntwo"]
+            | lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["one","two"]"#);
+}
+
+#[test]
+fn lines_names_the_type_it_cant_split() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 5 | lines
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("lines expected string, but received integer"));
+}
+
+#[test]
+fn errors_on_binary_data() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | lines
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("lines expected string, but received binary"));
+}
@@ -327,3 +327,178 @@ fn list_all_columns() {
         },
     );
 }
+
+// Joins paths written with forward slashes the way the platform does
+fn native_paths(paths: &[&str]) -> String {
+    paths
+        .iter()
+        .map(|path| path.replace('/', &std::path::MAIN_SEPARATOR.to_string()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[test]
+fn lists_subdirectories_recursively() {
+    Playground::setup("ls_test_10", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("a.txt")])
+            .within("src")
+            .with_files(vec![EmptyFile("lib.rs")])
+            .within("src/commands")
+            .with_files(vec![EmptyFile("ls.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --recursive
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            native_paths(&[
+                "a.txt",
+                "src",
+                "src/commands",
+                "src/commands/ls.rs",
+                "src/lib.rs"
+            ])
+        );
+    })
+}
+
+#[test]
+fn lists_the_directory_given_recursively() {
+    Playground::setup("ls_test_11", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("a.txt")])
+            .within("src")
+            .with_files(vec![EmptyFile("lib.rs")])
+            .within("src/commands")
+            .with_files(vec![EmptyFile("ls.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -r src
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            native_paths(&["src/commands", "src/commands/ls.rs", "src/lib.rs"])
+        );
+    })
+}
+
+#[test]
+fn lists_hidden_entries_recursively_only_with_all() {
+    Playground::setup("ls_test_12", |dirs, sandbox| {
+        sandbox
+            .within(".git")
+            .with_files(vec![EmptyFile("config")])
+            .within("src")
+            .with_files(vec![EmptyFile("lib.rs"), EmptyFile(".hidden")]);
+
+        let without_all = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -r
+                | count
+            "#
+        ));
+
+        let with_all = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -r -a
+                | count
+            "#
+        ));
+
+        assert_eq!(without_all.out, "2");
+        assert_eq!(with_all.out, "5");
+    })
+}
+
+#[test]
+fn does_not_follow_symlinks_when_listing_recursively() {
+    Playground::setup("ls_test_13", |dirs, sandbox| {
+        sandbox.mkdir("src").symlink(".", "src/up");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -r
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(actual.out, native_paths(&["src", "src/up"]));
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn skips_the_contents_of_unreadable_directories_when_listing_recursively() {
+    use std::os::unix::fs::PermissionsExt;
+
+    Playground::setup("ls_test_14", |dirs, sandbox| {
+        sandbox
+            .mkdir("locked")
+            .with_files(vec![EmptyFile("readable.txt")]);
+
+        let locked = dirs.test().join("locked");
+        std::fs::write(locked.join("hidden.txt"), "").expect("can write a file");
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000))
+            .expect("can lock the directory");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls -r
+                | get name
+                | str collect ","
+            "#
+        ));
+
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755))
+            .expect("can unlock the directory");
+
+        assert!(actual.err.is_empty());
+        assert!(actual.out.starts_with("locked"));
+        assert!(actual.out.ends_with("readable.txt"));
+    })
+}
+
+#[test]
+fn lists_all_columns_with_full() {
+    Playground::setup("ls_test_15", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("Leonardo.yaml")]);
+
+        let long = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --long
+                | get
+                | str collect ","
+            "#
+        ));
+
+        let full = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --full
+                | get
+                | str collect ","
+            "#
+        ));
+
+        assert_eq!(full.out, long.out);
+    })
+}
@@ -23,3 +23,31 @@ fn can_average_bytes() {
 
     assert_eq!(actual.out, "1.6 KB");
 }
+
+#[test]
+fn averages_the_column_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name size]; [a 1kb] [b 2kb]]
+            | math avg size
+            | format "{$it}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.5 KB");
+}
+
+#[test]
+fn average_of_nothing_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [] | math avg
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Cannot perform aggregate math operation on empty data"));
+}
@@ -173,6 +173,66 @@ fn modulo() {
     assert_eq!(actual.out, "1");
 }
 
+#[test]
+fn modulo_with_percent() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 7 - 4 % 3
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn filesize_math() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1kb * 2 + 1kb
+        "#
+    ));
+
+    assert_eq!(actual.out, "3.0 KB");
+}
+
+#[test]
+fn filesize_scaled_by_decimal() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1.5 * 1kb
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.5 KB");
+}
+
+#[test]
+fn error_filesize_times_filesize() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1kb * 1kb
+        "#
+    ));
+
+    assert!(actual.err.contains("Coercion error"));
+}
+
+#[test]
+fn error_zero_division_filesize_int() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 1kb / 0
+        "#
+    ));
+
+    assert!(actual.err.contains("division by zero"));
+}
+
 #[test]
 fn duration_math() {
     let actual = nu!(
@@ -256,3 +316,39 @@ fn compound_where_paren() {
 
     assert_eq!(actual.out, r#"[{"a":2,"b":1},{"a":2,"b":2}]"#);
 }
+
+#[test]
+fn where_with_arithmetic() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [[size]; [1kb] [3kb]] | where size > 2 * 1kb | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn radix_integer_literals() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 0x1F + 0o755 + 0b1010
+        "#
+    ));
+
+    assert_eq!(actual.out, "534");
+}
+
+#[test]
+fn error_invalid_radix_digit() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 0o9 + 1
+        "#
+    ));
+
+    assert!(actual.err.contains("an octal digit"));
+}
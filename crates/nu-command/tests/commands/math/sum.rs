@@ -85,3 +85,43 @@ fn sum_of_a_row_containing_a_table_is_an_error() {
         .err
         .contains("Attempted to compute values that can't be operated on"));
 }
+
+#[test]
+fn sums_the_column_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name size]; [a 1kb] [b 2kb]]
+            | math sum size
+            | format "{$it}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "3.0 KB");
+}
+
+#[test]
+fn sum_of_nothing_is_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [] | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn sum_names_the_row_that_is_not_a_number() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[size]; [1] [2] [three]] | math sum size
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Cannot sum row 2, it is a string rather than a number"));
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::files_exist_at;
+use nu_test_support::fs::{files_exist_at, Stub::EmptyFile};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 use std::path::Path;
@@ -82,3 +82,20 @@ fn show_created_paths() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn errors_naming_the_file_in_the_way() {
+    Playground::setup("mkdir_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("build")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mkdir build/debug/artifacts"
+        );
+
+        assert!(actual
+            .err
+            .contains("Can't create build/debug/artifacts because build is a file"));
+        assert!(!dirs.test().join("build").is_dir());
+    })
+}
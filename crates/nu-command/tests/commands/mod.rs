@@ -1,4 +1,7 @@
+mod alias;
+mod and_or;
 mod append;
+mod ast;
 mod autoenv;
 mod autoenv_trust;
 mod autoenv_untrust;
@@ -14,8 +17,10 @@ mod each;
 mod echo;
 mod empty;
 mod enter;
+mod enumerate;
 mod every;
 mod first;
+mod first_bytes;
 mod flatten;
 mod format;
 mod get;
@@ -34,13 +39,16 @@ mod math;
 mod merge;
 mod mkdir;
 mod move_;
+mod nth;
 mod open;
 mod parse;
 mod path;
+mod pivot;
 mod prepend;
 mod random;
 mod range;
 mod reduce;
+mod reject;
 mod rename;
 mod reverse;
 mod rm;
@@ -49,7 +57,9 @@ mod rotate;
 mod save;
 mod select;
 mod semicolon;
+mod size;
 mod skip;
+mod skip_bytes;
 mod sort_by;
 mod split_by;
 mod split_column;
@@ -23,7 +23,7 @@ fn moves_a_column_before() {
             r#"
                 open sample.csv
                 | move column99 --before column1
-                | rename chars
+                | rename column99 chars
                 | get chars
                 | str trim
                 | str collect
@@ -57,7 +57,7 @@ fn moves_columns_before() {
             r#"
                 open sample.csv
                 | move column99 column3 --before column2
-                | rename _ chars_1 chars_2
+                | rename column99 chars_1 column3 chars_2
                 | get chars_2 chars_1
                 | str trim
                 | str collect
@@ -92,7 +92,7 @@ fn moves_a_column_after() {
                 open sample.csv
                 | move letters --after and_more
                 | move letters and_more --before column2
-                | rename _ chars_1 chars_2
+                | rename letters chars_1 and_more chars_2
                 | get chars_1 chars_2
                 | str trim
                 | str collect
@@ -23,7 +23,7 @@ fn moves_a_file() {
 }
 
 #[test]
-fn overwrites_if_moving_to_existing_file() {
+fn overwrites_if_moving_to_existing_file_with_force() {
     Playground::setup("mv_test_2", |dirs, sandbox| {
         sandbox.with_files(vec![EmptyFile("andres.txt"), EmptyFile("jonathan.txt")]);
 
@@ -32,7 +32,7 @@ fn overwrites_if_moving_to_existing_file() {
 
         nu!(
             cwd: dirs.test(),
-            "mv andres.txt jonathan.txt"
+            "mv --force andres.txt jonathan.txt"
         );
 
         assert!(!original.exists());
@@ -40,6 +40,50 @@ fn overwrites_if_moving_to_existing_file() {
     })
 }
 
+#[test]
+fn errors_if_moving_to_existing_file_without_force() {
+    Playground::setup("mv_test_2_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("andres.txt")])
+            .within("expected")
+            .with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mv andres.txt expected"
+        );
+
+        assert!(actual.err.contains("already exists"));
+        assert!(dirs.test().join("andres.txt").exists());
+    })
+}
+
+#[test]
+fn moves_nothing_if_any_destination_exists_without_force() {
+    Playground::setup("mv_test_2_2", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                EmptyFile("andres.txt"),
+                EmptyFile("jonathan.txt"),
+                EmptyFile("yehuda.txt"),
+            ])
+            .within("expected")
+            .with_files(vec![EmptyFile("yehuda.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mv *.txt expected"
+        );
+
+        assert!(actual.err.contains("already exists"));
+        assert!(files_exist_at(
+            vec!["andres.txt", "jonathan.txt", "yehuda.txt"],
+            dirs.test()
+        ));
+        assert!(!dirs.test().join("expected").join("andres.txt").exists());
+    })
+}
+
 #[test]
 fn moves_a_directory() {
     Playground::setup("mv_test_3", |dirs, sandbox| {
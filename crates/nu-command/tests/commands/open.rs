@@ -83,43 +83,18 @@ fn parses_more_bson_complexity() {
 
 // sample.db has the following format:
 //
-// ━━━┯━━━━━━━━━━━━┯━━━━━━━━━━━━━━
-//  # │ table_name │ table_values
-// ───┼────────────┼──────────────
-//  0 │ strings    │ [6 items]
-//  1 │ ints       │ [5 items]
-//  2 │ floats     │ [4 items]
-// ━━━┷━━━━━━━━━━━━┷━━━━━━━━━━━━━━
+// ━━━━━━━━━━━━━━━━┯━━━━━━━━━━━━━━━━┯━━━━━━━━━━━━━━━━
+//  strings        │ ints           │ floats
+// ────────────────┼────────────────┼────────────────
+//  [table 6 rows] │ [table 5 rows] │ [table 4 rows]
+// ━━━━━━━━━━━━━━━━┷━━━━━━━━━━━━━━━━┷━━━━━━━━━━━━━━━━
 //
 // In this case, this represents a sqlite database
 // with three tables named `strings`, `ints`, and `floats`.
-// The table_values represent the values for the tables:
+// Each column holds the rows of its table. `strings` has `x` and `y`, while
+// `ints` has just `z`, and `floats` has only the column `f`:
 //
-// ━━━━┯━━━━━━━┯━━━━━━━━━━┯━━━━━━┯━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-//  #  │ x     │ y        │ z    │ f
-// ────┼───────┼──────────┼──────┼──────────────────────────────────────────────────────────────────────
-//   0 │ hello │ <binary> │      │
-//   1 │ hello │ <binary> │      │
-//   2 │ hello │ <binary> │      │
-//   3 │ hello │ <binary> │      │
-//   4 │ world │ <binary> │      │
-//   5 │ world │ <binary> │      │
-//   6 │       │          │    1 │
-//   7 │       │          │   42 │
-//   8 │       │          │  425 │
-//   9 │       │          │ 4253 │
-//  10 │       │          │      │
-//  11 │       │          │      │                                                    3.400000000000000
-//  12 │       │          │      │                                                    3.141592650000000
-//  13 │       │          │      │                                                    23.00000000000000
-//  14 │       │          │      │ this string that doesn't really belong here but sqlite is what it is
-// ━━━━┷━━━━━━━┷━━━━━━━━━━┷━━━━━━┷━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-//
-// We can see here that each table has different columns. `strings` has `x` and `y`, while
-// `ints` has just `z`, and `floats` has only the column `f`. This means, in general, when working
-// with sqlite, one will want to select a single table, e.g.:
-//
-// open sample.db | nth 1 | get table_values
+// open sample.db | get ints
 // ━━━┯━━━━━━
 //  # │ z
 // ───┼──────
@@ -137,7 +112,7 @@ fn parses_sqlite() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | get table_values
+            | get strings
             | nth 2
             | get x
         "#
@@ -210,12 +185,32 @@ fn parses_ini() {
 fn parses_utf16_ini() {
     let actual = nu!(
         cwd: "tests/fixtures/formats",
-        "open utf16.ini | rename info | get info | get IconIndex"
+        "open utf16.ini | rename --all info | get info | get IconIndex"
     );
 
     assert_eq!(actual.out, "-236")
 }
 
+#[test]
+fn parses_gitconfig_as_ini() {
+    Playground::setup("open_test_gitconfig", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            ".gitconfig",
+            r#"
+                [user]
+                    name = Jonathan Turner
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open .gitconfig | get user.name"
+        );
+
+        assert_eq!(actual.out, "Jonathan Turner");
+    })
+}
+
 #[test]
 fn errors_if_file_not_found() {
     let actual = nu!(
@@ -230,3 +225,15 @@ fn errors_if_file_not_found() {
         expected
     );
 }
+
+#[test]
+fn shows_binary_data_as_a_hex_dump() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open sample.db --raw"
+    );
+
+    assert!(actual.out.contains("0000:   53 51 4c 69"));
+    assert!(actual.out.contains("SQLite format 3"));
+    assert!(actual.out.contains("… 15872 more bytes"));
+}
@@ -0,0 +1,115 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pivots_a_record_into_keys_and_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, version]; [nu, "0.28.0"]]
+            | pivot
+            | where key == version
+            | get value
+        "#
+    ));
+
+    assert_eq!(actual.out, "0.28.0");
+}
+
+#[test]
+fn keeps_nested_values_intact() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"package": {"name": "nu", "authors": ["Andrés", "Jonathan", "Yehuda"]}}'
+            | from json
+            | get package
+            | pivot
+            | where key == authors
+            | get value
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn gives_each_row_of_a_table_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, stars]; [nu, 5] [bat, 4]]
+            | pivot
+            | where Column0 == name
+            | get Column2
+        "#
+    ));
+
+    assert_eq!(actual.out, "bat");
+}
+
+#[test]
+fn names_the_columns_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, version]; [nu, "0.28.0"]]
+            | pivot field setting
+            | get field
+            | str collect ','
+        "#
+    ));
+
+    assert_eq!(actual.out, "name,version");
+}
+
+#[test]
+fn round_trips_a_record() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, version]; [nu, "0.28.0"]]
+            | pivot
+            | pivot --header-row
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"nu","version":"0.28.0"}"#);
+}
+
+#[test]
+fn round_trips_a_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, stars]; [nu, 5] [bat, 4]]
+            | pivot
+            | pivot --header-row
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"nu","stars":5},{"name":"bat","stars":4}]"#
+    );
+}
+
+#[test]
+fn round_trips_the_shape_of_a_table_without_titles() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[a, b, c]; [1, 2, 3] [4, 5, 6]]
+            | pivot --ignore-titles
+            | pivot --ignore-titles
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column0":1,"Column1":2,"Column2":3},{"Column0":4,"Column1":5,"Column2":6}]"#
+    );
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContentToBeTrimmed};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -27,3 +27,45 @@ fn adds_a_row_to_the_beginning() {
         assert_eq!(actual.out, "pollo loco");
     })
 }
+
+#[test]
+fn adds_a_literal_row_before_ls_output() {
+    Playground::setup("prepend_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt"), EmptyFile("jonathan.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | sort-by name
+                | select name type
+                | prepend [[name]; [yehuda.txt]]
+                | get name
+                | str collect ','
+            "#
+        ));
+
+        assert_eq!(actual.out, "yehuda.txt,andres.txt,jonathan.txt");
+    })
+}
+
+#[test]
+fn leaves_the_cells_a_prepended_row_is_missing_empty() {
+    Playground::setup("prepend_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | select name type
+                | prepend [[name]; [yehuda.txt]]
+                | first
+                | get type
+                | empty?
+            "#
+        ));
+
+        assert_eq!(actual.out, "true");
+    })
+}
@@ -43,3 +43,72 @@ fn selects_some_rows() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn selects_rows_from_a_start() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | range 3..
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["d","e"]"#);
+}
+
+#[test]
+fn selects_rows_up_to_an_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | range ..1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b"]"#);
+}
+
+#[test]
+fn clamps_to_the_available_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | range 1..10
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["b","c"]"#);
+}
+
+#[test]
+fn stops_pulling_rows_after_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 0..
+            | range ..3
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,1,2,3]");
+}
+
+#[test]
+fn errors_on_an_inverted_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | range 2..1
+        "#
+    ));
+
+    assert!(actual.err.contains("Range ends before it starts"));
+}
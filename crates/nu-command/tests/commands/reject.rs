@@ -0,0 +1,50 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rejects_columns_keeping_the_order_of_the_rest() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, "last name", size, type]; [a b 1 x] [c d 2 y]]
+            | reject size "last name"
+            | get
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "name type");
+}
+
+#[test]
+fn rejects_columns_of_a_single_record() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name version edition]; [nu 0.28.0 2018]]
+            | first
+            | reject version
+            | get
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "name edition");
+}
+
+#[test]
+fn warns_about_columns_no_row_has() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name size]; [a 1] [b 2]]
+            | reject sise
+            | get
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "name size");
+    assert!(actual
+        .err
+        .contains("no row has a column named 'sise' to reject"));
+}
@@ -21,7 +21,7 @@ fn changes_the_column_name() {
                 open los_cuatro_mosqueteros.txt
                 | lines
                 | wrap name
-                | rename mosqueteros
+                | rename name mosqueteros
                 | get mosqueteros
                 | count
                 "#
@@ -32,7 +32,7 @@ fn changes_the_column_name() {
 }
 
 #[test]
-fn keeps_remaining_original_names_given_less_new_names_than_total_original_names() {
+fn keeps_the_names_of_the_columns_not_renamed() {
     Playground::setup("rename_test_2", |dirs, sandbox| {
         sandbox.with_files(vec![FileWithContentToBeTrimmed(
             "los_cuatro_mosqueteros.txt",
@@ -51,7 +51,7 @@ fn keeps_remaining_original_names_given_less_new_names_than_total_original_names
                 | lines
                 | wrap name
                 | default hit "arepa!"
-                | rename mosqueteros
+                | rename name mosqueteros
                 | get hit
                 | count
                 "#
@@ -79,7 +79,7 @@ fn errors_if_no_columns_present() {
             r#"
                 open los_cuatro_mosqueteros.txt
                 | lines
-                | rename mosqueteros
+                | rename name mosqueteros
                 "#
         ));
 
@@ -93,3 +93,85 @@ fn errors_if_no_columns_present() {
         );
     })
 }
+
+#[test]
+fn renames_columns_by_name_keeping_their_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [["First Name", "Last Name", age]; [Andrés, Robalino, 30]]
+            | rename "Last Name" last_name "First Name" first_name
+            | get
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["first_name","last_name","age"]"#);
+}
+
+#[test]
+fn errors_if_a_column_has_no_new_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[a, b]; [1, 2]]
+            | rename a x b
+        "#
+    ));
+
+    assert!(actual.err.contains("Missing the new name for column 'b'"));
+}
+
+#[test]
+fn errors_if_no_row_has_the_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, age]; [Andrés, 30]]
+            | rename nmae first_name
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column 'nmae'"));
+    assert!(actual.err.contains("available columns: name, age"));
+}
+
+#[test]
+fn errors_if_the_new_name_is_taken() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, age]; [Andrés, 30]]
+            | rename name age
+        "#
+    ));
+
+    assert!(actual.err.contains("Column 'age' already exists"));
+}
+
+#[test]
+fn renames_every_column_with_all() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[column1, column2]; [Andrés, 30]]
+            | rename --all name age
+            | get age
+        "#
+    ));
+
+    assert_eq!(actual.out, "30");
+}
+
+#[test]
+fn errors_if_all_is_not_given_a_name_for_every_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[column1, column2]; [Andrés, 30]]
+            | rename --all name
+        "#
+    ));
+
+    assert!(actual.err.contains("1 new names given for 2 columns"));
+}
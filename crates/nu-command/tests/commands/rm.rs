@@ -276,3 +276,106 @@ fn no_errors_if_attempting_to_delete_non_existent_file_with_f_flag() {
         assert!(!actual.err.contains("no valid path"));
     })
 }
+
+#[test]
+fn refuses_to_remove_anything_if_a_pattern_matches_nothing() {
+    Playground::setup("rm_test_15", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt"), EmptyFile("jonathan.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "rm *.txt *.tmp"
+        );
+
+        assert!(actual.err.contains("No matches found for *.tmp"));
+        assert!(files_exist_at(
+            vec!["yehuda.txt", "jonathan.txt"],
+            dirs.test()
+        ));
+    })
+}
+
+#[test]
+fn refuses_to_remove_the_current_directory() {
+    Playground::setup("rm_test_16", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "rm -r ../rm_test_16"
+        );
+
+        assert!(actual.err.contains("this is the current directory"));
+        assert!(files_exist_at(vec!["yehuda.txt"], dirs.test()));
+    })
+}
+
+#[test]
+fn refuses_to_remove_a_parent_of_the_current_directory() {
+    Playground::setup("rm_test_16_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("yehuda.txt")])
+            .mkdir("src");
+
+        let dots = nu!(
+            cwd: dirs.test().join("src"),
+            "rm -r .."
+        );
+
+        let parent = nu!(
+            cwd: dirs.test().join("src"),
+            "rm -r ../../rm_test_16_1"
+        );
+
+        assert!(dots.err.contains("Cannot remove any parent directory"));
+        assert!(parent.err.contains("this contains the current directory"));
+        assert!(files_exist_at(vec!["yehuda.txt", "src"], dirs.test()));
+    })
+}
+
+#[test]
+fn removes_paths_given_as_input_rows() {
+    Playground::setup("rm_test_17", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("yehuda.txt"),
+            EmptyFile("jonathan.txt"),
+            EmptyFile("andres.txt"),
+        ]);
+
+        nu!(
+            cwd: dirs.test(),
+            "ls j*.txt | rm"
+        );
+
+        nu!(
+            cwd: dirs.test(),
+            "echo yehuda.txt | wrap name | rm"
+        );
+
+        assert!(!dirs.test().join("yehuda.txt").exists());
+        assert!(!dirs.test().join("jonathan.txt").exists());
+        assert!(files_exist_at(vec!["andres.txt"], dirs.test()));
+    })
+}
+
+#[test]
+fn removes_the_other_input_rows_when_one_fails() {
+    Playground::setup("rm_test_18", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("yehuda.txt"), EmptyFile("jonathan.txt")])
+            .within("src")
+            .with_files(vec![EmptyFile("cli.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo [yehuda.txt src missing.txt jonathan.txt] | rm"
+        );
+
+        assert!(actual.err.contains("Could not remove 2 of 4 paths"));
+        assert!(actual.err.contains("src"));
+        assert!(actual.err.contains("missing.txt"));
+        assert!(files_exist_at(vec!["src/cli.rs"], dirs.test()));
+        assert!(!dirs.test().join("yehuda.txt").exists());
+        assert!(!dirs.test().join("jonathan.txt").exists());
+    })
+}
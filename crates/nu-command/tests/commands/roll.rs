@@ -158,7 +158,7 @@ mod columns {
             | each { str to-int }
             | rotate counter-clockwise _
             | reject _
-            | rename bit1 bit2 bit3 bit4 bit5 bit6 bit7 bit8
+            | rename --all bit1 bit2 bit3 bit4 bit5 bit6 bit7 bit8
         "#
             )
         )
@@ -47,3 +47,128 @@ fn writes_out_csv() {
         assert!(actual.contains("nu,0.14,A new type of shell,MIT,2018"));
     })
 }
+
+#[test]
+fn appends_to_an_existing_file() {
+    Playground::setup("save_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("log.txt", "first\n")]);
+
+        nu!(
+            cwd: dirs.test(),
+            r#"echo "second" | save --append log.txt"#
+        );
+
+        let actual = file_contents(dirs.test().join("log.txt"));
+        assert_eq!(actual, "first\nsecond");
+    })
+}
+
+#[test]
+fn redirects_output_to_a_file() {
+    Playground::setup("save_test_4", |dirs, _| {
+        nu!(cwd: dirs.test(), "echo hello > out.txt");
+        nu!(cwd: dirs.test(), "echo there >> out.txt");
+
+        let actual = file_contents(dirs.test().join("out.txt"));
+        assert_eq!(actual, "hellothere");
+    })
+}
+
+#[test]
+fn redirects_structured_output_through_the_extension_converter() {
+    Playground::setup("save_test_5", |dirs, _| {
+        nu!(
+            cwd: dirs.test(),
+            "echo [[name]; [nu]] > out.csv"
+        );
+
+        let actual = file_contents(dirs.test().join("out.csv"));
+        assert!(actual.contains("name\nnu"));
+    })
+}
+
+#[test]
+fn comparisons_in_conditions_are_not_redirections() {
+    Playground::setup("save_test_6", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo [1 2 3] | where $it > 1 | count"
+        );
+
+        assert_eq!(actual.out, "2");
+        assert!(!dirs.test().join("1").exists());
+
+        nu!(
+            cwd: dirs.test(),
+            "echo [1 2 3] | where $it > 1 >> big.json"
+        );
+
+        let actual = file_contents(dirs.test().join("big.json"));
+        assert_eq!(actual, "[2,3]");
+    })
+}
+
+#[test]
+fn redirection_requires_a_file() {
+    Playground::setup("save_test_7", |dirs, _| {
+        let actual = nu!(cwd: dirs.test(), "echo hello >");
+
+        assert!(actual.err.contains("a file to redirect to"));
+    })
+}
+
+#[test]
+fn overwrites_an_existing_file() {
+    Playground::setup("save_test_8", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("notes.txt", "old notes")]);
+
+        nu!(
+            cwd: dirs.test(),
+            r#"echo "new notes" | save notes.txt"#
+        );
+
+        let actual = file_contents(dirs.test().join("notes.txt"));
+        assert_eq!(actual, "new notes");
+    })
+}
+
+#[test]
+fn writes_the_text_of_values_with_raw() {
+    Playground::setup("save_test_9", |dirs, _| {
+        nu!(
+            cwd: dirs.test(),
+            "echo [1 2 3] | save --raw numbers.json"
+        );
+
+        let actual = file_contents(dirs.test().join("numbers.json"));
+        assert_eq!(actual, "1\n2\n3");
+    })
+}
+
+#[test]
+fn keeps_the_file_when_the_format_cant_hold_the_values() {
+    Playground::setup("save_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("crates.csv", "name\nnu\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo [[name, authors]; [nu, [[name]; [yehuda]]]] | save crates.csv"
+        );
+
+        assert!(actual.err.contains("can't hold nested data"));
+        assert_eq!(file_contents(dirs.test().join("crates.csv")), "name\nnu\n");
+    })
+}
+
+#[test]
+fn errors_if_the_directory_does_not_exist() {
+    Playground::setup("save_test_11", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo hello | save missing/out.txt"#
+        );
+
+        assert!(actual.err.contains("does not exist"));
+        assert!(!dirs.test().join("missing").exists());
+    })
+}
@@ -55,8 +55,8 @@ fn complex_nested_columns() {
             r#"
                 open los_tres_caballeros.json
                 | select nu."0xATYKARNU" nu.committers.name nu.releases.version
-                | where nu_releases_version > "0.8"
-                | get nu_releases_version
+                | where version > "0.8"
+                | get version
             "#
         ));
 
@@ -65,7 +65,7 @@ fn complex_nested_columns() {
 }
 
 #[test]
-fn allows_if_given_unknown_column_name_is_missing() {
+fn errors_if_given_unknown_column_name() {
     let actual = nu!(cwd: ".", pipeline(
         r#"
             echo [
@@ -76,11 +76,11 @@ fn allows_if_given_unknown_column_name_is_missing() {
                 [Yehuda Katz 10/11/2013 A]
             ]
             | select rrusty_at first_name
-            | count
         "#
     ));
 
-    assert_eq!(actual.out, "3");
+    assert!(actual.err.contains("Unknown column"));
+    assert!(actual.err.contains("rusty_at"));
 }
 
 #[test]
@@ -120,3 +120,64 @@ fn ignores_duplicate_columns_selected() {
 
     assert_eq!(actual.out, "first name last name");
 }
+
+#[test]
+fn quoted_members_in_nested_columns() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '{"my table": {"serde json": {"version": "1.0"}}}'
+            | from json
+            | select "my table"."serde json".version
+            | get version
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.0");
+}
+
+#[test]
+fn keeps_rows_missing_some_of_the_columns() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "size": 1}, {"name": "b"}]'
+            | from json
+            | select name size
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"a","size":1},{"name":"b","size":null}]"#
+    );
+}
+
+#[test]
+fn names_nested_columns_after_their_last_member() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '{"package": {"name": "nu", "version": "0.28.0"}}'
+            | from json
+            | select package.version package.name
+            | get
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "version name");
+}
+
+#[test]
+fn keeps_the_full_path_of_nested_columns_with_the_same_name() {
+    let actual = nu!(cwd: ".", pipeline(
+        r#"
+            echo '{"package": {"name": "nu"}, "author": {"name": "jt"}}'
+            | from json
+            | select package.name author.name
+            | get
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "package_name author_name");
+}
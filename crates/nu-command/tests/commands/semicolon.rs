@@ -27,3 +27,14 @@ fn semicolon_lhs_error_stops_processing() {
 
     assert!(!actual.out.contains("done"));
 }
+
+#[test]
+fn semicolon_tolerates_empty_statements() {
+    let actual = nu!(
+        cwd: ".",
+        "; echo a;; echo b;"
+    );
+
+    assert_eq!(actual.out, "b");
+    assert!(actual.err.is_empty());
+}
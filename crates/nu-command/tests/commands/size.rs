@@ -0,0 +1,108 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn counts_unicode_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "Amélie Amelie"
+            | size
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"lines":0,"words":2,"chars":13,"bytes":15}"#);
+}
+
+#[test]
+fn counts_across_lines_by_default() {
+    Playground::setup("size_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.txt",
+            "one two\nthree\nfour five six\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.txt
+                | lines
+                | size
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"lines":3,"words":6,"chars":25,"bytes":25}"#);
+    })
+}
+
+#[test]
+fn counts_a_whole_file() {
+    Playground::setup("size_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.txt",
+            &"nu shell\n".repeat(2000),
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.txt --raw
+                | size
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"{"lines":2000,"words":4000,"chars":18000,"bytes":18000}"#
+        );
+    })
+}
+
+#[test]
+fn counts_each_row_with_per_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "one two" "three" "four five six"
+            | size --per-row
+            | get words
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,1,3]");
+}
+
+#[test]
+fn counts_only_bytes_of_binary_data() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | size
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"lines":null,"words":null,"chars":null,"bytes":16384}"#
+    );
+}
+
+#[test]
+fn errors_on_values_that_are_not_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[a]; [1]]
+            | size
+        "#
+    ));
+
+    assert!(actual.err.contains("size expected string or binary, but received row"));
+}
@@ -48,3 +48,46 @@ fn condition_is_met() {
         assert_eq!(actual.out, "6");
     })
 }
+
+#[test]
+fn skips_a_preamble_given_in_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r##"
+            echo "# generated" "# by nu" "first" "# not skipped"
+            | skip while { $it =~ "#" }
+            | to json
+        "##
+    ));
+
+    assert_eq!(actual.out, r##"["first","# not skipped"]"##);
+}
+
+#[test]
+fn stops_checking_the_condition_once_it_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1 5 a
+            | skip while $it < 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[5,"a"]"#);
+}
+
+#[test]
+fn condition_that_is_not_true_or_false_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo $true $true 3 4
+            | skip while { = $it }
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Condition for row 2 isn't true or false"));
+}
@@ -0,0 +1,31 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn skips_the_first_bytes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | skip-bytes 7
+            | first-bytes 6
+        "#
+    ));
+
+    assert!(actual.out.contains("66 6f 72 6d  61 74"));
+    assert!(actual.out.contains("format"));
+}
+
+#[test]
+fn skips_everything_when_asked_for_more() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | skip-bytes 100000
+            | size
+            | get bytes
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
@@ -44,20 +44,80 @@ fn by_invalid_column() {
 }
 
 #[test]
-fn by_invalid_types() {
+fn mixed_types_sort_by_kind() {
     let actual = nu!(
-        cwd: "tests/fixtures/formats", pipeline(
+        cwd: ".", pipeline(
         r#"
-            open cargo_sample.toml --raw
-            | echo [1 "foo"]
+            echo [1 "foo" 3kb 2 $true "bar"]
             | sort-by
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[true,1,2,3000,"bar","foo"]"#);
+}
+
+#[test]
+fn by_nested_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"package": {"name": "b"}}, {"package": {"name": "c"}}, {"package": {"name": "a"}}]'
+            | from json
+            | sort-by package.name
+            | get package.name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
+#[test]
+fn rows_missing_the_column_go_last() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "x"}, {"name": "y", "size": 2}, {"name": "z", "size": 1}]'
+            | from json
+            | sort-by size
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["z","y","x"]"#);
+}
+
+#[test]
+fn rows_missing_the_column_go_last_in_reverse_too() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "x"}, {"name": "y", "size": 2}, {"name": "z", "size": 1}]'
+            | from json
+            | sort-by size --reverse
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["y","z","x"]"#);
+}
+
+#[test]
+fn later_columns_break_ties() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[type name size]; [File b 2kb] [Dir a 4kb] [File a 10b] [Dir b 1kb]]
+            | sort-by type size
+            | get name
+            | to json
         "#
     ));
 
-    assert!(actual.err.contains("Not all values can be compared"));
-    assert!(actual
-        .err
-        .contains("Unable to sort values, as \"integer\" cannot compare against \"string\""));
+    assert_eq!(actual.out, r#"["b","a","a","b"]"#);
 }
 
 #[test]
@@ -26,3 +26,73 @@ fn to_column() {
         assert!(actual.out.contains("shipper"));
     })
 }
+
+#[test]
+fn to_named_columns_keeping_the_rest_in_the_last() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "root:x:0:0:root:/root:/bin/bash"
+            | split column ":" user password rest
+            | get rest
+        "#
+    ));
+
+    assert_eq!(actual.out, "0:0:root:/root:/bin/bash");
+}
+
+#[test]
+fn leaves_columns_without_a_piece_empty() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu::shell"
+            | split column "::" name kind version
+            | get version
+            | empty?
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn errors_on_extra_pieces_when_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "a,b,c"
+            | split column "," first second --strict
+        "#
+    ));
+
+    assert!(actual.err.contains("Found 3 pieces for 2 columns"));
+}
+
+#[test]
+fn collapses_repeated_separators() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "a  b   c"
+            | split column " " --collapse-empty
+            | get Column3
+        "#
+    ));
+
+    assert_eq!(actual.out, "c");
+}
+
+#[test]
+fn keeps_the_separators_of_the_rest_when_collapsing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "ls  -l   nu  shell"
+            | split column " " --collapse-empty command args
+            | get args
+        "#
+    ));
+
+    assert_eq!(actual.out, "-l   nu  shell");
+}
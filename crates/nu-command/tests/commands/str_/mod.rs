@@ -125,7 +125,7 @@ fn converts_to_int() {
             echo '{number_as_string: "1"}'
             | from json
             | str to-int number_as_string
-            | rename number
+            | rename number_as_string number
             | where number == 1
             | get number
 
@@ -354,3 +354,33 @@ fn str_reverse() {
 
     assert!(actual.out.contains("llehsun"));
 }
+
+#[test]
+fn upcases_a_column_and_leaves_the_others() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name, lang]; [andrés, es] [jonathan, en]]
+        | str upcase name
+        | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"ANDRÉS","lang":"es"},{"name":"JONATHAN","lang":"en"}]"#
+    );
+}
+
+#[test]
+fn names_the_row_of_a_cell_that_is_not_a_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo [[name]; [andres] [5]]
+        | str downcase name
+        "#
+    ));
+
+    assert!(actual.err.contains("Value in row 1 is not a string"));
+}
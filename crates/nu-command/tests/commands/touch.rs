@@ -1,3 +1,5 @@
+use filetime::FileTime;
+use nu_test_support::fs::Stub::FileWithContent;
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
 
@@ -29,3 +31,27 @@ fn creates_two_files() {
         assert!(path2.exists());
     })
 }
+
+#[test]
+fn updates_the_modification_time_of_an_existing_file() {
+    Playground::setup("create_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("notes.txt", "keep me")]);
+
+        let path = dirs.test().join("notes.txt");
+        filetime::set_file_mtime(&path, FileTime::from_unix_time(0, 0))
+            .expect("can not set the modification time");
+
+        nu!(
+            cwd: dirs.test(),
+            "touch notes.txt"
+        );
+
+        let metadata = std::fs::metadata(&path).expect("can not read metadata");
+
+        assert!(FileTime::from_last_modification_time(&metadata) > FileTime::from_unix_time(0, 0));
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("can not read file"),
+            "keep me"
+        );
+    })
+}
@@ -209,3 +209,61 @@ fn uniq_simple_vals_strs() {
     print!("{}", expected.out);
     assert_eq!(actual.out, expected.out);
 }
+
+#[test]
+fn uniq_by_column_keeps_the_first_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name type]; [a x] [b y] [c x]]
+            | uniq type
+            | get name
+            | str collect ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "a,b");
+}
+
+#[test]
+fn uniq_by_column_counting() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name type]; [a x] [b y] [c x]]
+            | uniq type -c
+            | where name == a
+            | get count
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn uniq_by_unknown_column_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name type]; [a x] [b y]]
+            | uniq kind
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column"));
+}
+
+#[test]
+fn uniq_tells_numbers_and_strings_apart() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[1, "1", 1, {"a": [1, 2]}, {"a": [1, 2]}, {"a": [2, 1]}]'
+            | from json
+            | uniq
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
@@ -58,3 +58,48 @@ fn sets_the_column_from_an_invocation() {
 
     assert_eq!(actual.out, "true");
 }
+
+#[test]
+fn sets_the_column_in_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, stars]; [nu, 5] [bat, 3]]
+            | update stars 10
+            | get stars
+            | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "20");
+}
+
+#[test]
+fn sets_the_column_to_a_boolean() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | update package.edition true
+            | get package.edition
+            | describe
+        "#
+    ));
+
+    assert_eq!(actual.out, "boolean");
+}
+
+#[test]
+fn errors_if_the_column_does_not_exist() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | update package.versoin "0.2.0"
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Cannot update package.versoin, the column doesn't exist"));
+}
@@ -1,7 +1,6 @@
-use nu_test_support::nu;
-
-#[cfg(feature = "sqlite")]
-use nu_test_support::pipeline;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContent};
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
 
 #[test]
 fn filters_by_unit_size_comparison() {
@@ -13,6 +12,225 @@ fn filters_by_unit_size_comparison() {
     assert_eq!(actual.out, "cargo_sample.toml");
 }
 
+#[test]
+fn filters_by_unit_size_comparison_in_any_case() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where size < 10MB && size >= 1KIB | where size < 1gib | count"
+    );
+
+    assert_eq!(actual.out, "10");
+}
+
+#[test]
+fn filters_by_unknown_unit_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where size < 1XB"
+    );
+
+    assert!(actual.err.contains("Expected a unit"));
+}
+
+#[test]
+fn filters_by_decimal_unit_size_comparison() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo [1400b 1600b 1.5kb] | where $it < 1.55KB | math sum"#
+    );
+
+    assert_eq!(actual.out, "2.9 KB");
+}
+
+#[test]
+fn filters_by_duration_comparison() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [10ms 30s 2m 1h 2d] | where $it > 30s && $it < 1w | count"
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn filters_dates_by_how_long_ago_they_were() {
+    Playground::setup("where_test_durations", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("just_touched.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where modified < 1d | where modified > 2d | count"
+        );
+
+        assert_eq!(actual.out, "0");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where modified < 1d | get name"
+        );
+
+        assert_eq!(actual.out, "just_touched.txt");
+    })
+}
+
+#[test]
+fn filters_by_date_literals() {
+    Playground::setup("where_test_dates", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("just_touched.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where modified > 2019-01-01 | get name"
+        );
+
+        assert_eq!(actual.out, "just_touched.txt");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where modified < 2019-01-01T12:00:00Z | count"
+        );
+
+        assert_eq!(actual.out, "0");
+    })
+}
+
+#[test]
+fn compares_date_literals() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[when]; [2019-06-01T12:00:00Z] [2018-12-31T23:00:00-02:00]] | where when < 2019-01-01T00:00:00Z | count"
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn comparing_a_date_with_a_string_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where name > 2019-01-01"
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+    assert!(actual.err.contains("date"));
+}
+
+#[test]
+fn filters_by_duration_against_file_size_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where size > 2d"
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+}
+
+#[test]
+fn filters_with_parenthesized_conditions() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"ls | where (size > 1KB) && (name != "cargo_sample.toml") | where name == "cargo_sample.toml" | count"#
+    );
+
+    assert_eq!(actual.out, "0");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"ls | where (size < 1KB || (name == "cargo_sample.toml" && size > 1KB)) && name != "x" | where name == "cargo_sample.toml" | count"#
+    );
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn filters_with_not() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"ls | where not (name == "cargo_sample.toml") | where name == "cargo_sample.toml" | count"#
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn not_negates_only_the_comparison_before_and() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[x y]; [1 2] [1 3] [2 2]]
+            | where not x == 1 && y == 2
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn not_requires_a_boolean() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where not name"
+    );
+
+    assert!(actual.err.contains("Expected boolean"));
+}
+
+#[test]
+fn not_can_still_be_a_column_name() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"not": 1}, {"not": 2}]' | from json | where not == 2 | select not | get not"#
+    );
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn filters_by_member_of_it() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where $it.size > 1KB | count"
+    );
+
+    let expected = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where size > 1KB | count"
+    );
+
+    assert_eq!(actual.out, expected.out);
+}
+
+#[test]
+fn filters_with_every_comparison_operator() {
+    let cases = [
+        ("echo [a b c] | where $it >= b | str collect", "bc"),
+        ("echo [a b c] | where $it <= b | str collect", "ab"),
+        ("echo [a b c] | where $it != b | str collect", "ac"),
+        ("echo [1 2 3] | where $it >= 2 | math sum", "5"),
+        ("echo [1 2 3] | where $it <= 2 | math sum", "3"),
+        ("echo [1.5 2.5] | where $it == 2.5 | math sum", "2.5"),
+        ("echo [1.5 2 2.5] | where $it >= 2 | math sum", "4.5"),
+        ("echo [1kb 2kb] | where $it <= 1000b | math sum", "1,000 B"),
+    ];
+
+    for (command, expected) in cases.iter() {
+        let actual = nu!(cwd: "tests/fixtures/formats", *command);
+
+        assert_eq!(actual.out, *expected, "{}", command);
+    }
+}
+
+#[test]
+fn comparing_incompatible_types_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where name >= 1KB"
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+}
+
 #[test]
 fn filters_with_nothing_comparison() {
     let actual = nu!(
@@ -23,6 +241,16 @@ fn filters_with_nothing_comparison() {
     assert_eq!(actual.out, "7");
 }
 
+#[test]
+fn filters_with_negative_number_comparison() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo '[{"count": 1}, {"count": -10}, {"count": -3}]' | from json | where count > -5 | get count | math sum"#
+    );
+
+    assert_eq!(actual.out, "-2");
+}
+
 #[test]
 fn where_in_table() {
     let actual = nu!(
@@ -50,8 +278,7 @@ fn explicit_block_condition() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where {= $it.z > 4200}
             | get z
@@ -68,8 +295,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z > 4200
             | get z
@@ -82,8 +308,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z >= 4253
             | get z
@@ -96,8 +321,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z < 10
             | get z
@@ -110,8 +334,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | first 4
             | where z <= 1
             | get z
@@ -124,8 +347,7 @@ fn binary_operator_comparisons() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == ints
-            | get table_values
+            | get ints
             | where z != 1
             | first 1
             | get z
@@ -142,8 +364,7 @@ fn contains_operator() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == strings
-            | get table_values
+            | get strings
             | where x =~ ell
             | count
         "#
@@ -155,8 +376,7 @@ fn contains_operator() {
         cwd: "tests/fixtures/formats", pipeline(
         r#"
             open sample.db
-            | where table_name == strings
-            | get table_values
+            | get strings
             | where x !~ ell
             | count
         "#
@@ -164,3 +384,272 @@ fn contains_operator() {
 
     assert_eq!(actual.out, "2");
 }
+
+#[test]
+fn contains_operator_accepts_bare_and_quoted_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where name =~ toml | count"
+    );
+
+    assert_eq!(actual.out, "1");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"ls | where $it.name =~ ".toml" | count"#
+    );
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn contains_operator_is_case_sensitive() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [Abc abc] | where $it =~ ab | count"
+    );
+
+    assert_eq!(actual.out, "1");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [Abc abc] | where $it !~ ab"
+    );
+
+    assert_eq!(actual.out, "Abc");
+}
+
+#[test]
+fn contains_operator_requires_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [1 2] | where $it =~ 1"
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+}
+
+#[test]
+fn filters_by_membership_in_a_list() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name]; [a] [b] [c]] | where name in [a c \"d e\"] | count"
+    );
+
+    assert_eq!(actual.out, "2");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name]; [a] [b] [c]] | where name not-in [a c] | get name"
+    );
+
+    assert_eq!(actual.out, "b");
+}
+
+#[test]
+fn membership_requires_a_list() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name]; [a] [b]] | where name in a"
+    );
+
+    assert!(actual.err.contains("Expected list, found string"));
+}
+
+#[test]
+fn membership_looks_for_a_single_value() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name]; [a] [b]] | where [a] in [a b]"
+    );
+
+    assert!(actual.err.contains("Expected a single value, found table"));
+}
+
+#[test]
+fn filters_with_a_condition_inside_a_block() {
+    Playground::setup("where_test_blocks", |dirs, sandbox| {
+        let contents = "x".repeat(2048);
+        sandbox.with_files(vec![
+            FileWithContent("big.txt", &contents),
+            EmptyFile("yehuda.txt"),
+            EmptyFile("jonathan.txt"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where { $it.size > 1kb } | count"
+        );
+
+        assert_eq!(actual.out, "1");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ls | where { not ($it.size > 1kb) } | count"
+        );
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn comparison_errors_point_at_the_operands() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | where size < \"foo\""
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+    assert!(actual.err.contains("filesize"));
+    assert!(actual.err.contains("string"));
+}
+
+#[test]
+fn filters_by_a_boolean_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name enabled]; [a true] [b false] [c true]] | where enabled | get name | to json"
+    );
+
+    assert_eq!(actual.out, r#"["a","c"]"#);
+}
+
+#[test]
+fn quoted_booleans_are_strings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo [true "true"] | to json"#
+    );
+
+    assert_eq!(actual.out, r#"[true,"true"]"#);
+}
+
+#[test]
+fn filters_by_a_nested_column_path() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"open cargo_sample.toml | where package.version != "0.1.0" | get package.name"#
+    );
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn unknown_columns_list_the_available_columns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [[name size]; [a 1] [b 2]] | where sise > 1"
+    );
+
+    assert!(actual.err.contains("Unknown column"));
+    assert!(actual
+        .err
+        .contains("did you mean 'size'? (available columns: name, size)"));
+}
+
+#[test]
+fn unknown_nested_columns_list_the_available_columns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"open cargo_sample.toml | where package.nme == "nu""#
+    );
+
+    assert!(actual.err.contains("did you mean 'name'?"));
+    assert!(actual
+        .err
+        .contains("available columns: authors, description, edition, license, name, version"));
+}
+
+#[test]
+fn filters_rows_as_they_stream_in() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1..
+            | skip 2
+            | where $it mod 5 == 0
+            | first 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[5,10]");
+}
+
+#[test]
+fn filters_by_regex() {
+    Playground::setup("where_test_regex_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("test_1.txt"),
+            EmptyFile("test_22.txt"),
+            EmptyFile("test_notes.txt"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | where name =~ r'^test_\d+\.txt$'
+                | count
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn filters_out_by_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [test_1] [test_notes]]
+            | where name !~ r'\d$'
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "test_notes");
+}
+
+#[test]
+fn keeps_slashed_strings_as_substrings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [/tmp/notes.txt] [/home/notes.txt]]
+            | where name =~ /tmp/
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "/tmp/notes.txt");
+}
+
+#[test]
+fn points_at_an_invalid_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [test_1]]
+            | where name =~ r'*test'
+        "#
+    ));
+
+    assert!(actual.err.contains("valid regular expression"));
+}
+
+#[test]
+fn condition_that_is_not_true_or_false_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo $true hello
+            | where { = $it }
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Condition for row 1 isn't true or false"));
+}
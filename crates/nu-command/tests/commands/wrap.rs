@@ -59,3 +59,35 @@ fn wrap_rows_into_a_table() {
         assert_eq!(actual.out, "Katz");
     })
 }
+
+#[test]
+fn wrap_values_and_unwrap_them_with_get() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "pear,apple,fig"
+            | split row ","
+            | wrap word
+            | sort-by word
+            | get word
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["apple","fig","pear"]"#);
+}
+
+#[test]
+fn wrap_values_leaving_rows_among_them_untouched() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[word]; [pear]] fig
+            | wrap word
+            | get word
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["pear","fig"]"#);
+}
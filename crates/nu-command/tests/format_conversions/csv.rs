@@ -207,3 +207,60 @@ fn from_csv_text_skipping_headers_to_table() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn from_csv_text_with_quoted_commas_and_newlines() {
+    Playground::setup("filter_from_csv_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.txt",
+            r#"
+                first_name,note
+                Andrés,"likes commas, a lot"
+                Jonathan,"two
+                lines"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.txt
+                | from csv
+                | get note
+                | each { echo $it | lines | str collect "|" }
+                | str collect " / "
+            "#
+        ));
+
+        assert_eq!(actual.out, "likes commas, a lot / two|lines");
+    })
+}
+
+#[test]
+fn table_to_csv_text_quotes_only_when_needed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, note]; [plain, "has, a comma"]]
+            | to csv
+            | lines
+            | nth 1
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"plain,"has, a comma""#);
+}
+
+#[test]
+fn table_with_nested_values_to_csv_is_an_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name, versions]; [nu, [0.27 0.28]]]
+            | to csv
+        "#
+    ));
+
+    assert!(actual.err.contains("CSV can't hold nested data"));
+    assert!(actual.err.contains("column 'versions' holds a table"));
+}
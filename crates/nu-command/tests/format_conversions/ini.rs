@@ -0,0 +1,116 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reads_sections_and_keys_outside_of_them() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | get editor user.name
+            | str collect ", "
+        "#
+    ));
+
+    assert_eq!(actual.out, "vim, Jonathan Turner");
+}
+
+#[test]
+fn keeps_subsection_headers() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | get 'remote "origin"'
+            | get url
+        "#
+    ));
+
+    assert_eq!(actual.out, "https://github.com/nushell/nushell.git");
+}
+
+#[test]
+fn splits_keys_from_values_at_the_first_equals_sign() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | get alias.lg
+        "#
+    ));
+
+    assert_eq!(actual.out, "log --graph --pretty=format:'%h %s'");
+}
+
+#[test]
+fn keeps_the_order_of_sections() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | pivot
+            | get key
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"editor user remote "origin" alias"#);
+}
+
+#[test]
+fn from_ini_and_to_ini_round_trip() {
+    let original = nu!(
+        cwd: "tests/fixtures/formats",
+        "open gitconfig.ini | to json"
+    );
+
+    let round_tripped = nu!(
+        cwd: "tests/fixtures/formats",
+        "open gitconfig.ini | to ini | from ini | to json"
+    );
+
+    assert_eq!(round_tripped.out, original.out);
+}
+
+#[test]
+fn to_ini_and_from_ini_round_trip() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | to ini
+            | from ini
+            | to ini
+            | from ini
+            | get user.email
+        "#
+    ));
+
+    assert_eq!(actual.out, "jonathan@example.com");
+}
+
+#[test]
+fn refuses_nested_data_in_sections() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open gitconfig.ini
+            | insert user.languages [rust nu]
+            | to ini
+        "#
+    ));
+
+    assert!(actual.err.contains("INI can't hold nested data"));
+}
+
+#[test]
+fn reports_the_line_that_failed_to_parse() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "[user]\nname = nu\nnot a pair\n"
+            | from ini
+        "#
+    ));
+
+    assert!(actual.err.contains("line 3"));
+}
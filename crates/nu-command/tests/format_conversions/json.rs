@@ -98,3 +98,59 @@ fn table_to_json_text() {
         assert_eq!(actual.out, "JonAndrehudaTZ");
     })
 }
+
+#[test]
+fn json_round_trip_is_stable() {
+    let json = r#"{"name": "nu", "size": 1024, "tags": ["a", "b"], "nested": {"ok": true, "ratio": 0.5, "none": null}}"#;
+
+    let once = nu!(
+        cwd: ".",
+        format!("echo '{}' | from json | to json", json)
+    );
+    let twice = nu!(
+        cwd: ".",
+        format!("echo '{}' | from json | to json | from json | to json", json)
+    );
+
+    assert!(once.out.contains(r#""nested":{"#));
+    assert_eq!(once.out, twice.out);
+}
+
+#[test]
+fn file_sizes_to_json_are_bytes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1kb 2b] | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1000,2]");
+}
+
+#[test]
+fn dates_to_json_are_rfc3339() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2021-01-02T03:04:05+00:00"
+            | str to-datetime
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""2021-01-02T03:04:05+00:00""#);
+}
+
+#[test]
+fn binary_data_to_json_is_an_error() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw | to json
+        "#
+    ));
+
+    assert!(actual.err.contains("Binary data can't be written as JSON"));
+    assert!(actual.err.contains("hash base64"));
+}
@@ -3,6 +3,7 @@ mod csv;
 mod eml;
 mod html;
 mod ics;
+mod ini;
 mod json;
 mod markdown;
 mod ods;
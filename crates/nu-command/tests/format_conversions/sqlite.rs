@@ -10,7 +10,7 @@ fn table_to_sqlite_and_back_into_table() {
             open sample.db
             | to sqlite
             | from sqlite
-            | get table_values
+            | get strings
             | nth 2
             | get x
         "#
@@ -14,3 +14,19 @@ fn table_to_toml_text_and_from_toml_text_back_into_table() {
 
     assert_eq!(actual.out, "nu");
 }
+
+#[test]
+fn booleans_round_trip_as_booleans() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [[name enabled]; [nu true]]
+            | first
+            | to toml
+            | from toml
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"enabled":true,"name":"nu"}"#);
+}
@@ -14,3 +14,32 @@ fn table_to_xml_text_and_from_xml_text_back_into_table() {
 
     assert_eq!(actual.out, "true");
 }
+
+#[test]
+fn keeps_namespace_prefixes_in_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '<rss xmlns:dc="http://purl.org/dc/elements/1.1/"><item dc:id="1"><dc:creator>jt</dc:creator></item></rss>'
+            | from xml
+            | get rss.children.item
+            | format "{attributes.dc:id} {children.0.dc:creator.children.0}"
+        "#
+    ));
+
+    assert_eq!(actual.out, "1 jt");
+}
+
+#[test]
+fn malformed_xml_errors_with_the_position() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '<rss><item></rss>' | from xml
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Could not parse as XML: expected 'item' tag, not 'rss' at 1:12"));
+}
@@ -14,3 +14,18 @@ fn table_to_yaml_text_and_from_yaml_text_back_into_table() {
 
     assert_eq!(actual.out, "nushell");
 }
+
+#[test]
+fn yaml_documents_become_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "kind: Service\n---\nkind: Deployment\n"
+            | from yaml
+            | get kind
+            | str collect " "
+        "#
+    ));
+
+    assert_eq!(actual.out, "Service Deployment");
+}
@@ -88,6 +88,8 @@ impl CompareValues {
             CompareValues::String(left, right) => left.cmp(right),
             CompareValues::Date(left, right) => left.cmp(right),
             CompareValues::DateDuration(left, right) => {
+                // Comparing a date with a duration compares how long ago the date was, so
+                // `modified > 2day` matches dates that are more than two days old.
                 // FIXME: Not sure if I could do something better with the Span.
                 let duration = Primitive::into_chrono_duration(
                     Primitive::Duration(right.clone()),
@@ -146,6 +148,7 @@ pub fn coerce_compare_primitive(
         (String(left), String(right)) => CompareValues::String(left.clone(), right.clone()),
         (Date(left), Date(right)) => CompareValues::Date(*left, *right),
         (Date(left), Duration(right)) => CompareValues::DateDuration(*left, right.clone()),
+        (Duration(left), Duration(right)) => CompareValues::Ints(left.clone(), right.clone()),
         (Boolean(left), Boolean(right)) => CompareValues::Booleans(*left, *right),
         (Boolean(left), Nothing) => CompareValues::Booleans(*left, false),
         (Nothing, Boolean(right)) => CompareValues::Booleans(false, *right),
@@ -67,6 +67,12 @@ fn zero_division_error() -> UntaggedValue {
     UntaggedValue::Error(ShellError::untagged_runtime_error("division by zero"))
 }
 
+/// Scaling a filesize by a decimal rounds it down to whole bytes
+fn filesize_from_decimal(bytes: bigdecimal::BigDecimal) -> UntaggedValue {
+    let (bytes, _) = bytes.with_scale(0).as_bigint_and_exponent();
+    UntaggedValue::Primitive(Primitive::Filesize(bytes))
+}
+
 pub fn unsafe_compute_values(
     operator: Operator,
     left: &UntaggedValue,
@@ -112,13 +118,38 @@ pub fn compute_values(
             }
             (Primitive::Filesize(x), Primitive::Int(y)) => match operator {
                 Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(x * y))),
-                Operator::Divide => Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y))),
+                Operator::Divide => {
+                    if y.is_zero() {
+                        return Ok(zero_division_error());
+                    }
+                    Ok(UntaggedValue::Primitive(Primitive::Filesize(x / y)))
+                }
                 _ => Err((left.type_name(), right.type_name())),
             },
             (Primitive::Int(x), Primitive::Filesize(y)) => match operator {
                 Operator::Multiply => Ok(UntaggedValue::Primitive(Primitive::Filesize(x * y))),
                 _ => Err((left.type_name(), right.type_name())),
             },
+            (Primitive::Filesize(x), Primitive::Decimal(y)) => match operator {
+                Operator::Multiply => Ok(filesize_from_decimal(
+                    bigdecimal::BigDecimal::from(x.clone()) * y,
+                )),
+                Operator::Divide => {
+                    if y.is_zero() {
+                        return Ok(zero_division_error());
+                    }
+                    Ok(filesize_from_decimal(
+                        bigdecimal::BigDecimal::from(x.clone()) / y,
+                    ))
+                }
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            (Primitive::Decimal(x), Primitive::Filesize(y)) => match operator {
+                Operator::Multiply => Ok(filesize_from_decimal(
+                    x * bigdecimal::BigDecimal::from(y.clone()),
+                )),
+                _ => Err((left.type_name(), right.type_name())),
+            },
             (Primitive::Int(x), Primitive::Int(y)) => match operator {
                 Operator::Plus => Ok(UntaggedValue::Primitive(Primitive::Int(x + y))),
                 Operator::Minus => Ok(UntaggedValue::Primitive(Primitive::Int(x - y))),
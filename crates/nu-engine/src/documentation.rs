@@ -138,6 +138,10 @@ pub fn get_documentation(
         long_desc.push_str("\n\n");
     }
 
+    if let Some(deprecation) = &signature.deprecated {
+        long_desc.push_str(&format!("This command is {}.\n\n", deprecation.describe()));
+    }
+
     let extra_usage = if config.brief { "" } else { &cmd.extra_usage() };
     if !extra_usage.is_empty() {
         long_desc.push_str(extra_usage);
@@ -146,34 +150,14 @@ pub fn get_documentation(
 
     let mut subcommands = vec![];
     if !config.no_subcommands {
-        for name in scope.get_command_names() {
-            if name.starts_with(&format!("{} ", cmd_name)) {
-                let subcommand = scope.get_command(&name).expect("This shouldn't happen");
-
-                subcommands.push(format!("  {} - {}", name, subcommand.usage()));
-            }
+        for subcommand in scope.find_prefix(&format!("{} ", cmd_name)) {
+            subcommands.push(format!("  {} - {}", subcommand.name(), subcommand.usage()));
         }
     }
 
-    let mut one_liner = String::new();
-    one_liner.push_str(&signature.name);
+    let mut one_liner = signature.usage_line();
     one_liner.push(' ');
 
-    for positional in &signature.positional {
-        match &positional.0 {
-            PositionalType::Mandatory(name, _m) => {
-                one_liner.push_str(&format!("<{}> ", name));
-            }
-            PositionalType::Optional(name, _o) => {
-                one_liner.push_str(&format!("({}) ", name));
-            }
-        }
-    }
-
-    if signature.rest_positional.is_some() {
-        one_liner.push_str("...args ");
-    }
-
     if !subcommands.is_empty() {
         one_liner.push_str("<subcommand> ");
     }
@@ -300,7 +284,14 @@ fn get_flags_section(signature: &Signature) -> String {
                 }
             }
         };
-        long_desc.push_str(&msg);
+        match signature.deprecated_flags.get(flag) {
+            Some(deprecation) => long_desc.push_str(&format!(
+                "{} ({})\n",
+                msg.trim_end(),
+                deprecation.describe()
+            )),
+            None => long_desc.push_str(&msg),
+        }
     }
     long_desc
 }
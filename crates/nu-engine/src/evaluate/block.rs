@@ -2,17 +2,20 @@ use crate::evaluate::expr::run_expression_block;
 use crate::evaluate::internal::run_internal_command;
 use crate::evaluation_context::EvaluationContext;
 use async_recursion::async_recursion;
-use futures::stream::TryStreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
 use nu_errors::ShellError;
 use nu_parser::ParserScope;
 use nu_protocol::hir::{
-    Block, Call, ClassifiedCommand, Expression, Pipeline, SpannedExpression, Synthetic,
+    Block, Call, ClassifiedCommand, Expression, Pipeline, PipelineCondition, SpannedExpression,
+    Synthetic,
 };
 use nu_protocol::{ReturnSuccess, UntaggedValue, Value};
 use nu_source::{Span, Tag};
 use nu_stream::InputStream;
 use nu_stream::ToOutputStream;
+use parking_lot::Mutex;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 #[async_recursion]
 pub async fn run_block(
@@ -85,57 +88,147 @@ pub async fn run_block(
             }
         }
         output = Ok(InputStream::empty());
+
+        // Tracks whether the most recently run pipeline in the group succeeded, which decides
+        // whether the pipelines chained to it with `&&` or `||` get to run.
+        let mut status: Result<(), ShellError> = Ok(());
+
         for pipeline in &group.pipelines {
-            match output {
-                Ok(inp) if inp.is_empty() => {}
-                Ok(inp) => {
-                    let mut output_stream = inp.to_output_stream();
+            if status.is_ok() {
+                status = match output {
+                    Ok(inp) if inp.is_empty() => Ok(()),
+                    Ok(inp) if pipeline.condition.is_some() => {
+                        // Whether `&&` or `||` runs what follows depends on every value of the
+                        // pipeline before it. With `||` those values are shown as they stream by,
+                        // as they're the output when what follows doesn't run.
+                        let show = pipeline.condition == Some(PipelineCondition::OrElse);
+                        let result = run_to_end(inp, show, ctx).await;
 
-                    match output_stream.try_next().await {
-                        Ok(Some(ReturnSuccess::Value(Value {
-                            value: UntaggedValue::Error(e),
-                            ..
-                        }))) => {
-                            return Err(e);
+                        if ctx.ctrl_c.load(Ordering::SeqCst) {
+                            return Ok(InputStream::empty());
                         }
-                        Ok(Some(_item)) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
-                            }
-                            if ctx.ctrl_c.load(Ordering::SeqCst) {
-                                // This early return doesn't return the result
-                                // we have so far, but breaking out of this loop
-                                // causes lifetime issues. A future contribution
-                                // could attempt to return the current output.
-                                // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
-                                return Ok(InputStream::empty());
+
+                        result
+                    }
+                    Ok(inp) => {
+                        let mut output_stream = inp.to_output_stream();
+
+                        match output_stream.try_next().await {
+                            Ok(Some(ReturnSuccess::Value(Value {
+                                value: UntaggedValue::Error(e),
+                                ..
+                            }))) => Err(e),
+                            Ok(Some(_item)) => {
+                                if let Some(err) = ctx.get_errors().get(0) {
+                                    ctx.clear_errors();
+                                    Err(err.clone())
+                                } else if ctx.ctrl_c.load(Ordering::SeqCst) {
+                                    // This early return doesn't return the result
+                                    // we have so far, but breaking out of this loop
+                                    // causes lifetime issues. A future contribution
+                                    // could attempt to return the current output.
+                                    // https://github.com/nushell/nushell/pull/2830#discussion_r550319687
+                                    return Ok(InputStream::empty());
+                                } else {
+                                    Ok(())
+                                }
                             }
-                        }
-                        Ok(None) => {
-                            if let Some(err) = ctx.get_errors().get(0) {
-                                ctx.clear_errors();
-                                return Err(err.clone());
+                            Ok(None) => {
+                                if let Some(err) = ctx.get_errors().get(0) {
+                                    ctx.clear_errors();
+                                    Err(err.clone())
+                                } else {
+                                    Ok(())
+                                }
                             }
-                        }
-                        Err(e) => {
-                            return Err(e);
+                            Err(e) => Err(e),
                         }
                     }
-                }
-                Err(e) => {
-                    return Err(e);
-                }
+                    Err(e) => Err(e),
+                };
             }
-            output = run_pipeline(pipeline, ctx, input).await;
+            output = Ok(InputStream::empty());
+
+            let should_run = match (pipeline.condition, &status) {
+                (None, Err(_)) => break,
+                (None, Ok(_)) | (Some(PipelineCondition::AndThen), Ok(_)) => true,
+                (Some(PipelineCondition::AndThen), Err(_))
+                | (Some(PipelineCondition::OrElse), Ok(_)) => false,
+                (Some(PipelineCondition::OrElse), Err(_)) => {
+                    status = Ok(());
+                    true
+                }
+            };
+
+            if should_run {
+                output = run_pipeline(pipeline, ctx, input).await;
 
-            input = InputStream::empty();
+                input = InputStream::empty();
+            }
         }
+
+        status?;
     }
 
     output
 }
 
+/// Pulls the values of a pipeline chained with `&&` or `||` to the end, stopping at the first
+/// error or when interrupted, and gives whether it succeeded. The values are shown through
+/// `autoview` as they come when `show` is set, and dropped otherwise.
+async fn run_to_end(
+    input: InputStream,
+    show: bool,
+    ctx: &EvaluationContext,
+) -> Result<(), ShellError> {
+    let failure = Arc::new(Mutex::new(None));
+    let seen = failure.clone();
+    let errors = ctx.current_errors.clone();
+    let ctrl_c = ctx.ctrl_c.clone();
+
+    let values = input.take_while(move |value| {
+        let keep = match &value.value {
+            UntaggedValue::Error(e) => {
+                *seen.lock() = Some(e.clone());
+                false
+            }
+            _ => errors.lock().is_empty() && !ctrl_c.load(Ordering::SeqCst),
+        };
+
+        futures::future::ready(keep)
+    });
+
+    match ctx.get_command("autoview") {
+        Some(autoview) if show => {
+            let mut output_stream = ctx
+                .run_command(
+                    autoview,
+                    Tag::unknown(),
+                    Call::new(
+                        Box::new(SpannedExpression::new(
+                            Expression::Synthetic(Synthetic::String("autoview".into())),
+                            Span::unknown(),
+                        )),
+                        Span::unknown(),
+                    ),
+                    InputStream::from_stream(values),
+                )
+                .await?;
+
+            while output_stream.try_next().await?.is_some() {}
+        }
+        _ => values.for_each(|_| futures::future::ready(())).await,
+    }
+
+    let failure = failure.lock().take();
+    if let Some(err) = failure.or_else(|| ctx.get_errors().get(0).cloned()) {
+        ctx.clear_errors();
+        return Err(err);
+    }
+
+    Ok(())
+}
+
 #[async_recursion]
 async fn run_pipeline(
     commands: &Pipeline,
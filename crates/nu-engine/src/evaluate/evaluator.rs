@@ -11,11 +11,11 @@ use nu_protocol::{
     Dictionary,
 };
 use nu_protocol::{
-    ColumnPath, Primitive, RangeInclusion, UnspannedPathMember, UntaggedValue, Value,
+    ColumnPath, Primitive, RangeInclusion, ShellTypeName, UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::{Span, SpannedItem, Tag};
 use nu_stream::InputStream;
-use nu_value_ext::ValueExt;
+use nu_value_ext::{get_data_by_dotted_members, ValueExt};
 
 #[async_recursion]
 pub async fn evaluate_baseline_expr(
@@ -64,6 +64,19 @@ pub async fn evaluate_baseline_expr(
                 _ => unreachable!(),
             }
         }
+        Expression::Not(inner) => {
+            let value = evaluate_baseline_expr(&inner, ctx).await?;
+
+            match value.value {
+                UntaggedValue::Primitive(Primitive::Boolean(b)) => {
+                    Ok(UntaggedValue::boolean(!b).into_value(tag))
+                }
+                other => Err(ShellError::type_error(
+                    "boolean",
+                    other.type_name().spanned(inner.span),
+                )),
+            }
+        }
         Expression::Range(range) => {
             let left = if let Some(left) = &range.left {
                 evaluate_baseline_expr(&left, ctx).await?
@@ -168,19 +181,34 @@ pub async fn evaluate_baseline_expr(
             let value = evaluate_baseline_expr(&path.head, ctx).await?;
             let mut item = value;
 
-            for member in &path.tail {
+            let mut index = 0;
+
+            while let Some(member) = path.tail.get(index) {
                 let next = item.get_data_by_member(member);
 
                 match next {
                     Err(err) => match &member.unspanned {
                         UnspannedPathMember::String(_name) => {
+                            // A flattened column is named by several members joined with dots
+                            if let Some((next, used)) =
+                                get_data_by_dotted_members(&item, &path.tail[index..])
+                            {
+                                item = next.value.into_value(&tag);
+                                index += used;
+                                continue;
+                            }
+
                             let possible_matches = did_you_mean(&item, member.as_string());
 
                             match possible_matches {
                                 Some(p) => {
                                     return Err(ShellError::labeled_error(
                                         "Unknown column",
-                                        format!("did you mean '{}'?", p[0]),
+                                        format!(
+                                            "did you mean '{}'? (available columns: {})",
+                                            p[0],
+                                            item.data_descriptors().join(", ")
+                                        ),
                                         &member.span,
                                     ));
                                 }
@@ -197,6 +225,7 @@ pub async fn evaluate_baseline_expr(
                     },
                     Ok(next) => {
                         item = next.clone().value.into_value(&tag);
+                        index += 1;
                     }
                 };
             }
@@ -226,6 +255,7 @@ fn evaluate_literal(literal: &hir::Literal, span: Span) -> Value {
         hir::Literal::String(string) => UntaggedValue::string(string).into_value(span),
         hir::Literal::GlobPattern(pattern) => UntaggedValue::glob_pattern(pattern).into_value(span),
         hir::Literal::Bare(bare) => UntaggedValue::string(bare.clone()).into_value(span),
+        hir::Literal::Date(date) => UntaggedValue::date(*date).into_value(span),
         hir::Literal::Operator(_) => unimplemented!("Not sure what to do with operator yet"),
     }
 }
@@ -234,6 +264,8 @@ fn evaluate_reference(name: &str, ctx: &EvaluationContext, tag: Tag) -> Result<V
     match name {
         "$nu" => crate::evaluate::variables::nu(&ctx.scope.get_env_vars(), tag),
 
+        "$env" => crate::evaluate::variables::env(&ctx.scope.get_env_vars(), tag),
+
         "$true" => Ok(Value {
             value: UntaggedValue::boolean(true),
             tag,
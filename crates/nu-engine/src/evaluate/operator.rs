@@ -1,9 +1,21 @@
+use indexmap::IndexMap;
 use nu_data::{value, value::compare_values};
 use nu_errors::ShellError;
 use nu_protocol::hir::Operator;
 use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use nu_source::SpannedItem;
+use regex::Regex;
+use std::cell::RefCell;
 use std::ops::Not;
 
+// How many compiled patterns are kept around before the least recently used one is dropped
+const MAX_CACHED_REGEXES: usize = 32;
+
+thread_local! {
+    // The patterns of `=~ r'regex'` are compiled the first time they're used, rather than for every row
+    static REGEXES: RefCell<IndexMap<String, Regex>> = RefCell::new(IndexMap::new());
+}
+
 pub fn apply_operator(
     op: Operator,
     left: &Value,
@@ -22,6 +34,13 @@ pub fn apply_operator(
         Operator::NotContains => string_contains(left, right)
             .map(Not::not)
             .map(UntaggedValue::boolean),
+        Operator::Matches => string_matches(left, right),
+        Operator::NotMatches => string_matches(left, right).map(|res| match res {
+            UntaggedValue::Primitive(Primitive::Boolean(matches)) => {
+                UntaggedValue::boolean(!matches)
+            }
+            _ => res,
+        }),
         Operator::Plus => value::compute_values(op, left, right),
         Operator::Minus => value::compute_values(op, left, right),
         Operator::Multiply => value::compute_values(op, left, right),
@@ -42,6 +61,15 @@ pub fn apply_operator(
             )),
             _ => res,
         }),
+        Operator::In | Operator::NotIn if !right.is_table() => Ok(UntaggedValue::Error(
+            ShellError::type_error("list", right.type_name().spanned(right.tag.span)),
+        )),
+        Operator::In | Operator::NotIn if left.is_table() || left.is_row() => {
+            Ok(UntaggedValue::Error(ShellError::type_error(
+                "a single value",
+                left.type_name().spanned(left.tag.span),
+            )))
+        }
         Operator::In => table_contains(left, right).map(UntaggedValue::boolean),
         Operator::NotIn => table_contains(left, right).map(|x| UntaggedValue::boolean(!x)),
         Operator::And => match (left.as_bool(), right.as_bool()) {
@@ -76,6 +104,50 @@ fn string_contains(
     }
 }
 
+fn string_matches(
+    left: &Value,
+    right: &Value,
+) -> Result<UntaggedValue, (&'static str, &'static str)> {
+    let text = match &left.value {
+        UntaggedValue::Primitive(Primitive::String(l)) => l.clone(),
+        UntaggedValue::Primitive(Primitive::FilePath(l)) => l.as_path().display().to_string(),
+        _ => return Err((left.type_name(), right.type_name())),
+    };
+
+    let pattern = match &right.value {
+        UntaggedValue::Primitive(Primitive::String(r)) => r,
+        _ => return Err((left.type_name(), right.type_name())),
+    };
+
+    REGEXES.with(|regexes| {
+        let mut regexes = regexes.borrow_mut();
+
+        // Entries are kept in order of use, so the least recently used pattern is always first
+        let regex = match regexes.shift_remove(pattern) {
+            Some(regex) => regex,
+            None => match Regex::new(pattern) {
+                Ok(regex) => regex,
+                Err(_) => {
+                    return Ok(UntaggedValue::Error(ShellError::labeled_error(
+                        "Invalid regular expression",
+                        "invalid regular expression",
+                        &right.tag.span,
+                    )))
+                }
+            },
+        };
+
+        let matches = regex.is_match(&text);
+
+        if regexes.len() >= MAX_CACHED_REGEXES {
+            regexes.shift_remove_index(0);
+        }
+        regexes.insert(pattern.clone(), regex);
+
+        Ok(UntaggedValue::boolean(matches))
+    })
+}
+
 fn table_contains(
     left: &UntaggedValue,
     right: &UntaggedValue,
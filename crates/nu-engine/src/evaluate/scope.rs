@@ -4,11 +4,13 @@ use nu_errors::ShellError;
 use nu_parser::ParserScope;
 use nu_protocol::{hir::Block, Value};
 use nu_source::Spanned;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct Scope {
     frames: Arc<parking_lot::Mutex<Vec<ScopeFrame>>>,
+    case_insensitive_commands: Arc<AtomicBool>,
 }
 
 impl Default for Scope {
@@ -21,8 +23,27 @@ impl Scope {
     pub fn new() -> Scope {
         Scope {
             frames: Arc::new(parking_lot::Mutex::new(vec![ScopeFrame::new()])),
+            case_insensitive_commands: Arc::new(AtomicBool::new(false)),
         }
     }
+
+    /// A copy of the scope as it is now that no longer shares its frames with this one, so frames
+    /// entered and exited through either don't affect the other
+    pub fn detached(&self) -> Scope {
+        Scope {
+            frames: Arc::new(parking_lot::Mutex::new(self.frames.lock().clone())),
+            case_insensitive_commands: self.case_insensitive_commands.clone(),
+        }
+    }
+
+    /// Lets a command be found by a name that only differs in case, as the
+    /// `case_insensitive_commands` config setting asks. The parser only sees the scope, so the
+    /// setting is handed over here rather than read from the config while parsing.
+    pub fn set_case_insensitive_commands(&self, enabled: bool) {
+        self.case_insensitive_commands
+            .store(enabled, Ordering::SeqCst);
+    }
+
     pub fn get_command(&self, name: &str) -> Option<Command> {
         for frame in self.frames.lock().iter().rev() {
             if let Some(command) = frame.get_command(name) {
@@ -71,6 +92,37 @@ impl Scope {
         }
     }
 
+    /// Adds a command to the innermost scope while the shell is running, so it can be parsed,
+    /// run and listed by help straight away. A command already in scope with the same name is
+    /// only replaced if `overwrite` is set.
+    pub fn register(&self, command: Command, overwrite: bool) -> Result<(), ShellError> {
+        let name = command.name().to_string();
+
+        if !overwrite && self.has_command(&name) {
+            return Err(ShellError::untagged_runtime_error(format!(
+                "A command named '{}' already exists",
+                name
+            )));
+        }
+
+        self.add_command(name, command);
+        Ok(())
+    }
+
+    /// Removes a command from every scope, giving back the innermost one if it was defined
+    pub fn deregister(&self, name: &str) -> Option<Command> {
+        let mut removed = None;
+
+        for frame in self.frames.lock().iter_mut() {
+            frame.custom_commands.shift_remove(name);
+            if let Some(command) = frame.commands.shift_remove(name) {
+                removed = Some(command);
+            }
+        }
+
+        removed
+    }
+
     pub fn get_command_names(&self) -> Vec<String> {
         let mut names = vec![];
 
@@ -79,12 +131,38 @@ impl Scope {
             names.append(&mut frame_command_names);
         }
 
-        names.dedup();
         names.sort();
+        names.dedup();
 
         names
     }
 
+    /// Gets the commands whose names start with `prefix`, sorted by name
+    pub fn find_prefix(&self, prefix: &str) -> Vec<Command> {
+        self.get_command_names()
+            .into_iter()
+            .filter(|name| name.starts_with(prefix))
+            .filter_map(|name| self.get_command(&name))
+            .collect()
+    }
+
+    /// Gets every alias in scope along with what it expands to. When inner and outer scopes
+    /// define the same alias, the innermost one is the one that applies.
+    pub fn get_aliases(&self) -> IndexMap<String, Vec<Spanned<String>>> {
+        let mut output = IndexMap::new();
+
+        for frame in self.frames.lock().iter().rev() {
+            for (name, expansion) in frame.aliases.iter() {
+                if !output.contains_key(name) {
+                    output.insert(name.clone(), expansion.clone());
+                }
+            }
+        }
+
+        output.sort_keys();
+        output
+    }
+
     pub fn len(&self) -> usize {
         self.frames.lock().len()
     }
@@ -208,6 +286,18 @@ impl ParserScope for Scope {
         self.get_command(name).is_some()
     }
 
+    fn get_command_names_ignoring_case(&self, name: &str) -> Vec<String> {
+        if !self.case_insensitive_commands.load(Ordering::SeqCst) {
+            return vec![];
+        }
+
+        let name = name.to_lowercase();
+        self.get_command_names()
+            .into_iter()
+            .filter(|command_name| command_name.to_lowercase() == name)
+            .collect()
+    }
+
     fn add_definition(&self, block: Block) {
         if let Some(frame) = self.frames.lock().last_mut() {
             let name = block.params.name.clone();
@@ -296,3 +386,101 @@ impl ScopeFrame {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use crate::{basic_evaluation_context, run_block, whole_stream_command};
+    use crate::{CommandArgs, WholeStreamCommand};
+    use futures::executor::block_on;
+    use nu_errors::ShellError;
+    use nu_parser::ParserScope;
+    use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
+    use nu_stream::{InputStream, OutputStream};
+
+    struct Greet;
+
+    #[async_trait::async_trait]
+    impl WholeStreamCommand for Greet {
+        fn name(&self) -> &str {
+            "greet"
+        }
+
+        fn signature(&self) -> Signature {
+            Signature::build("greet")
+        }
+
+        fn usage(&self) -> &str {
+            "Says hello."
+        }
+
+        async fn run(&self, _args: CommandArgs) -> Result<OutputStream, ShellError> {
+            Ok(OutputStream::one(ReturnSuccess::value(
+                UntaggedValue::string("hello").into_untagged_value(),
+            )))
+        }
+    }
+
+    fn run(src: &str, scope: &Scope) -> Result<Vec<String>, ShellError> {
+        let mut context = basic_evaluation_context().expect("could not create a context");
+        context.scope = scope.clone();
+
+        let (block, err) = nu_parser::parse(src, 0, scope);
+        if let Some(err) = err {
+            return Err(err.into());
+        }
+
+        let mut output = block_on(run_block(&block, &context, InputStream::empty()))?;
+        block_on(output.drain_vec())
+            .iter()
+            .map(|value| value.as_string())
+            .collect()
+    }
+
+    #[test]
+    fn commands_registered_at_runtime_can_be_run() -> Result<(), ShellError> {
+        let scope = Scope::new();
+        assert!(!scope.has_signature("greet"));
+
+        scope.register(whole_stream_command(Greet), false)?;
+
+        assert!(scope.has_signature("greet"));
+        assert_eq!(scope.find_prefix("gre").len(), 1);
+        assert_eq!(run("greet", &scope)?, vec!["hello".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn registering_a_name_in_use_needs_overwrite() {
+        let scope = Scope::new();
+
+        assert!(scope.register(whole_stream_command(Greet), false).is_ok());
+        assert!(scope.register(whole_stream_command(Greet), false).is_err());
+        assert!(scope.register(whole_stream_command(Greet), true).is_ok());
+    }
+
+    #[test]
+    fn deregistered_commands_are_gone() {
+        let scope = Scope::new();
+        scope.add_command("greet".into(), whole_stream_command(Greet));
+        scope.enter_scope();
+        scope.add_command("greet".into(), whole_stream_command(Greet));
+
+        assert!(scope.deregister("greet").is_some());
+        assert!(!scope.has_signature("greet"));
+        assert!(scope.deregister("greet").is_none());
+    }
+
+    #[test]
+    fn detached_scopes_keep_their_own_frames() {
+        let scope = Scope::new();
+        scope.add_command("greet".into(), whole_stream_command(Greet));
+
+        let detached = scope.detached();
+        detached.deregister("greet");
+
+        assert!(detached.get_command("greet").is_none());
+        assert!(scope.get_command("greet").is_some());
+    }
+}
@@ -64,3 +64,14 @@ pub fn nu(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value,
 
     Ok(nu_dict.into_value())
 }
+
+pub fn env(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let mut dict = TaggedDictBuilder::new(&tag);
+    for v in env.iter() {
+        dict.insert_untagged(v.0, UntaggedValue::string(v.1));
+    }
+
+    Ok(dict.into_value())
+}
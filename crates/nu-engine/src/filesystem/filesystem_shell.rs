@@ -15,7 +15,7 @@ use nu_source::{Span, Tag};
 use nu_stream::{Interruptible, OutputStream, ToOutputStream};
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::io::{Error, ErrorKind};
+use std::io::{Error, ErrorKind, Write};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -96,12 +96,15 @@ impl Shell for FilesystemShell {
             path,
             all,
             long,
+            full,
             short_names,
             du,
+            recursive,
         }: LsArgs,
         name_tag: Tag,
         ctrl_c: Arc<AtomicBool>,
     ) -> Result<OutputStream, ShellError> {
+        let long = long || full;
         let ctrl_c_copy = ctrl_c.clone();
         let (path, p_tag) = match path {
             Some(p) => {
@@ -140,10 +143,27 @@ impl Shell for FilesystemShell {
 
         let mut hidden_dirs = vec![];
 
+        let paths = paths.flat_map(move |path| {
+            let path = path.map_err(|e| ShellError::from(e.into_error()));
+
+            let walk = match &path {
+                Ok(path)
+                    if recursive
+                        && is_walkable(path)
+                        && (all || hidden_dir_specified || !is_hidden_dir(path)) =>
+                {
+                    Some(DirWalk::new(path, all))
+                }
+                _ => None,
+            };
+
+            std::iter::once(path).chain(walk.into_iter().flatten().map(Ok))
+        });
+
         // Generated stream: impl Stream<Item = Result<ReturnSuccess, ShellError>
 
         Ok(futures::stream::iter(paths.filter_map(move |path| {
-            let path = match path.map_err(|e| ShellError::from(e.into_error())) {
+            let path = match path {
                 Ok(path) => path,
                 Err(err) => return Some(Err(err)),
             };
@@ -264,6 +284,7 @@ impl Shell for FilesystemShell {
             src,
             dst,
             recursive,
+            force,
         }: CopyArgs,
         name: Tag,
         path: &str,
@@ -301,6 +322,14 @@ impl Shell for FilesystemShell {
             ));
         }
 
+        if !destination.exists() && !destination.parent().map(Path::exists).unwrap_or(true) {
+            return Err(ShellError::labeled_error(
+                "Destination directory does not exist",
+                "destination does not exist",
+                dst.tag,
+            ));
+        }
+
         let any_source_is_dir = sources.iter().any(|f| matches!(f, Ok(f) if f.is_dir()));
 
         if any_source_is_dir && !recursive.item {
@@ -311,6 +340,11 @@ impl Shell for FilesystemShell {
             ));
         }
 
+        // Every destination is worked out and checked before anything is copied, so a file in
+        // the way of one source doesn't leave the others copied already
+        let mut directories = vec![];
+        let mut files = vec![];
+
         for entry in sources {
             if let Ok(entry) = entry {
                 let mut sources = FileStructure::new();
@@ -329,13 +363,7 @@ impl Shell for FilesystemShell {
                         }
                     })?;
 
-                    for (src, dst) in sources {
-                        if src.is_file() {
-                            std::fs::copy(src, dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), &name_tag)
-                            })?;
-                        }
-                    }
+                    files.extend(sources.into_iter().filter(|(src, _)| src.is_file()));
                 } else if entry.is_dir() {
                     let destination = if !destination.exists() {
                         destination.clone()
@@ -352,9 +380,7 @@ impl Shell for FilesystemShell {
                         }
                     };
 
-                    std::fs::create_dir_all(&destination).map_err(|e| {
-                        ShellError::labeled_error(e.to_string(), e.to_string(), &dst.tag)
-                    })?;
+                    directories.push(destination.clone());
 
                     let sources = sources.paths_applying_with(|(source_file, depth_level)| {
                         let mut dest = destination.clone();
@@ -374,24 +400,34 @@ impl Shell for FilesystemShell {
                         Ok((PathBuf::from(&source_file), dest))
                     })?;
 
-                    let dst_tag = &dst.tag;
                     for (src, dst) in sources {
-                        if src.is_dir() && !dst.exists() {
-                            std::fs::create_dir_all(&dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), dst_tag)
-                            })?;
-                        }
-
-                        if src.is_file() {
-                            std::fs::copy(&src, &dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), &name_tag)
-                            })?;
+                        if src.is_dir() {
+                            directories.push(dst);
+                        } else if src.is_file() {
+                            files.push((src, dst));
                         }
                     }
                 }
             }
         }
 
+        for (_, to) in &files {
+            check_overwrite(to, force.item, &dst.tag)?;
+        }
+
+        for directory in directories {
+            if !directory.exists() {
+                std::fs::create_dir_all(&directory).map_err(|e| {
+                    ShellError::labeled_error(e.to_string(), e.to_string(), &dst.tag)
+                })?;
+            }
+        }
+
+        for (from, to) in files {
+            std::fs::copy(from, to)
+                .map_err(|e| ShellError::labeled_error(e.to_string(), e.to_string(), &name_tag))?;
+        }
+
         Ok(OutputStream::empty())
     }
 
@@ -418,10 +454,32 @@ impl Shell for FilesystemShell {
         for dir in directories.iter() {
             let create_at = path.join(&dir.item);
 
-            let dir_res = std::fs::create_dir_all(&create_at);
-            if let Err(reason) = dir_res {
+            if let Some(file) = create_at.ancestors().find(|p| p.exists() && !p.is_dir()) {
                 return Err(ShellError::labeled_error(
-                    reason.to_string(),
+                    format!(
+                        "Can't create {} because {} is a file",
+                        dir.item.display(),
+                        file.strip_prefix(path).unwrap_or(file).display()
+                    ),
+                    "a file is in the way",
+                    dir.tag(),
+                ));
+            }
+
+            if let Err(reason) = std::fs::create_dir_all(&create_at) {
+                // The highest directory still missing is the one that couldn't be created
+                let failed = create_at
+                    .ancestors()
+                    .filter(|p| !p.exists())
+                    .last()
+                    .unwrap_or(&create_at);
+
+                return Err(ShellError::labeled_error(
+                    format!(
+                        "Could not create {}: {}",
+                        failed.strip_prefix(path).unwrap_or(failed).display(),
+                        reason
+                    ),
                     reason.to_string(),
                     dir.tag(),
                 ));
@@ -437,7 +495,7 @@ impl Shell for FilesystemShell {
 
     fn mv(
         &self,
-        MvArgs { src, dst }: MvArgs,
+        MvArgs { src, dst, force }: MvArgs,
         _name: Tag,
         path: &str,
     ) -> Result<OutputStream, ShellError> {
@@ -499,15 +557,27 @@ impl Shell for FilesystemShell {
                 .collect();
         }
 
+        // Every destination is worked out and checked before anything is moved, so a file in
+        // the way of one source doesn't leave the others moved already
+        let mut moves = vec![];
         for entry in sources {
             if let Ok(entry) = entry {
-                move_file(
+                let to = move_destination(
                     TaggedPathBuf(&entry, &src.tag),
                     TaggedPathBuf(&destination, &dst.tag),
-                )?
+                )?;
+                moves.push((entry, to));
             }
         }
 
+        for (_, to) in &moves {
+            check_overwrite(to, force.item, &dst.tag)?;
+        }
+
+        for (from, to) in moves {
+            move_item(&from, &src.tag, &to)?;
+        }
+
         Ok(OutputStream::empty())
     }
 
@@ -518,7 +588,7 @@ impl Shell for FilesystemShell {
             recursive,
             trash: _trash,
             permanent: _permanent,
-            force: _force,
+            force,
         }: RemoveArgs,
         name: Tag,
         path: &str,
@@ -552,9 +622,11 @@ impl Shell for FilesystemShell {
             let path = path.join(&target.item);
             match glob::glob(&path.to_string_lossy()) {
                 Ok(files) => {
+                    let mut matched = false;
                     for file in files {
                         match file {
                             Ok(ref f) => {
+                                matched = true;
                                 all_targets
                                     .entry(f.clone())
                                     .or_insert_with(|| target.tag.clone());
@@ -568,6 +640,15 @@ impl Shell for FilesystemShell {
                             }
                         }
                     }
+
+                    // Refuse to remove anything if one of the patterns is likely a typo
+                    if !matched && !force.item {
+                        return Err(ShellError::labeled_error(
+                            format!("No matches found for {}", target.item.display()),
+                            "no matches found",
+                            &target.tag,
+                        ));
+                    }
                 }
                 Err(e) => {
                     return Err(ShellError::labeled_error(
@@ -579,7 +660,7 @@ impl Shell for FilesystemShell {
             };
         }
 
-        if all_targets.is_empty() && !_force.item {
+        if all_targets.is_empty() && !force.item {
             return Err(ShellError::labeled_error(
                 "No valid paths",
                 "no valid paths",
@@ -587,6 +668,43 @@ impl Shell for FilesystemShell {
             ));
         }
 
+        // Refuse to run at all if a target is the root, where we're standing or above it.
+        // Symlinks are left out, as removing one doesn't touch what it points to.
+        let current_dir = dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        for (f, tag) in all_targets.iter() {
+            let is_symlink = f
+                .symlink_metadata()
+                .map_or(false, |metadata| metadata.file_type().is_symlink());
+            if is_symlink {
+                continue;
+            }
+
+            let f = match dunce::canonicalize(f) {
+                Ok(f) => f,
+                Err(_) => continue,
+            };
+
+            if f.parent().is_none() {
+                return Err(ShellError::labeled_error(
+                    format!("Refusing to remove {}", f.to_string_lossy()),
+                    "this is the root of the filesystem",
+                    tag,
+                ));
+            }
+
+            if current_dir.starts_with(&f) {
+                return Err(ShellError::labeled_error(
+                    format!("Refusing to remove {}", f.to_string_lossy()),
+                    if f == current_dir {
+                        "this is the current directory"
+                    } else {
+                        "this contains the current directory"
+                    },
+                    tag,
+                ));
+            }
+        }
+
         Ok(
             futures::stream::iter(all_targets.into_iter().map(move |(f, tag)| {
                 let is_empty = || match f.read_dir() {
@@ -767,8 +885,16 @@ impl Shell for FilesystemShell {
         full_path: &Path,
         save_data: &[u8],
         name: Span,
+        append: bool,
     ) -> Result<OutputStream, ShellError> {
-        match std::fs::write(full_path, save_data) {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .create(true)
+            .open(full_path);
+
+        match file.and_then(|mut file| file.write_all(save_data)) {
             Ok(_) => Ok(OutputStream::empty()),
             Err(e) => Err(ShellError::labeled_error(
                 e.to_string(),
@@ -781,7 +907,8 @@ impl Shell for FilesystemShell {
 
 struct TaggedPathBuf<'a>(&'a PathBuf, &'a Tag);
 
-fn move_file(from: TaggedPathBuf, to: TaggedPathBuf) -> Result<(), ShellError> {
+/// Where moving a file or directory to the given destination puts it
+fn move_destination(from: TaggedPathBuf, to: TaggedPathBuf) -> Result<PathBuf, ShellError> {
     let TaggedPathBuf(from, from_tag) = from;
     let TaggedPathBuf(to, to_tag) = to;
 
@@ -823,7 +950,20 @@ fn move_file(from: TaggedPathBuf, to: TaggedPathBuf) -> Result<(), ShellError> {
         to.push(from_file_name);
     }
 
-    move_item(&from, from_tag, &to)
+    Ok(to)
+}
+
+/// Errors if there's already something at the destination, unless overwriting it was asked for
+fn check_overwrite(destination: &Path, force: bool, tag: &Tag) -> Result<(), ShellError> {
+    if destination.exists() && !force {
+        return Err(ShellError::labeled_error(
+            format!("{} already exists", destination.display()),
+            "destination already exists (use --force to overwrite it)",
+            tag,
+        ));
+    }
+
+    Ok(())
 }
 
 fn move_item(from: &Path, from_tag: &Tag, to: &Path) -> Result<(), ShellError> {
@@ -857,6 +997,71 @@ fn is_empty_dir(dir: impl AsRef<Path>) -> bool {
     }
 }
 
+/// Whether `ls --recursive` should go into a path. Symlinks aren't followed.
+fn is_walkable(path: &Path) -> bool {
+    std::fs::symlink_metadata(path)
+        .map(|metadata| metadata.is_dir())
+        .unwrap_or(false)
+}
+
+/// Walks the entries under a directory for `ls --recursive`, depth first and in name order.
+/// Each directory is only read once the walk gets to it, so entries stream out as they're
+/// found. Not following symlinks keeps links back up the tree from sending it round in
+/// circles. A directory that can't be read is still listed, but its entries are skipped
+/// rather than failing the whole listing.
+struct DirWalk {
+    pending: Vec<std::vec::IntoIter<PathBuf>>,
+    all: bool,
+}
+
+impl DirWalk {
+    fn new(dir: &Path, all: bool) -> DirWalk {
+        let mut walk = DirWalk {
+            pending: vec![],
+            all,
+        };
+
+        walk.enter(dir);
+        walk
+    }
+
+    fn enter(&mut self, dir: &Path) {
+        let all = self.all;
+
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            let mut paths: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| all || !is_hidden_dir(path))
+                .collect();
+            paths.sort();
+
+            self.pending.push(paths.into_iter());
+        }
+    }
+}
+
+impl Iterator for DirWalk {
+    type Item = PathBuf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.pending.last_mut()?.next() {
+                Some(path) => {
+                    if is_walkable(&path) {
+                        self.enter(&path);
+                    }
+
+                    return Some(path);
+                }
+                None => {
+                    self.pending.pop();
+                }
+            }
+        }
+    }
+}
+
 fn is_hidden_dir(dir: impl AsRef<Path>) -> bool {
     #[cfg(windows)]
     {
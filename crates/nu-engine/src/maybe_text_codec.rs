@@ -68,32 +68,25 @@ impl futures_codec::Decoder for MaybeTextCodec {
         }
 
         let mut s = String::with_capacity(OUTPUT_BUFFER_SIZE);
+        let mut read = 0;
 
-        let (res, _read, replacements) = self.decoder.decode_to_string(src, &mut s, false);
-
-        let result = if replacements {
-            // If we had to make replacements when converting to utf8, fall back to binary
-            StringOrBinary::Binary(src.to_vec())
-        } else {
-            // If original buffer size is too small, we continue to allocate new Strings and append
-            // them to the result until the input buffer is smaller than the allocated String
-            if let CoderResult::OutputFull = res {
-                let mut buffer = String::with_capacity(OUTPUT_BUFFER_SIZE);
-                loop {
-                    let (res, _read, _replacements) =
-                        self.decoder
-                            .decode_to_string(&src[s.len()..], &mut buffer, false);
-                    s.push_str(&buffer);
-
-                    if let CoderResult::InputEmpty = res {
-                        break;
-                    }
-
-                    buffer.clear();
-                }
+        // If the String fills up before all of the input is decoded, we grow it and carry on
+        // from where the decoder stopped
+        let result = loop {
+            let (res, more_read, replacements) =
+                self.decoder.decode_to_string(&src[read..], &mut s, false);
+
+            if replacements {
+                // If we had to make replacements when converting to utf8, fall back to binary
+                break StringOrBinary::Binary(src.to_vec());
             }
 
-            StringOrBinary::String(s)
+            read += more_read;
+
+            match res {
+                CoderResult::InputEmpty => break StringOrBinary::String(s),
+                CoderResult::OutputFull => s.reserve(OUTPUT_BUFFER_SIZE),
+            }
         };
 
         src.clear();
@@ -123,4 +116,17 @@ mod tests {
         );
         assert!(bytes.is_empty());
     }
+
+    #[test]
+    fn should_fall_back_to_binary_when_invalid_bytes_come_after_the_temporary_buffer() {
+        let mut maybe_text = MaybeTextCodec::new(None);
+        let mut input = b"0123456789".to_vec();
+        input.push(0xff);
+        let mut bytes = BytesMut::from(&input[..]);
+
+        let binary = maybe_text.decode(&mut bytes);
+
+        assert_eq!(Ok(Some(StringOrBinary::Binary(input))), binary);
+        assert!(bytes.is_empty());
+    }
 }
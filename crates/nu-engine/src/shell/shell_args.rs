@@ -12,6 +12,7 @@ pub struct CopyArgs {
     pub src: Tagged<PathBuf>,
     pub dst: Tagged<PathBuf>,
     pub recursive: Tagged<bool>,
+    pub force: Tagged<bool>,
 }
 
 #[derive(Deserialize)]
@@ -19,16 +20,19 @@ pub struct LsArgs {
     pub path: Option<Tagged<PathBuf>>,
     pub all: bool,
     pub long: bool,
+    pub full: bool,
     #[serde(rename = "short-names")]
     pub short_names: bool,
     #[serde(rename = "du")]
     pub du: bool,
+    pub recursive: bool,
 }
 
 #[derive(Deserialize)]
 pub struct MvArgs {
     pub src: Tagged<PathBuf>,
     pub dst: Tagged<PathBuf>,
+    pub force: Tagged<bool>,
 }
 
 #[derive(Deserialize)]
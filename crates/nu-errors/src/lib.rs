@@ -19,6 +19,12 @@ use std::ops::Range;
 pub enum ParseErrorReason {
     /// The parser encountered an EOF rather than what it was expecting
     Eof { expected: String, span: Span },
+    /// The parser encountered an EOF while a quote or a paired delimiter was still open
+    Unclosed {
+        opened: Spanned<String>,
+        expected: String,
+        end: Span,
+    },
     /// The parser expected to see the end of a token stream (possibly the token
     /// stream from inside a delimited token node), but found something else.
     ExtraTokens { actual: Spanned<String> },
@@ -57,6 +63,21 @@ impl ParseError {
         }
     }
 
+    /// Construct a [ParseErrorReason::Unclosed](ParseErrorReason::Unclosed)
+    pub fn unclosed(
+        opened: Spanned<impl Into<String>>,
+        expected: impl Into<String>,
+        end: Span,
+    ) -> ParseError {
+        ParseError {
+            reason: ParseErrorReason::Unclosed {
+                opened: opened.item.into().spanned(opened.span),
+                expected: expected.into(),
+                end,
+            },
+        }
+    }
+
     /// Construct a [ParseErrorReason::ExtraTokens](ParseErrorReason::ExtraTokens)
     pub fn extra_tokens(actual: Spanned<impl Into<String>>) -> ParseError {
         let Spanned { span, item } = actual;
@@ -105,6 +126,20 @@ impl From<ParseError> for ShellError {
     fn from(error: ParseError) -> ShellError {
         match error.reason {
             ParseErrorReason::Eof { expected, span } => ShellError::unexpected_eof(expected, span),
+            ParseErrorReason::Unclosed {
+                opened,
+                expected,
+                end,
+            } => ShellError::diagnostic(
+                Diagnostic::error()
+                    .with_message("Unexpected end of input")
+                    .with_labels(vec![
+                        Label::primary(0, opened.span)
+                            .with_message(format!("'{}' is never closed", opened.item)),
+                        Label::secondary(0, end)
+                            .with_message(format!("expected '{}' before the end", expected)),
+                    ]),
+            ),
             ParseErrorReason::ExtraTokens { actual } => ShellError::type_error("nothing", actual),
             ParseErrorReason::Mismatch { actual, expected } => {
                 ShellError::type_error(expected, actual)
@@ -131,12 +166,18 @@ pub enum ArgumentError {
     MissingMandatoryFlag(String),
     /// The command specified a mandatory positional argument, but it was missing.
     MissingMandatoryPositional(String),
+    /// A mandatory positional argument was missing, along with the usage line of the command
+    MissingMandatoryPositionalWithUsage(String, String),
     /// A flag was found, and it should have been followed by a value, but no value was found
     MissingValueForName(String),
     /// An argument was found, but the command does not recognize it
     UnexpectedArgument(Spanned<String>),
-    /// An flag was found, but the command does not recognize it
+    /// More arguments were found than the command accepts, along with its usage line
+    UnexpectedArgumentWithUsage(Spanned<String>, String),
+    /// A flag was found, but the command does not recognize it
     UnexpectedFlag(Spanned<String>),
+    /// A flag was found that the command does not recognize, but it is close to one it does
+    UnexpectedFlagWithSuggestion(Spanned<String>, String),
     /// A sequence of characters was found that was not syntactically valid (but would have
     /// been valid if the command was an external command)
     InvalidExternalWord,
@@ -155,16 +196,37 @@ impl PrettyDebug for ArgumentError {
                     + DbgDocBldr::description(&name.item)
                     + DbgDocBldr::description("` is not supported")
             }
+            ArgumentError::UnexpectedArgumentWithUsage(name, usage) => {
+                DbgDocBldr::description("unexpected `")
+                    + DbgDocBldr::description(&name.item)
+                    + DbgDocBldr::description("` is not supported, usage: `")
+                    + DbgDocBldr::description(usage)
+                    + DbgDocBldr::description("`")
+            }
             ArgumentError::UnexpectedFlag(name) => {
                 DbgDocBldr::description("unexpected `")
                     + DbgDocBldr::description(&name.item)
                     + DbgDocBldr::description("` is not supported")
             }
+            ArgumentError::UnexpectedFlagWithSuggestion(name, suggestion) => {
+                DbgDocBldr::description("unexpected `")
+                    + DbgDocBldr::description(&name.item)
+                    + DbgDocBldr::description("` is not supported, did you mean `")
+                    + DbgDocBldr::description(suggestion)
+                    + DbgDocBldr::description("`?")
+            }
             ArgumentError::MissingMandatoryPositional(pos) => {
                 DbgDocBldr::description("missing `")
                     + DbgDocBldr::description(pos)
                     + DbgDocBldr::description("` as mandatory positional argument")
             }
+            ArgumentError::MissingMandatoryPositionalWithUsage(pos, usage) => {
+                DbgDocBldr::description("missing `")
+                    + DbgDocBldr::description(pos)
+                    + DbgDocBldr::description("` as mandatory positional argument, usage: `")
+                    + DbgDocBldr::description(usage)
+                    + DbgDocBldr::description("`")
+            }
             ArgumentError::MissingValueForName(name) => {
                 DbgDocBldr::description("missing value for flag `")
                     + DbgDocBldr::description(name)
@@ -345,6 +407,30 @@ impl ShellError {
         .start()
     }
 
+    /// An error for a command that was given a kind of value it can't work with, such as binary
+    /// data where it needs text
+    pub fn unexpected_input(
+        command: Spanned<impl Into<String>>,
+        expected: impl Into<String>,
+        actual: Spanned<impl Into<String>>,
+    ) -> ShellError {
+        let expected = expected.into();
+        let actual_type = actual.item.into();
+
+        ShellError::labeled_error_with_secondary(
+            format!(
+                "{} expected {}, but received {}",
+                command.item.into(),
+                expected,
+                actual_type
+            ),
+            format!("requires {} input", expected),
+            command.span,
+            format!("{} originates from here", actual_type),
+            actual.span,
+        )
+    }
+
     pub fn missing_property(
         subpath: Spanned<impl Into<String>>,
         expr: Spanned<impl Into<String>>,
@@ -465,6 +551,17 @@ impl ShellError {
                     vec![Label::primary(0, argument.span).with_message(
                         format!("unexpected argument (try {} -h)", &command.item))]
                 ),
+                ArgumentError::UnexpectedArgumentWithUsage(argument, usage) => Diagnostic::error().with_message(
+                    format!(
+                        "{} unexpected {}",
+                        Color::Cyan.paint(&command.item),
+                        Color::Green.bold().paint(&argument.item)
+                    )
+                )
+                .with_labels(
+                    vec![Label::primary(0, argument.span).with_message(
+                        format!("unexpected argument (usage: {})", usage))]
+                ),
                 ArgumentError::UnexpectedFlag(flag) => Diagnostic::error().with_message(
                     format!(
                         "{} unexpected {}",
@@ -476,6 +573,17 @@ impl ShellError {
                     Label::primary(0, flag.span).with_message(
                     format!("unexpected flag (try {} -h)", &command.item))
                     ]),
+                ArgumentError::UnexpectedFlagWithSuggestion(flag, suggestion) => Diagnostic::error().with_message(
+                    format!(
+                        "{} unexpected {}",
+                        Color::Cyan.paint(&command.item),
+                        Color::Green.bold().paint(&flag.item)
+                    ),
+                )
+                .with_labels(vec![
+                    Label::primary(0, flag.span).with_message(
+                    format!("unexpected flag, did you mean '{}'?", suggestion))
+                    ]),
                 ArgumentError::MissingMandatoryFlag(name) => Diagnostic::error().with_message(                    format!(
                         "{} requires {}{}",
                         Color::Cyan.paint(&command.item),
@@ -494,6 +602,16 @@ impl ShellError {
                 .with_labels(
                     vec![Label::primary(0, command.span).with_message(format!("requires {} parameter", name))],
                 ),
+                ArgumentError::MissingMandatoryPositionalWithUsage(name, usage) => Diagnostic::error().with_message(
+                    format!(
+                        "{} requires {} parameter",
+                        Color::Cyan.paint(&command.item),
+                        Color::Green.bold().paint(name.clone())
+                    ),
+                )
+                .with_labels(
+                    vec![Label::primary(0, command.span).with_message(format!("requires {} parameter (usage: {})", name, usage))],
+                ),
                 ArgumentError::MissingValueForName(name) => Diagnostic::error().with_message(
                     format!(
                         "{} is missing value for flag {}{}",
@@ -635,6 +753,21 @@ impl ShellError {
         )
     }
 
+    /// Points at where the error came from in addition to where it happened, such as the block
+    /// that ran the failing command
+    pub fn with_secondary_label(
+        self,
+        label: impl Into<String>,
+        span: impl Into<Span>,
+    ) -> ShellError {
+        match self.clone().into_diagnostic() {
+            Some(diagnostic) => ShellError::diagnostic(diagnostic.with_labels(vec![
+                Label::secondary(0, span.into()).with_message(label.into()),
+            ])),
+            None => self,
+        }
+    }
+
     pub fn unimplemented(title: impl Into<String>) -> ShellError {
         ShellError::untagged_runtime_error(&format!("Unimplemented: {}", title.into()))
     }
@@ -34,6 +34,14 @@ enum BlockKind {
 }
 
 impl BlockKind {
+    fn opening(self) -> char {
+        match self {
+            BlockKind::Paren => '(',
+            BlockKind::SquareBracket => '[',
+            BlockKind::CurlyBracket => '{',
+        }
+    }
+
     fn closing(self) -> char {
         match self {
             BlockKind::Paren => ')',
@@ -51,6 +59,11 @@ impl BlockKind {
 /// separator (like pipe or semicolon) Baseline tokens may be surrounded by
 /// quotes (single, double, or backtick) or braces (square, paren, curly)
 ///
+/// Double-quoted strings understand backslash escapes, so `\"` does not end
+/// the string. Single-quoted strings are raw: there is no escape character at
+/// all inside of them, which means a single-quoted string can never contain a
+/// `'` but can hold a path like `'C:\Users\me\'` as-is.
+///
 /// Baseline tokens may be further processed based on the needs of the syntax
 /// shape that encounters them. They are still lightly lexed. For example, if a
 /// baseline token begins with `{`, the entire token will continue until the
@@ -67,16 +80,24 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
     // we remain inside the string literal lexer mode until we encounter the
     // closing quote.
     let mut quote_start: Option<char> = None;
+    let mut quote_opened_at = 0;
+
+    // Inside of a double-quoted string, a backslash escapes the character that
+    // follows it, so `\"` does not close the string.
+    let mut escaped = false;
 
     let mut in_comment = false;
 
-    // This Vec tracks paired delimiters
-    let mut block_level: Vec<BlockKind> = vec![];
+    // This Vec tracks paired delimiters, along with where each one was opened
+    let mut block_level: Vec<(BlockKind, usize)> = vec![];
+
+    // The first `)` that didn't close an opening `(`
+    let mut unmatched_paren: Option<usize> = None;
 
     // A baseline token is terminated if it's not nested inside of a paired
     // delimiter and the next character is one of: `|`, `;`, `#` or any
     // whitespace.
-    fn is_termination(block_level: &[BlockKind], c: char) -> bool {
+    fn is_termination(block_level: &[(BlockKind, usize)], c: char) -> bool {
         block_level.is_empty() && (c.is_whitespace() || c == '|' || c == ';' || c == '#')
     }
 
@@ -91,13 +112,17 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
     //   character (whitespace, `|`, `;` or `#`) is encountered, the baseline
     //   token is done.
     // - Otherwise, accumulate the character into the current baseline token.
-    while let Some((_, c)) = src.peek() {
-        let c = *c;
+    while let Some((pos, c)) = src.peek() {
+        let (pos, c) = (*pos, *c);
 
         if quote_start.is_some() {
             // If we encountered the closing quote character for the current
             // string, we're done with the current string.
-            if Some(c) == quote_start {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && quote_start == Some('"') {
+                escaped = true;
+            } else if Some(c) == quote_start {
                 quote_start = None;
             }
         } else if c == '#' {
@@ -117,30 +142,33 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
         } else if c == '\'' || c == '"' || c == '`' {
             // We encountered the opening quote of a string literal.
             quote_start = Some(c);
+            quote_opened_at = pos;
         } else if c == '[' {
             // We encountered an opening `[` delimiter.
-            block_level.push(BlockKind::SquareBracket);
+            block_level.push((BlockKind::SquareBracket, pos));
         } else if c == ']' {
             // We encountered a closing `]` delimiter. Pop off the opening `[`
             // delimiter.
-            if let Some(BlockKind::SquareBracket) = block_level.last() {
+            if let Some((BlockKind::SquareBracket, _)) = block_level.last() {
                 let _ = block_level.pop();
             }
         } else if c == '{' {
             // We encountered an opening `{` delimiter.
-            block_level.push(BlockKind::CurlyBracket);
+            block_level.push((BlockKind::CurlyBracket, pos));
         } else if c == '}' {
             // We encountered a closing `}` delimiter. Pop off the opening `{`.
-            if let Some(BlockKind::CurlyBracket) = block_level.last() {
+            if let Some((BlockKind::CurlyBracket, _)) = block_level.last() {
                 let _ = block_level.pop();
             }
         } else if c == '(' {
             // We enceountered an opening `(` delimiter.
-            block_level.push(BlockKind::Paren);
+            block_level.push((BlockKind::Paren, pos));
         } else if c == ')' {
             // We encountered a closing `)` delimiter. Pop off the opening `(`.
-            if let Some(BlockKind::Paren) = block_level.last() {
+            if let Some((BlockKind::Paren, _)) = block_level.last() {
                 let _ = block_level.pop();
+            } else if unmatched_paren.is_none() {
+                unmatched_paren = Some(pos);
             }
         } else if is_termination(&block_level, c) {
             break;
@@ -158,28 +186,59 @@ pub fn baseline(src: &mut Input, span_offset: usize) -> (Spanned<String>, Option
         start_offset + span_offset + token_contents.len(),
     );
 
-    // If there is still unclosed opening delimiters, close them and add
-    // synthetic closing characters to the accumulated token.
-    if let Some(block) = block_level.last() {
-        let delim: char = (*block).closing();
-        let cause = ParseError::unexpected_eof(delim.to_string(), span);
+    // The end of the input, where the closing delimiter was expected
+    let end = Span::new(span.end(), span.end());
+
+    // If a string literal is still open, it's the innermost delimiter, so the error points
+    // at its opening quote. The non-lite parse trims quotes on both sides, so we add the
+    // expected quote so that anyone wanting to consume this partial parse (e.g.,
+    // completions) will be able to get correct information from the non-lite parse.
+    let mut cause = None;
+    if let Some(delimiter) = quote_start {
+        let opened_at = Span::new(
+            quote_opened_at + span_offset,
+            quote_opened_at + span_offset + 1,
+        );
+        cause = Some(ParseError::unclosed(
+            delimiter.to_string().spanned(opened_at),
+            delimiter.to_string(),
+            end,
+        ));
+
+        token_contents.push(delimiter);
+    }
 
-        while let Some(bk) = block_level.pop() {
+    // If there is still unclosed opening delimiters, close them and add
+    // synthetic closing characters to the accumulated token. Otherwise, the
+    // error points at the innermost delimiter that was left open.
+    if let Some((block, opened_at)) = block_level.last() {
+        let opened_at = Span::new(opened_at + span_offset, opened_at + span_offset + 1);
+        cause = cause.or_else(|| {
+            Some(ParseError::unclosed(
+                block.opening().to_string().spanned(opened_at),
+                block.closing().to_string(),
+                end,
+            ))
+        });
+
+        while let Some((bk, _)) = block_level.pop() {
             token_contents.push(bk.closing());
         }
+    }
 
-        return (token_contents.spanned(span), Some(cause));
+    if cause.is_some() {
+        return (token_contents.spanned(span), cause);
     }
 
-    if let Some(delimiter) = quote_start {
-        // The non-lite parse trims quotes on both sides, so we add the expected quote so that
-        // anyone wanting to consume this partial parse (e.g., completions) will be able to get
-        // correct information from the non-lite parse.
-        token_contents.push(delimiter);
+    if let Some(pos) = unmatched_paren {
+        let paren = Span::new(pos + span_offset, pos + span_offset + 1);
 
         return (
             token_contents.spanned(span),
-            Some(ParseError::unexpected_eof(delimiter.to_string(), span)),
+            Some(ParseError::mismatch(
+                "a matching '(' before ')'",
+                ")".to_string().spanned(paren),
+            )),
         );
     }
 
@@ -254,14 +313,10 @@ impl BlockParser {
         self.prev_token = Some(token);
     }
 
-    fn success(mut self) -> (LiteBlock, Option<ParseError>) {
+    fn finish(mut self, error: Option<ParseError>) -> (LiteBlock, Option<ParseError>) {
         self.close_group();
 
-        (LiteBlock::new(self.groups.map(|g| g.into())), None)
-    }
-
-    fn fail(self, error: ParseError) -> (LiteBlock, Option<ParseError>) {
-        (LiteBlock::new(self.groups.map(|g| g.into())), Some(error))
+        (LiteBlock::new(self.groups.map(|g| g.into())), error)
     }
 
     fn comment(&mut self, token: &LiteComment) {
@@ -351,7 +406,22 @@ impl BlockParser {
 pub fn parse_block(tokens: Vec<Token>) -> (LiteBlock, Option<ParseError>) {
     let mut parser = BlockParser::default();
 
+    // A `|` at the end of a line continues the pipeline on the next line, so a `|` that is
+    // only followed by newlines and comments is waiting for a command that never comes.
+    let dangling_pipe = tokens
+        .iter()
+        .rev()
+        .find(|token| {
+            !matches!(
+                token.contents,
+                TokenContents::EOL | TokenContents::Comment(_)
+            )
+        })
+        .filter(|token| token.contents == TokenContents::Pipe)
+        .map(|token| token.span);
+
     let mut tokens = tokens.iter().peekable();
+    let mut error = None;
 
     // The parsing process repeats:
     //
@@ -385,13 +455,16 @@ pub fn parse_block(tokens: Vec<Token>) -> (LiteBlock, Option<ParseError>) {
                 // command.
 
                 if parser.pipe().is_err() {
-                    // If the current command doesn't have content, return an
-                    // error that indicates that the `|` was unexpected.
-                    return parser.fail(ParseError::extra_tokens(
-                        "|".to_string().spanned(token.span),
-                    ));
+                    // If the current command doesn't have content, report that
+                    // the `|` was unexpected, but keep going so the commands
+                    // around it are still there for callers that recover from
+                    // errors.
+                    error = error.or_else(|| {
+                        Some(ParseError::extra_tokens(
+                            "|".to_string().spanned(token.span),
+                        ))
+                    });
                 }
-                // match parser.pipe() {}
             }
             TokenContents::Semicolon => {
                 // We encountered a semicolon (`;`) character, which terminates
@@ -411,7 +484,13 @@ pub fn parse_block(tokens: Vec<Token>) -> (LiteBlock, Option<ParseError>) {
         parser.consumed(token.clone());
     }
 
-    parser.success()
+    if let Some(span) = dangling_pipe {
+        // Report the dangling pipe as an unexpected EOF, which lets the line editor know to
+        // keep asking for more input.
+        error = error.or_else(|| Some(ParseError::unexpected_eof("a command after '|'", span)));
+    }
+
+    parser.finish(error)
 }
 
 /// Breaks the input string into a vector of tokens. This tokenization only tries to classify separators like
@@ -111,6 +111,47 @@ mod bare {
         assert_eq!(result[0].span, span(0, 10));
     }
 
+    #[test]
+    fn escaped_double_quote() {
+        let input = r#""foo \" bar" baz"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result[0].span, span(0, 12));
+    }
+
+    #[test]
+    fn escaped_backslash_before_closing_quote() {
+        let input = r#""foo\\" bar"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result[0].span, span(0, 7));
+    }
+
+    #[test]
+    fn single_quotes_do_not_escape() {
+        let input = r#"'foo\' bar"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result[0].span, span(0, 6));
+    }
+
+    #[test]
+    fn single_quotes_with_trailing_backslash() {
+        let input = r#"'C:\Users\me\' bar"#;
+
+        let (result, err) = lex(input, 0);
+
+        assert!(err.is_none());
+        assert_eq!(result[0].span, span(0, 14));
+        assert_eq!(result[1].span, span(15, 18));
+    }
+
     #[test]
     fn lex_comment() {
         let input = r#"
@@ -131,6 +172,39 @@ def e [] {echo hi}
         );
     }
 
+    #[test]
+    fn lex_comment_at_eof_without_newline() {
+        let input = "ls # list files";
+
+        let (result, err) = lex(input, 0);
+        assert!(err.is_none());
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].contents, TokenContents::Baseline("ls".into()));
+        assert_eq!(result[1].span, span(4, 15));
+        assert_eq!(
+            result[1].contents,
+            TokenContents::Comment(LiteComment::new_with_ws(
+                " ".to_string().spanned(Span::new(4, 5)),
+                "list files".to_string().spanned(Span::new(5, 15))
+            ))
+        );
+    }
+
+    #[test]
+    fn lex_hash_inside_double_quotes() {
+        let input = r#"echo "not # a comment""#;
+
+        let (result, err) = lex(input, 0);
+        assert!(err.is_none());
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[1].contents,
+            TokenContents::Baseline(r#""not # a comment""#.into())
+        );
+    }
+
     #[test]
     fn def_comment_with_sinqle_quote() {
         let input = r#"def f [] {
@@ -180,6 +254,56 @@ def e [] {echo hi}
         assert_eq!(result[0].span, span(0, 3));
     }
 
+    #[test]
+    fn punctuation_heavy_words() {
+        let words = [
+            "https://example.com/data.json",
+            "http://localhost:8080/api?q=1&page=2",
+            "user@host:path/to/file",
+            "git@github.com:nushell/nushell.git",
+            "1.2.3-beta+build",
+            "key=value",
+            "a:b:c",
+            "~/projects/*.rs",
+        ];
+
+        for word in words.iter() {
+            let (result, err) = lex(word, 0);
+
+            assert!(err.is_none(), "{}", word);
+            assert_eq!(result.len(), 1, "{}", word);
+            assert_eq!(
+                result[0].contents,
+                TokenContents::Baseline(word.to_string()),
+                "{}",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn punctuation_heavy_words_still_end_at_terminators() {
+        let cases = [
+            ("https://a.com/x|lines", vec![(0, 15), (16, 21)]),
+            ("user@host:path;ls", vec![(0, 14), (15, 17)]),
+            ("1.2.3-beta#comment", vec![(0, 10)]),
+            ("http://a.com \"b c\"", vec![(0, 12), (13, 18)]),
+        ];
+
+        for (input, spans) in cases.iter() {
+            let (result, err) = lex(input, 0);
+            assert!(err.is_none(), "{}", input);
+
+            let words: Vec<_> = result
+                .iter()
+                .filter(|token| matches!(token.contents, TokenContents::Baseline(_)))
+                .map(|token| (token.span.start(), token.span.end()))
+                .collect();
+
+            assert_eq!(&words, spans, "{}", input);
+        }
+    }
+
     #[test]
     fn invalid_1() {
         let input = "'foo bar";
@@ -206,6 +330,68 @@ def e [] {echo hi}
 
         assert!(err.is_some());
     }
+
+    #[test]
+    fn unmatched_closing_paren_is_an_error() {
+        let (_, err) = lex("echo (ls))", 0);
+
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::Mismatch { actual, .. }) => {
+                assert_eq!(actual.item, ")");
+                assert_eq!(actual.span, span(9, 10));
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unclosed_bracket_points_at_the_opening_bracket() {
+        for (input, opened_at) in &[("echo [1 2", 5), ("echo [1 [2] [3", 12)] {
+            let (_, err) = lex(input, 0);
+
+            match err.map(|err| err.reason().clone()) {
+                Some(nu_errors::ParseErrorReason::Unclosed {
+                    opened,
+                    expected,
+                    end,
+                }) => {
+                    assert_eq!(opened.item, "[");
+                    assert_eq!(opened.span, span(*opened_at, opened_at + 1));
+                    assert_eq!(expected, "]");
+                    assert_eq!(end, span(input.len(), input.len()));
+                }
+                other => panic!("expected an unclosed bracket, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn unclosed_string_points_at_the_opening_quote() {
+        let cases = [
+            ("echo \"unclosed", 5, "\""),
+            ("config --get \"a | ls", 13, "\""),
+            ("echo 'it", 5, "'"),
+            ("echo [1 \"a", 8, "\""),
+        ];
+
+        for (input, opened_at, quote) in cases.iter() {
+            let (_, err) = lex(input, 0);
+
+            match err.map(|err| err.reason().clone()) {
+                Some(nu_errors::ParseErrorReason::Unclosed {
+                    opened,
+                    expected,
+                    end,
+                }) => {
+                    assert_eq!(opened.item, *quote);
+                    assert_eq!(opened.span, span(*opened_at, opened_at + 1));
+                    assert_eq!(expected, *quote);
+                    assert_eq!(end, span(input.len(), input.len()));
+                }
+                other => panic!("expected an unclosed string, got {:?}", other),
+            }
+        }
+    }
 }
 
 mod lite_parse {
@@ -224,6 +410,43 @@ mod lite_parse {
         assert_eq!(result.block[0].pipelines[1].span(), span(14, 20));
     }
 
+    #[test]
+    fn empty_statements_are_skipped() {
+        let (result, err) = lex("; cmd1 ;; cmd2 ;", 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        assert!(err.is_none());
+        assert_eq!(result.block.len(), 1);
+        assert_eq!(result.block[0].pipelines.len(), 2);
+        assert_eq!(result.block[0].pipelines[0].span(), span(2, 6));
+        assert_eq!(result.block[0].pipelines[1].span(), span(10, 14));
+    }
+
+    #[test]
+    fn pipe_at_end_of_line_continues_the_pipeline() {
+        let (result, err) = lex("cmd1 |\n  cmd2", 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        assert!(err.is_none());
+        assert_eq!(result.block.len(), 1);
+        assert_eq!(result.block[0].pipelines.len(), 1);
+        assert_eq!(result.block[0].pipelines[0].commands.len(), 2);
+    }
+
+    #[test]
+    fn dangling_pipe_is_an_unexpected_eof() {
+        let (result, err) = lex("cmd1 |\n# nothing else\n", 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::Eof { span: eof_span, .. }) => {
+                assert_eq!(eof_span, span(5, 6))
+            }
+            other => panic!("expected an unexpected EOF, got {:?}", other),
+        }
+        assert_eq!(result.block[0].pipelines[0].commands.len(), 1);
+    }
+
     #[test]
     fn simple_1() {
         let (result, err) = lex("foo", 0);
@@ -260,7 +483,7 @@ mod lite_parse {
         let (result, err) = lex("my_command \"foo' --test", 10);
         assert!(matches!(
             err.unwrap().reason(),
-            nu_errors::ParseErrorReason::Eof { .. }
+            nu_errors::ParseErrorReason::Unclosed { .. }
         ));
         let (result, _) = parse_block(result);
 
@@ -311,6 +534,35 @@ def my_echo [arg] { echo $arg }
             ])
         );
     }
+
+    #[test]
+    fn comment_only_line_is_an_empty_block() {
+        let (result, err) = lex("# nothing to see here", 0);
+        assert!(err.is_none());
+        let (result, err) = parse_block(result);
+        assert!(err.is_none());
+        assert!(result.block.is_empty());
+    }
+
+    #[test]
+    fn trailing_comment_is_ignored() {
+        let (with_comment, err) = lex("ls #foo", 0);
+        assert!(err.is_none());
+        let (with_comment, err) = parse_block(with_comment);
+        assert!(err.is_none());
+
+        let (without_comment, err) = lex("ls", 0);
+        assert!(err.is_none());
+        let (without_comment, err) = parse_block(without_comment);
+        assert!(err.is_none());
+
+        assert_eq!(with_comment.block.len(), 1);
+        assert_eq!(
+            with_comment.block[0].pipelines[0].commands[0].parts,
+            without_comment.block[0].pipelines[0].commands[0].parts
+        );
+    }
+
     #[test]
     fn discarded_comment() {
         let code = r#"
@@ -13,7 +13,10 @@ mod signature;
 
 pub use lex::lexer::{lex, parse_block};
 pub use lex::tokens::{LiteBlock, LiteCommand, LiteGroup, LitePipeline};
-pub use parse::{classify_block, garbage, parse, parse_full_column_path, parse_math_expression};
+pub use parse::{
+    classify_block, garbage, parse, parse_full_column_path, parse_math_expression, parse_partial,
+    unescape_string,
+};
 pub use path::expand_ndots;
 pub use path::expand_path;
 pub use scope::ParserScope;
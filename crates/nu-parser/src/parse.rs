@@ -5,18 +5,22 @@ use log::trace;
 use nu_errors::{ArgumentError, ParseError};
 use nu_protocol::hir::{
     self, Binary, Block, ClassifiedCommand, Expression, ExternalRedirection, Flag, FlagKind, Group,
-    InternalCommand, Member, NamedArguments, Operator, Pipeline, RangeOperator, SpannedExpression,
-    Unit,
+    InternalCommand, Member, NamedArguments, Operator, Pipeline, PipelineCondition, RangeOperator,
+    SpannedExpression, Unit,
+};
+use nu_protocol::{
+    closest_match, NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember,
 };
-use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember};
 use nu_source::{HasSpan, Span, Spanned, SpannedItem};
 use num_bigint::BigInt;
+use regex::Regex;
 
 use crate::lex::lexer::{lex, parse_block};
 use crate::lex::tokens::{LiteBlock, LiteCommand, LitePipeline};
 use crate::path::expand_path;
 use crate::scope::ParserScope;
 use bigdecimal::BigDecimal;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
 
 use self::{
     def::{parse_definition, parse_definition_prototype},
@@ -27,7 +31,7 @@ use self::{
 mod def;
 mod util;
 
-pub use self::util::garbage;
+pub use self::util::{garbage, unescape_string};
 
 /// Parses a simple column path, one without a variable (implied or explicit) at the head
 pub fn parse_simple_column_path(
@@ -290,109 +294,269 @@ fn parse_range(
 
 /// Parse any allowed operator, including word-based operators
 fn parse_operator(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
-    let operator = match &lite_arg.item[..] {
-        "==" => Operator::Equal,
-        "!=" => Operator::NotEqual,
-        "<" => Operator::LessThan,
-        "<=" => Operator::LessThanOrEqual,
-        ">" => Operator::GreaterThan,
-        ">=" => Operator::GreaterThanOrEqual,
-        "=~" => Operator::Contains,
-        "!~" => Operator::NotContains,
-        "+" => Operator::Plus,
-        "-" => Operator::Minus,
-        "*" => Operator::Multiply,
-        "/" => Operator::Divide,
-        "in" => Operator::In,
-        "not-in" => Operator::NotIn,
-        "mod" => Operator::Modulo,
-        "&&" => Operator::And,
-        "||" => Operator::Or,
-        "**" => Operator::Pow,
-        _ => {
-            return (
-                garbage(lite_arg.span),
-                Some(ParseError::mismatch("operator", lite_arg.clone())),
-            );
-        }
-    };
-
-    (
-        SpannedExpression::new(Expression::operator(operator), lite_arg.span),
-        None,
-    )
+    match lite_arg.item.parse::<Operator>() {
+        Ok(operator) => (
+            SpannedExpression::new(Expression::operator(operator), lite_arg.span),
+            None,
+        ),
+        Err(()) => (
+            garbage(lite_arg.span),
+            Some(ParseError::mismatch("operator", lite_arg.clone())),
+        ),
+    }
 }
 
+/// The suffixes that make a number into a unit literal, like `10kb` or `3day`. They are matched
+/// case-insensitively, so `10KB`, `10kB` and `10Kb` are all the same. The common durations also
+/// have one-letter forms, like `30s` or `2d`.
+const UNITS: [(Unit, &str); 24] = [
+    (Unit::Byte, "b"),
+    (Unit::Kilobyte, "kb"),
+    (Unit::Megabyte, "mb"),
+    (Unit::Gigabyte, "gb"),
+    (Unit::Terabyte, "tb"),
+    (Unit::Petabyte, "pb"),
+    (Unit::Kibibyte, "kib"),
+    (Unit::Mebibyte, "mib"),
+    (Unit::Gibibyte, "gib"),
+    (Unit::Nanosecond, "ns"),
+    (Unit::Microsecond, "us"),
+    (Unit::Millisecond, "ms"),
+    (Unit::Second, "sec"),
+    (Unit::Minute, "min"),
+    (Unit::Hour, "hr"),
+    (Unit::Day, "day"),
+    (Unit::Week, "wk"),
+    (Unit::Month, "mon"),
+    (Unit::Year, "yr"),
+    (Unit::Second, "s"),
+    (Unit::Minute, "m"),
+    (Unit::Hour, "h"),
+    (Unit::Day, "d"),
+    (Unit::Week, "w"),
+];
+
 /// Parse a unit type, eg '10kb'
 fn parse_unit(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
-    let unit_groups = [
-        (Unit::Byte, vec!["b", "B"]),
-        (Unit::Kilobyte, vec!["kb", "KB", "Kb", "kB"]),
-        (Unit::Megabyte, vec!["mb", "MB", "Mb", "mB"]),
-        (Unit::Gigabyte, vec!["gb", "GB", "Gb", "gB"]),
-        (Unit::Terabyte, vec!["tb", "TB", "Tb", "tB"]),
-        (Unit::Petabyte, vec!["pb", "PB", "Pb", "pB"]),
-        (Unit::Kibibyte, vec!["KiB", "kib", "kiB", "Kib"]),
-        (Unit::Mebibyte, vec!["MiB", "mib", "miB", "Mib"]),
-        (Unit::Gibibyte, vec!["GiB", "gib", "giB", "Gib"]),
-        (Unit::Nanosecond, vec!["ns"]),
-        (Unit::Microsecond, vec!["us"]),
-        (Unit::Millisecond, vec!["ms"]),
-        (Unit::Second, vec!["sec"]),
-        (Unit::Minute, vec!["min"]),
-        (Unit::Hour, vec!["hr"]),
-        (Unit::Day, vec!["day"]),
-        (Unit::Week, vec!["wk"]),
-        (Unit::Month, vec!["mon"]),
-        (Unit::Year, vec!["yr"]),
-    ];
-
-    for unit_group in unit_groups.iter() {
-        for unit in unit_group.1.iter() {
-            if !lite_arg.item.ends_with(unit) {
-                continue;
-            }
-            let mut lhs = lite_arg.item.clone();
+    let lowercase = lite_arg.item.to_ascii_lowercase();
 
-            for _ in 0..unit.len() {
-                lhs.pop();
-            }
+    for (unit, suffix) in UNITS.iter() {
+        // Sizes can be written in any case, but durations can't, so that `10M` isn't read as
+        // ten minutes
+        let matches = if suffix.ends_with('b') {
+            lowercase.ends_with(suffix)
+        } else {
+            lite_arg.item.ends_with(suffix)
+        };
+        if !matches {
+            continue;
+        }
+        let lhs = &lite_arg.item[..lite_arg.item.len() - suffix.len()];
 
-            // these units are allowed to be signed
-            if let Ok(x) = lhs.parse::<i64>() {
-                let lhs_span = Span::new(lite_arg.span.start(), lite_arg.span.start() + lhs.len());
-                let unit_span = Span::new(lite_arg.span.start() + lhs.len(), lite_arg.span.end());
-                return (
-                    SpannedExpression::new(
-                        Expression::unit(x.spanned(lhs_span), unit_group.0.spanned(unit_span)),
-                        lite_arg.span,
-                    ),
-                    None,
-                );
+        // these units are allowed to be signed
+        if let Ok(x) = lhs.parse::<i64>() {
+            let lhs_span = Span::new(lite_arg.span.start(), lite_arg.span.start() + lhs.len());
+            let unit_span = Span::new(lite_arg.span.start() + lhs.len(), lite_arg.span.end());
+            return (
+                SpannedExpression::new(
+                    Expression::unit(x.spanned(lhs_span), (*unit).spanned(unit_span)),
+                    lite_arg.span,
+                ),
+                None,
+            );
+        }
+
+        if let Ok(x) = lhs.parse::<BigDecimal>() {
+            if lhs.ends_with('.') {
+                continue;
             }
+
+            let lhs_span = Span::new(lite_arg.span.start(), lite_arg.span.start() + lhs.len());
+            let unit_span = Span::new(lite_arg.span.start() + lhs.len(), lite_arg.span.end());
+            return (
+                SpannedExpression::new(
+                    Expression::decimal_unit(x.spanned(lhs_span), (*unit).spanned(unit_span)),
+                    lite_arg.span,
+                ),
+                None,
+            );
         }
     }
 
+    if let Some(suffix) = unknown_unit_suffix(lite_arg) {
+        let units = UNITS
+            .iter()
+            .map(|(_, suffix)| *suffix)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        return (
+            garbage(lite_arg.span),
+            Some(ParseError::mismatch(format!("a unit ({})", units), suffix)),
+        );
+    }
+
     (
         garbage(lite_arg.span),
         Some(ParseError::mismatch("unit", lite_arg.clone())),
     )
 }
 
+/// Finds the suffix of something shaped like a file size, a number followed by letters ending in
+/// `b`, like the `XB` in `1XB`. Other suffixes are left alone so that things like `1st` can still
+/// be strings.
+fn unknown_unit_suffix(lite_arg: &Spanned<String>) -> Option<Spanned<String>> {
+    let start = lite_arg.item.find(|c: char| c.is_ascii_alphabetic())?;
+    let (number, suffix) = lite_arg.item.split_at(start);
+
+    if number.parse::<BigDecimal>().is_ok()
+        && suffix.chars().all(|c| c.is_ascii_alphabetic())
+        && suffix.to_ascii_lowercase().ends_with('b')
+    {
+        let start = lite_arg.span.start() + start;
+        Some(
+            suffix
+                .to_string()
+                .spanned(Span::new(start, lite_arg.span.end())),
+        )
+    } else {
+        None
+    }
+}
+
+/// Numbers need at least one digit after the decimal point. Something like `1.` is likely a typo,
+/// so rather than quietly reading it as `1` we point at the dangling `.`
+fn dangling_decimal_point(lite_arg: &Spanned<String>) -> Option<ParseError> {
+    let digits = lite_arg.item.strip_suffix('.')?;
+
+    if digits.parse::<BigInt>().is_ok() {
+        let end = lite_arg.span.end();
+        Some(ParseError::mismatch(
+            "a digit after the decimal point",
+            ".".to_string().spanned(Span::new(end - 1, end)),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Integers can also be written in hexadecimal (`0x1F`), octal (`0o755`) or binary (`0b1010`).
+/// Returns `None` if the argument doesn't start with one of those prefixes. Otherwise, a digit
+/// that doesn't belong to the radix is pointed at.
+fn parse_radix_int(lite_arg: &Spanned<String>) -> Option<(SpannedExpression, Option<ParseError>)> {
+    let (negative, unsigned) = match lite_arg.item.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, lite_arg.item.as_str()),
+    };
+
+    let (radix, digit) = match unsigned.get(..2) {
+        Some("0x") => (16, "a hexadecimal digit"),
+        Some("0o") => (8, "an octal digit"),
+        Some("0b") => (2, "a binary digit"),
+        _ => return None,
+    };
+
+    let digits = &unsigned[2..];
+    let digits_start = lite_arg.span.start() + lite_arg.item.len() - digits.len();
+
+    if digits.is_empty() {
+        // A bare `0b` is zero bytes, so it's left for the unit parser
+        if radix == 2 {
+            return None;
+        }
+
+        return Some((
+            garbage(lite_arg.span),
+            Some(ParseError::mismatch(
+                format!("digits after '{}'", &unsigned[..2]),
+                lite_arg.clone(),
+            )),
+        ));
+    }
+
+    if let Some((idx, c)) = digits.char_indices().find(|(_, c)| !c.is_digit(radix)) {
+        let start = digits_start + idx;
+        return Some((
+            garbage(lite_arg.span),
+            Some(ParseError::mismatch(
+                digit,
+                c.to_string()
+                    .spanned(Span::new(start, start + c.len_utf8())),
+            )),
+        ));
+    }
+
+    let value = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+    let value = if negative { -value } else { value };
+
+    Some((
+        SpannedExpression::new(Expression::integer(value), lite_arg.span),
+        None,
+    ))
+}
+
+/// Dates are written as `2019-06-01`, optionally followed by a time (`2019-06-01T12:00` or
+/// `2019-06-01T12:00:00.5`) and an offset (`Z` or `+02:00`). Without an offset, the date is in
+/// the local timezone. Returns `None` for anything else, which then stays a string.
+fn parse_date(lite_arg: &Spanned<String>) -> Option<SpannedExpression> {
+    let text = lite_arg.item.as_str();
+    let bytes = text.as_bytes();
+
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' || !bytes[0].is_ascii_digit() {
+        return None;
+    }
+
+    let has_offset = text.len() > 10
+        && (text.ends_with('Z')
+            || text
+                .get(10..)
+                .map_or(false, |rest| rest.contains('+') || rest.contains('-')));
+
+    let date = if has_offset {
+        let with_offset = match text.strip_suffix('Z') {
+            Some(stripped) => format!("{}+00:00", stripped),
+            None => text.to_string(),
+        };
+
+        ["%Y-%m-%dT%H:%M:%S%.f%:z", "%Y-%m-%dT%H:%M%:z"]
+            .iter()
+            .find_map(|format| DateTime::parse_from_str(&with_offset, format).ok())?
+    } else {
+        let naive = if text.len() == 10 {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()?
+                .and_hms(0, 0, 0)
+        } else {
+            ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())?
+        };
+
+        let local = Local.from_local_datetime(&naive).earliest()?;
+        local.with_timezone(local.offset())
+    };
+
+    Some(SpannedExpression::new(
+        Expression::date(date),
+        lite_arg.span,
+    ))
+}
+
 fn parse_invocation(
     lite_arg: &Spanned<String>,
     scope: &dyn ParserScope,
 ) -> (SpannedExpression, Option<ParseError>) {
-    // We have a command invocation
+    // We have a command invocation, either `$(...)` or `(...)`
+    let prefix = if lite_arg.item.starts_with('$') { 2 } else { 1 };
     let string: String = lite_arg
         .item
         .chars()
-        .skip(2)
-        .take(lite_arg.item.len() - 3)
+        .skip(prefix)
+        .take(lite_arg.item.len() - prefix - 1)
         .collect();
 
     // We haven't done much with the inner string, so let's go ahead and work with it
-    let (tokens, err) = lex(&string, lite_arg.span.start() + 2);
+    let (tokens, err) = lex(&string, lite_arg.span.start() + prefix);
     if err.is_some() {
         return (garbage(lite_arg.span), err);
     };
@@ -594,6 +758,7 @@ fn parse_interpolated_string(
 
     let pipelines = vec![Pipeline {
         span: lite_arg.span,
+        condition: None,
         list: vec![ClassifiedCommand::Internal(InternalCommand {
             name: "build-string".to_owned(),
             name_span: lite_arg.span,
@@ -752,9 +917,20 @@ fn parse_arg(
         return parse_dollar_expr(&lite_arg, scope);
     }
 
+    // In argument position, a parenthesized pipeline is a command substitution, just like
+    // `$(...)`. Math expressions look for parentheses before getting here, so inside of them
+    // parentheses still group.
+    if lite_arg.item.len() > 1 && lite_arg.item.starts_with('(') && lite_arg.item.ends_with(')') {
+        return parse_invocation(lite_arg, scope);
+    }
+
     match expected_type {
         SyntaxShape::Number => {
-            if let Ok(x) = lite_arg.item.parse::<BigInt>() {
+            if let Some(parsed) = parse_radix_int(lite_arg) {
+                parsed
+            } else if let Some(err) = dangling_decimal_point(lite_arg) {
+                (garbage(lite_arg.span), Some(err))
+            } else if let Ok(x) = lite_arg.item.parse::<BigInt>() {
                 (
                     SpannedExpression::new(Expression::integer(x), lite_arg.span),
                     None,
@@ -772,7 +948,9 @@ fn parse_arg(
             }
         }
         SyntaxShape::Int => {
-            if let Ok(x) = lite_arg.item.parse::<BigInt>() {
+            if let Some(parsed) = parse_radix_int(lite_arg) {
+                parsed
+            } else if let Ok(x) = lite_arg.item.parse::<BigInt>() {
                 (
                     SpannedExpression::new(Expression::integer(x), lite_arg.span),
                     None,
@@ -791,6 +969,17 @@ fn parse_arg(
             {
                 // This is an interpolated string
                 parse_interpolated_string(&lite_arg, scope)
+            } else if lite_arg.item.starts_with('"')
+                && lite_arg.item.len() > 1
+                && lite_arg.item.ends_with('"')
+            {
+                match unescape_string(&lite_arg) {
+                    Ok(unescaped) => (
+                        SpannedExpression::new(Expression::string(unescaped), lite_arg.span),
+                        None,
+                    ),
+                    Err(err) => (garbage(lite_arg.span), Some(err)),
+                }
             } else {
                 let trimmed = trim_quotes(&lite_arg.item);
                 (
@@ -813,7 +1002,21 @@ fn parse_arg(
         SyntaxShape::Unit => parse_unit(&lite_arg),
         SyntaxShape::FilePath => {
             let trimmed = trim_quotes(&lite_arg.item);
-            let expanded = expand_path(&trimmed).to_string();
+
+            // Only bare words are expanded, a quoted path is taken as written
+            let expanded = if trimmed != lite_arg.item {
+                trimmed
+            } else if is_other_users_home(&trimmed) {
+                return (
+                    garbage(lite_arg.span),
+                    Some(ParseError::mismatch(
+                        "'~' or '~/' for the home directory ('~user' is not supported)",
+                        lite_arg.clone(),
+                    )),
+                );
+            } else {
+                expand_path(&trimmed).to_string()
+            };
             let path = Path::new(&expanded);
             (
                 SpannedExpression::new(Expression::FilePath(path.to_path_buf()), lite_arg.span),
@@ -823,6 +1026,36 @@ fn parse_arg(
         SyntaxShape::ColumnPath => parse_simple_column_path(lite_arg),
         SyntaxShape::FullColumnPath => parse_full_column_path(lite_arg, scope),
         SyntaxShape::Any => {
+            if let Some(err) = dangling_decimal_point(lite_arg) {
+                return (garbage(lite_arg.span), Some(err));
+            }
+
+            if let Some(parsed) = parse_radix_int(lite_arg) {
+                return parsed;
+            }
+
+            if let Some(date) = parse_date(lite_arg) {
+                return (date, None);
+            }
+
+            // A bare `true` or `false` is a boolean. Quoting it, or passing it where a string
+            // or a path is expected, keeps it a string.
+            match lite_arg.item.as_str() {
+                "true" => {
+                    return (
+                        SpannedExpression::new(Expression::boolean(true), lite_arg.span),
+                        None,
+                    )
+                }
+                "false" => {
+                    return (
+                        SpannedExpression::new(Expression::boolean(false), lite_arg.span),
+                        None,
+                    )
+                }
+                _ => {}
+            }
+
             let shapes = vec![
                 SyntaxShape::Int,
                 SyntaxShape::Number,
@@ -830,17 +1063,15 @@ fn parse_arg(
                 SyntaxShape::Unit,
                 SyntaxShape::Block,
                 SyntaxShape::Table,
-                SyntaxShape::String,
             ];
             for shape in shapes.iter() {
                 if let (s, None) = parse_arg(*shape, scope, lite_arg) {
                     return (s, None);
                 }
             }
-            (
-                garbage(lite_arg.span),
-                Some(ParseError::mismatch("any shape", lite_arg.clone())),
-            )
+            // Anything else is a string, which only fails to parse if it
+            // contains a malformed escape sequence.
+            parse_arg(SyntaxShape::String, scope, lite_arg)
         }
         SyntaxShape::Table => {
             let mut chars = lite_arg.item.chars();
@@ -916,6 +1147,20 @@ fn parse_arg(
                         return (garbage(lite_arg.span), err);
                     }
 
+                    // A condition can also be spelled out inside of a block, as in
+                    // `where { $it.size > 1kb }`
+                    if expected_type == SyntaxShape::RowCondition {
+                        if let Some(parts) = bare_condition(&lite_block) {
+                            let (_, expr, err) = parse_math_expression(0, parts, scope, true);
+                            let block = condition_block(expr, lite_arg.span);
+
+                            return (
+                                SpannedExpression::new(Expression::Block(block), lite_arg.span),
+                                err,
+                            );
+                        }
+                    }
+
                     scope.enter_scope();
                     let (classified_block, err) = classify_block(&lite_block, scope);
                     scope.exit_scope();
@@ -938,6 +1183,71 @@ fn parse_arg(
     }
 }
 
+/// Finds the parts of a block that holds nothing but a condition, like `{ $it.size > 1kb }` or
+/// `{ not $it.hidden }`
+fn bare_condition(lite_block: &LiteBlock) -> Option<&[Spanned<String>]> {
+    match lite_block.block.as_slice() {
+        [group] => match group.pipelines.as_slice() {
+            [pipeline] => match pipeline.commands.as_slice() {
+                [command] => {
+                    let parts = &command.parts;
+                    let is_condition = (parts.len() >= 3 && parse_operator(&parts[1]).1.is_none())
+                        || (parts.len() >= 2 && parts[0].item == "not");
+
+                    if is_condition {
+                        Some(parts)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Wraps a condition in the block that commands taking a `RowCondition` expect
+fn condition_block(expr: SpannedExpression, group_span: Span) -> hir::Block {
+    let span = expr.span;
+    let mut commands = hir::Pipeline::new(span);
+    commands.push(ClassifiedCommand::Expr(Box::new(expr)));
+
+    hir::Block::new(
+        Signature::new("<cond>"),
+        vec![Group::new(vec![commands], group_span)],
+        IndexMap::new(),
+        span,
+    )
+}
+
+/// Checks if the path points into the home directory of another user, like `~alice` or
+/// `~alice/notes`. The name has to end at a separator or the end of the path, so files like
+/// `~notes.txt` are left alone.
+fn is_other_users_home(path: &str) -> bool {
+    let user = match path.strip_prefix('~') {
+        Some(rest) => rest.split(|c| c == '/' || c == '\\').next().unwrap_or(""),
+        None => return false,
+    };
+
+    !user.is_empty()
+        && user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Checks if the argument looks like a flag (`-l` or `--long`). A `-` directly followed by a digit,
+/// as in `-1` or `-1.5`, is a negative number rather than a flag.
+fn is_flag(arg: &str) -> bool {
+    let mut chars = arg.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some('-'), Some(c)) => !c.is_ascii_digit(),
+        _ => false,
+    }
+}
+
 /// Match the available flags in a signature with what the user provided. This will check both long-form flags (--long) and shorthand flags (-l)
 /// This also allows users to provide a group of shorthand flags (-la) that correspond to multiple shorthand flags at once.
 fn get_flags_from_flag(
@@ -958,33 +1268,56 @@ fn get_flags_from_flag(
             if let Some((named_type, _)) = signature.named.get(&remainder) {
                 output.push((remainder.clone(), named_type.clone()));
             } else {
-                error = Some(ParseError::argument_error(
-                    cmd.clone(),
-                    ArgumentError::UnexpectedFlag(arg.clone()),
-                ));
+                let reason = match closest_match(signature.named.keys().cloned(), &remainder) {
+                    Some(suggestion) => ArgumentError::UnexpectedFlagWithSuggestion(
+                        arg.clone(),
+                        format!("--{}", suggestion),
+                    ),
+                    None => ArgumentError::UnexpectedFlag(arg.clone()),
+                };
+                error = Some(ParseError::argument_error(cmd.clone(), reason));
             }
         } else {
-            // Short flag(s) expected
+            // Short flag(s) expected. When several are grouped together, like `-la`, they all
+            // have to be switches, as it would be unclear which one a value belongs to.
+            let grouped = remainder.chars().count() > 1;
             let mut starting_pos = arg.span.start() + 1;
             for c in remainder.chars() {
+                let span = Span::new(starting_pos, starting_pos + c.len_utf8());
                 let mut found = false;
                 for (full_name, named_arg) in signature.named.iter() {
                     if Some(c) == named_arg.0.get_short() {
                         found = true;
+                        if grouped
+                            && !matches!(named_arg.0, NamedType::Switch(_))
+                            && error.is_none()
+                        {
+                            error = Some(ParseError::mismatch(
+                                "a switch (flags that take a value can't be grouped)",
+                                c.to_string().spanned(span),
+                            ));
+                        }
                         output.push((full_name.clone(), named_arg.0.clone()));
                         break;
                     }
                 }
 
                 if !found {
-                    error = Some(ParseError::argument_error(
-                        cmd.clone(),
-                        ArgumentError::UnexpectedFlag(
-                            arg.item
-                                .clone()
-                                .spanned(Span::new(starting_pos, starting_pos + c.len_utf8())),
-                        ),
-                    ));
+                    // Any two short flags are a single edit apart, so only a flag that differs
+                    // in case, like `-R` for `-r`, is suggested
+                    let flag = arg.item.clone().spanned(span);
+                    let suggestion = signature
+                        .named
+                        .values()
+                        .filter_map(|(named_type, _)| named_type.get_short())
+                        .find(|short| short.to_lowercase().eq(c.to_lowercase()));
+                    let reason = match suggestion {
+                        Some(short) => {
+                            ArgumentError::UnexpectedFlagWithSuggestion(flag, format!("-{}", short))
+                        }
+                        None => ArgumentError::UnexpectedFlag(flag),
+                    };
+                    error = Some(ParseError::argument_error(cmd.clone(), reason));
                 }
 
                 starting_pos += c.len_utf8();
@@ -998,6 +1331,25 @@ fn get_flags_from_flag(
     }
 }
 
+/// Splits a long flag with an attached value, like `--level=debug`, into the flag and its value.
+/// Any other argument is returned as is, without a value.
+fn split_flag_value(arg: &Spanned<String>) -> (Spanned<String>, Option<Spanned<String>>) {
+    match arg.item.find('=') {
+        Some(eq) if arg.item.starts_with("--") => {
+            let start = arg.span.start();
+            let flag = arg.item[..eq]
+                .to_string()
+                .spanned(Span::new(start, start + eq));
+            let value = arg.item[eq + 1..]
+                .to_string()
+                .spanned(Span::new(start + eq + 1, arg.span.end()));
+
+            (flag, Some(value))
+        }
+        _ => (arg.clone(), None),
+    }
+}
+
 /// This is a bit of a "fix-up" of previously parsed areas. In cases where we're in shorthand mode (eg in the `where` command), we need
 /// to use the original source to parse a column path. Without it, we'll lose a little too much information to parse it correctly. As we'll
 /// only know we were on the left-hand side of an expression after we do the full math parse, we need to do this step after rather than during
@@ -1008,8 +1360,17 @@ fn shorthand_reparse(
     scope: &dyn ParserScope,
     shorthand_mode: bool,
 ) -> (SpannedExpression, Option<ParseError>) {
-    // If we're in shorthand mode, we need to reparse the left-hand side if possible
-    if shorthand_mode {
+    // If we're in shorthand mode, we need to reparse the left-hand side if possible. Numbers,
+    // dates and booleans stay as they are, so that arithmetic like `size > 2 * 1kb` still works.
+    let is_literal = matches!(
+        left.expr,
+        Expression::Literal(hir::Literal::Number(_))
+            | Expression::Literal(hir::Literal::Size(..))
+            | Expression::Literal(hir::Literal::Date(_))
+            | Expression::Boolean(_)
+    );
+
+    if shorthand_mode && !is_literal {
         if let Some(orig_left) = orig_left {
             parse_arg(SyntaxShape::FullColumnPath, scope, &orig_left)
         } else {
@@ -1081,10 +1442,80 @@ fn parse_possibly_parenthesized(
         ((None, lhs), err)
     } else {
         let (lhs, err) = parse_arg(SyntaxShape::Any, scope, lite_arg);
+
+        if let Expression::Literal(hir::Literal::String(_)) = lhs.expr {
+            // In a comparison, something like `1XB` is a mistyped unit rather than a string
+            if unknown_unit_suffix(lite_arg).is_some() {
+                let (lhs, err) = parse_unit(lite_arg);
+                return ((Some(lite_arg.clone()), lhs), err);
+            }
+        }
+
         ((Some(lite_arg.clone()), lhs), err)
     }
 }
 
+/// An `r'pattern'` or `r"pattern"` on the right of `=~` or `!~` is a regular expression rather
+/// than a substring, which is checked here so that a pattern that doesn't compile is pointed out
+/// before running. Other strings, paths such as `/tmp/` included, are still substrings.
+fn parse_regex_operand(
+    op: &SpannedExpression,
+    lite_arg: &Spanned<String>,
+) -> Option<(SpannedExpression, SpannedExpression, Option<ParseError>)> {
+    let matches = match op.expr {
+        Expression::Literal(hir::Literal::Operator(Operator::Contains)) => Operator::Matches,
+        Expression::Literal(hir::Literal::Operator(Operator::NotContains)) => Operator::NotMatches,
+        _ => return None,
+    };
+
+    let text = &lite_arg.item;
+    let quote = match text.get(..2) {
+        Some("r'") => '\'',
+        Some("r\"") => '"',
+        _ => return None,
+    };
+    if text.len() < 3 || !text.ends_with(quote) {
+        return None;
+    }
+
+    let pattern = &text[2..text.len() - 1];
+    let err = Regex::new(pattern)
+        .err()
+        .map(|_| ParseError::mismatch("a valid regular expression", lite_arg.clone()));
+
+    Some((
+        SpannedExpression::new(Expression::operator(matches), op.span),
+        SpannedExpression::new(Expression::string(pattern.to_string()), lite_arg.span),
+        err,
+    ))
+}
+
+/// Whether the expression starts with a `not` to negate it. It's only a keyword when something
+/// other than an operator follows, so that `not` can still be used as a column name.
+fn is_not_prefix(lite_args: &[Spanned<String>]) -> bool {
+    lite_args.len() > 1 && lite_args[0].item == "not" && parse_operator(&lite_args[1]).1.is_some()
+}
+
+/// How many of the arguments a `not` at their start takes, itself included. It negates the
+/// comparison up to the next `&&` or `||`, so `not a == 1 && b` is `(not (a == 1)) && b`, and
+/// parentheses give it more.
+fn not_operand_len(lite_args: &[Spanned<String>]) -> usize {
+    let mut idx = 1;
+
+    loop {
+        while is_not_prefix(&lite_args[idx..]) {
+            idx += 1;
+        }
+        idx += 1;
+
+        match lite_args.get(idx).map(|arg| arg.item.parse::<Operator>()) {
+            Some(Ok(Operator::And)) | Some(Ok(Operator::Or)) => return idx,
+            Some(_) => idx += 1,
+            None => return lite_args.len(),
+        }
+    }
+}
+
 /// Handle parsing math expressions, complete with working with the precedence of the operators
 pub fn parse_math_expression(
     incoming_idx: usize,
@@ -1103,8 +1534,11 @@ pub fn parse_math_expression(
     let mut working_exprs = vec![];
     let mut prec = vec![];
 
-    let (lhs_working_expr, err) =
-        parse_possibly_parenthesized(&lite_args[idx], scope, shorthand_mode);
+    let (lhs_working_expr, err) = if is_not_prefix(lite_args) {
+        parse_not(&mut idx, lite_args, scope, shorthand_mode)
+    } else {
+        parse_possibly_parenthesized(&lite_args[idx], scope, shorthand_mode)
+    };
 
     if error.is_none() {
         error = err;
@@ -1116,7 +1550,7 @@ pub fn parse_math_expression(
     prec.push(0);
 
     while idx < lite_args.len() {
-        let (op, err) = parse_arg(SyntaxShape::Operator, scope, &lite_args[idx]);
+        let (mut op, err) = parse_arg(SyntaxShape::Operator, scope, &lite_args[idx]);
         if error.is_none() {
             error = err;
         }
@@ -1142,8 +1576,17 @@ pub fn parse_math_expression(
             prec
         );
 
-        let (rhs_working_expr, err) =
-            parse_possibly_parenthesized(&lite_args[idx], scope, shorthand_mode);
+        let (rhs_working_expr, err) = if is_not_prefix(&lite_args[idx..]) {
+            parse_not(&mut idx, lite_args, scope, shorthand_mode)
+        } else {
+            match parse_regex_operand(&op, &lite_args[idx]) {
+                Some((matches, pattern, err)) => {
+                    op = matches;
+                    ((None, pattern), err)
+                }
+                None => parse_possibly_parenthesized(&lite_args[idx], scope, shorthand_mode),
+            }
+        };
 
         if error.is_none() {
             error = err;
@@ -1227,6 +1670,28 @@ pub fn parse_math_expression(
     (incoming_idx + idx, left, error)
 }
 
+/// Parses the `not` at `idx` together with what it negates, as a single operand, and leaves `idx`
+/// on the last argument it took
+fn parse_not(
+    idx: &mut usize,
+    lite_args: &[Spanned<String>],
+    scope: &dyn ParserScope,
+    shorthand_mode: bool,
+) -> (
+    (Option<Spanned<String>>, SpannedExpression),
+    Option<ParseError>,
+) {
+    let end = *idx + not_operand_len(&lite_args[*idx..]);
+    let (_, expr, err) = parse_math_expression(0, &lite_args[*idx + 1..end], scope, shorthand_mode);
+    let span = Span::new(lite_args[*idx].span.start(), expr.span.end());
+    *idx = end - 1;
+
+    (
+        (None, SpannedExpression::new(Expression::not(expr), span)),
+        err,
+    )
+}
+
 /// Handles parsing the positional arguments as a batch
 /// This allows us to check for times where multiple arguments are treated as one shape, as is the case with SyntaxShape::Math
 fn parse_positional_argument(
@@ -1294,15 +1759,7 @@ fn parse_positional_argument(
                         parse_math_expression(idx, &lite_cmd.parts[idx..end_idx], scope, true);
 
                     let span = arg.span;
-                    let mut commands = hir::Pipeline::new(span);
-                    commands.push(ClassifiedCommand::Expr(Box::new(arg)));
-
-                    let block = hir::Block::new(
-                        Signature::new("<cond>"),
-                        vec![Group::new(vec![commands], lite_cmd.span())],
-                        IndexMap::new(),
-                        span,
-                    );
+                    let block = condition_block(arg, lite_cmd.span());
 
                     let arg = SpannedExpression::new(Expression::Block(block), span);
 
@@ -1322,10 +1779,10 @@ fn parse_positional_argument(
                 garbage(lite_cmd.span())
             }
         }
-        PositionalType::Mandatory(_, shape) | PositionalType::Optional(_, shape) => {
+        PositionalType::Mandatory(name, shape) | PositionalType::Optional(name, shape) => {
             let (arg, err) = parse_arg(*shape, scope, &lite_cmd.parts[idx]);
             if error.is_none() {
-                error = err;
+                error = err.map(|err| name_mismatched_positional(err, name, &lite_cmd.parts[idx]));
             }
             arg
         }
@@ -1334,6 +1791,18 @@ fn parse_positional_argument(
     (idx, arg, error)
 }
 
+/// When a positional argument as a whole has the wrong type, name the parameter it was given
+/// for, as in "Expected int for `rows`, found foo". Errors about some part of the argument
+/// are left as they are.
+fn name_mismatched_positional(err: ParseError, name: &str, arg: &Spanned<String>) -> ParseError {
+    match err.reason() {
+        nu_errors::ParseErrorReason::Mismatch { expected, actual } if actual.span == arg.span => {
+            ParseError::mismatch(format!("{} for `{}`", expected, name), actual.clone())
+        }
+        _ => err,
+    }
+}
+
 /// Does a full parse of an internal command using the lite-ly parse command as a starting point
 /// This main focus at this level is to understand what flags were passed in, what positional arguments were passed in, what rest arguments were passed in
 /// and to ensure that the basic requirements in terms of number of each were met.
@@ -1357,6 +1826,9 @@ fn parse_internal_command(
         ),
     );
 
+    // Flag and arity errors name the whole command, subcommand included, as in "try config get -h"
+    let command = name.clone().spanned(name_span);
+
     let mut internal_command = InternalCommand::new(name, name_span, lite_cmd.span());
     internal_command.args.set_initial_flags(&signature);
 
@@ -1367,13 +1839,37 @@ fn parse_internal_command(
     idx += 1; // Start where the arguments begin
 
     while idx < lite_cmd.parts.len() {
-        if lite_cmd.parts[idx].item.starts_with('-') && lite_cmd.parts[idx].item.len() > 1 {
-            let (named_types, err) =
-                get_flags_from_flag(&signature, &lite_cmd.parts[0], &lite_cmd.parts[idx]);
+        if is_flag(&lite_cmd.parts[idx].item) {
+            let (flag, attached_value) = split_flag_value(&lite_cmd.parts[idx]);
+            let (named_types, err) = get_flags_from_flag(&signature, &command, &flag);
 
             if err.is_none() {
                 for (full_name, named_type) in &named_types {
                     match named_type {
+                        NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape)
+                            if attached_value.is_some() =>
+                        {
+                            match &attached_value {
+                                Some(value) if !value.item.is_empty() => {
+                                    let (arg, err) = parse_arg(*shape, scope, value);
+                                    named.insert_mandatory(full_name.clone(), flag.span, arg);
+
+                                    if error.is_none() {
+                                        error = err;
+                                    }
+                                }
+                                _ => {
+                                    if error.is_none() {
+                                        error = Some(ParseError::argument_error(
+                                            lite_cmd.parts[0].clone(),
+                                            ArgumentError::MissingValueForName(
+                                                full_name.to_owned(),
+                                            ),
+                                        ));
+                                    }
+                                }
+                            }
+                        }
                         NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape) => {
                             if idx == lite_cmd.parts.len() {
                                 // Oops, we're missing the argument to our named argument
@@ -1405,6 +1901,15 @@ fn parse_internal_command(
                             }
                         }
                         NamedType::Switch(_) => {
+                            if let Some(value) = &attached_value {
+                                if error.is_none() {
+                                    error = Some(ParseError::argument_error(
+                                        lite_cmd.parts[0].clone(),
+                                        ArgumentError::UnexpectedArgument(value.clone()),
+                                    ));
+                                }
+                            }
+
                             named.insert_switch(
                                 full_name.clone(),
                                 Some(Flag::new(FlagKind::Longhand, lite_cmd.parts[idx].span)),
@@ -1450,8 +1955,11 @@ fn parse_internal_command(
 
             if error.is_none() {
                 error = Some(ParseError::argument_error(
-                    lite_cmd.parts[0].clone(),
-                    ArgumentError::UnexpectedArgument(lite_cmd.parts[idx].clone()),
+                    command.clone(),
+                    ArgumentError::UnexpectedArgumentWithUsage(
+                        lite_cmd.parts[idx].clone(),
+                        signature.usage_line(),
+                    ),
                 ));
             }
         }
@@ -1469,10 +1977,19 @@ fn parse_internal_command(
     if positional.len() < required_arg_count && error.is_none() {
         // to make "command -h" work even if required arguments are missing
         if !named.named.contains_key("help") {
-            let (_, name) = &signature.positional[positional.len()];
+            // Parameters of custom commands may not have a description, so fall back to the name
+            let (positional_type, description) = &signature.positional[positional.len()];
+            let name = if description.is_empty() {
+                positional_type.name()
+            } else {
+                description
+            };
             error = Some(ParseError::argument_error(
-                lite_cmd.parts[0].clone(),
-                ArgumentError::MissingMandatoryPositional(name.to_owned()),
+                command,
+                ArgumentError::MissingMandatoryPositionalWithUsage(
+                    name.to_owned(),
+                    signature.usage_line(),
+                ),
             ));
         }
     }
@@ -1573,33 +2090,140 @@ fn parse_value_call(
     )
 }
 
-fn expand_aliases_in_call(call: &mut LiteCommand, scope: &dyn ParserScope) {
-    if let Some(name) = call.parts.get(0) {
-        if let Some(mut expansion) = scope.get_alias(name) {
-            // set the expansion's spans to point to the alias itself
-            for item in expansion.iter_mut() {
-                item.span = name.span;
-            }
-
-            // replace the alias with the expansion
-            call.parts.remove(0);
-            expansion.append(&mut call.parts);
-            call.parts = expansion;
-        }
+/// Expands the aliases at the head of each command of the pipeline. An alias for a whole
+/// pipeline, like `alias lss = ls | sort-by size`, expands into several commands.
+fn expand_aliases_in_pipeline(
+    lite_pipeline: &LitePipeline,
+    scope: &dyn ParserScope,
+) -> LitePipeline {
+    let mut commands = vec![];
+    for lite_cmd in &lite_pipeline.commands {
+        expand_aliases_in_call(lite_cmd.clone(), scope, &[], &mut commands);
     }
+
+    LitePipeline::new(commands)
 }
 
-fn parse_call(
-    mut lite_cmd: LiteCommand,
-    end_of_pipeline: bool,
+/// Expands the alias at the head of the command, if there is one, pushing the commands it
+/// expands to onto `output`. Aliases may expand to other aliases, but never to one that is
+/// already being expanded, so `alias ls = ls -a` expands only once rather than forever.
+fn expand_aliases_in_call(
+    call: LiteCommand,
     scope: &dyn ParserScope,
-) -> (Option<ClassifiedCommand>, Option<ParseError>) {
-    expand_aliases_in_call(&mut lite_cmd, scope);
+    expanding: &[String],
+    output: &mut Vec<LiteCommand>,
+) {
+    let (name, mut expansion) = match call.parts.get(0) {
+        Some(name) if !expanding.contains(&name.item) => match scope.get_alias(&name.item) {
+            Some(expansion) => (name.clone(), expansion),
+            None => return output.push(call),
+        },
+        _ => return output.push(call),
+    };
 
-    let mut error = None;
-    if lite_cmd.parts.is_empty() {
-        return (None, None);
-    } else if lite_cmd.parts[0].item.starts_with('^') {
+    // set the expansion's spans to point to the alias itself
+    for item in expansion.iter_mut() {
+        item.span = name.span;
+    }
+
+    // replace the alias with the expansion, so that its arguments go to the last command
+    expansion.extend(call.parts.into_iter().skip(1));
+
+    let mut expanding = expanding.to_vec();
+    expanding.push(name.item);
+
+    let mut comments = call.comments;
+    for parts in expansion.split(|part| part.item == "|") {
+        let lite_cmd = LiteCommand {
+            parts: parts.to_vec(),
+            comments: comments.take(),
+        };
+        expand_aliases_in_call(lite_cmd, scope, &expanding, output);
+    }
+}
+
+/// An alias for a pipeline, like `alias lss = ls | sort-by size`, is lexed as a pipeline of
+/// its own. Join it back into a single `alias` command, keeping the `|` between the commands
+/// of its expansion.
+fn join_alias_definition(lite_pipeline: &LitePipeline) -> LitePipeline {
+    let is_alias = match lite_pipeline.commands.first() {
+        Some(first) => first.parts.len() > 1 && first.parts[0].item == "alias",
+        None => false,
+    };
+
+    if !is_alias || lite_pipeline.commands.len() == 1 {
+        return lite_pipeline.clone();
+    }
+
+    let mut commands = lite_pipeline.commands.iter();
+    let mut joined = commands.next().cloned().unwrap_or_default();
+    for lite_cmd in commands {
+        let end = joined.span().end();
+        joined.parts.push(
+            "|".to_string()
+                .spanned(Span::new(end, lite_cmd.span().start())),
+        );
+        joined.parts.extend(lite_cmd.parts.iter().cloned());
+    }
+
+    LitePipeline::new(vec![joined])
+}
+
+/// Falls back to a command whose name only differs in case, when the scope allows it. Both
+/// subcommands, like `Config GET`, and commands are looked up this way. Two such commands make
+/// the name ambiguous, which is an error.
+fn resolve_command_case(lite_cmd: &mut LiteCommand, scope: &dyn ParserScope) -> Option<ParseError> {
+    for words in (1..=lite_cmd.parts.len().min(2)).rev() {
+        let name = lite_cmd.parts[..words]
+            .iter()
+            .map(|part| part.item.clone())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if scope.has_signature(&name) {
+            return None;
+        }
+
+        let names = scope.get_command_names_ignoring_case(&name);
+        match names.len() {
+            0 => continue,
+            1 => {
+                for (part, word) in lite_cmd.parts.iter_mut().zip(names[0].split(' ')) {
+                    part.item = word.to_string();
+                }
+                return None;
+            }
+            _ => {
+                let span = Span::new(
+                    lite_cmd.parts[0].span.start(),
+                    lite_cmd.parts[words - 1].span.end(),
+                );
+
+                return Some(ParseError::mismatch(
+                    names
+                        .iter()
+                        .map(|name| format!("`{}`", name))
+                        .collect::<Vec<_>>()
+                        .join(" or "),
+                    name.spanned(span),
+                ));
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_call(
+    mut lite_cmd: LiteCommand,
+    end_of_pipeline: bool,
+    scope: &dyn ParserScope,
+) -> (Option<ClassifiedCommand>, Option<ParseError>) {
+    if lite_cmd.parts.is_empty() {
+        return (None, None);
+    }
+
+    let mut error = resolve_command_case(&mut lite_cmd, scope);
+    if lite_cmd.parts[0].item.starts_with('^') {
         let name = lite_cmd.parts[0]
             .clone()
             .map(|v| v.chars().skip(1).collect::<String>());
@@ -1660,7 +2284,8 @@ fn parse_call(
             garbage(lite_cmd.span())
         };
         return (Some(ClassifiedCommand::Expr(Box::new(expr))), error);
-    } else if lite_cmd.parts[0].item == "alias" {
+    } else if lite_cmd.parts[0].item == "alias" && lite_cmd.parts.len() > 1 {
+        // Without any arguments, `alias` is a command that lists the aliases
         let error = parse_alias(&lite_cmd, scope);
         if error.is_none() {
             return (None, None);
@@ -1702,6 +2327,8 @@ fn parse_call(
                 )),
             );
         }
+    } else if lite_cmd.parts[0].item == "let" {
+        error = error.or_else(|| verify_let(&lite_cmd));
     } else if lite_cmd.parts.len() > 1 {
         // Check if it's a sub-command
         if let Some(signature) = scope.get_signature(&format!(
@@ -1741,7 +2368,8 @@ fn parse_call(
         };
         (Some(ClassifiedCommand::Internal(internal_command)), error)
     } else {
-        parse_external_call(&lite_cmd, end_of_pipeline, scope)
+        let (call, err) = parse_external_call(&lite_cmd, end_of_pipeline, scope);
+        (call, error.or(err))
     }
 }
 
@@ -1899,6 +2527,20 @@ fn expand_shorthand_forms(
 //     ClassifiedBlock::new(block, error)
 // }
 
+/// Checks that a `let` has the shape `let <name> = <value>`, so that a missing `=` or value is
+/// reported where it was expected rather than on the `let` itself
+fn verify_let(call: &LiteCommand) -> Option<ParseError> {
+    match &call.parts[..] {
+        [_, name] => Some(ParseError::unexpected_eof(
+            "'=' after the variable name",
+            name.span,
+        )),
+        [_, _, equals, ..] if equals.item != "=" => Some(ParseError::mismatch("=", equals.clone())),
+        [_, _, equals] => Some(ParseError::unexpected_eof("a value after '='", equals.span)),
+        _ => None,
+    }
+}
+
 fn parse_alias(call: &LiteCommand, scope: &dyn ParserScope) -> Option<ParseError> {
     if call.parts.len() < 4 {
         return Some(ParseError::mismatch("alias", call.parts[0].clone()));
@@ -1920,11 +2562,188 @@ fn parse_alias(call: &LiteCommand, scope: &dyn ParserScope) -> Option<ParseError
     None
 }
 
+/// Returns true if the command takes a math expression as one of its arguments (like `where`,
+/// `if` or `=`). These commands treat `&&` and `||` as logical operators rather than as
+/// pipeline separators.
+fn takes_math_expression(lite_cmd: &LiteCommand, scope: &dyn ParserScope) -> bool {
+    let mut expanded = vec![];
+    expand_aliases_in_call(lite_cmd.clone(), scope, &[], &mut expanded);
+
+    expanded
+        .iter()
+        .any(|lite_cmd| command_takes_math_expression(lite_cmd, scope))
+}
+
+fn command_takes_math_expression(lite_cmd: &LiteCommand, scope: &dyn ParserScope) -> bool {
+    let head = match lite_cmd.parts.get(0) {
+        Some(head) => head,
+        None => return false,
+    };
+
+    if head.item == "=" {
+        return true;
+    }
+
+    let signature = lite_cmd
+        .parts
+        .get(1)
+        .and_then(|sub| scope.get_signature(&format!("{} {}", head.item, sub.item)))
+        .or_else(|| scope.get_signature(&head.item));
+
+    match signature {
+        Some(signature) => signature
+            .positional
+            .iter()
+            .map(|(positional, _)| positional.syntax_type())
+            .chain(signature.rest_positional.iter().map(|(shape, _)| *shape))
+            .any(|shape| {
+                matches!(
+                    shape,
+                    SyntaxShape::MathExpression | SyntaxShape::RowCondition
+                )
+            }),
+        None => false,
+    }
+}
+
+/// Split a lite pipeline wherever one of its commands is followed by `&&` or `||`. Each of the
+/// resulting pipelines (except the first) remembers the operator that chained it to the pipeline
+/// before it.
+fn split_conditional_pipelines(
+    lite_pipeline: &LitePipeline,
+    scope: &dyn ParserScope,
+) -> (
+    Vec<(LitePipeline, Option<PipelineCondition>)>,
+    Option<ParseError>,
+) {
+    let mut output = vec![];
+    let mut commands = vec![];
+    let mut condition = None;
+
+    for lite_cmd in &lite_pipeline.commands {
+        let mut lite_cmd = lite_cmd.clone();
+
+        loop {
+            let position = if takes_math_expression(&lite_cmd, scope) {
+                None
+            } else {
+                lite_cmd
+                    .parts
+                    .iter()
+                    .position(|part| matches!(&part.item[..], "&&" | "||" | "&"))
+            };
+
+            let position = match position {
+                Some(position) => position,
+                None => {
+                    commands.push(lite_cmd);
+                    break;
+                }
+            };
+
+            let mut rest = lite_cmd.parts.split_off(position);
+            let operator = rest.remove(0);
+
+            if operator.item == "&" {
+                return (
+                    vec![(lite_pipeline.clone(), None)],
+                    Some(ParseError::mismatch(
+                        "'&&' or '||' to chain commands (a single '&' is not supported)",
+                        operator,
+                    )),
+                );
+            }
+
+            if lite_cmd.parts.is_empty() || rest.is_empty() {
+                return (
+                    vec![(lite_pipeline.clone(), None)],
+                    Some(ParseError::extra_tokens(operator)),
+                );
+            }
+
+            commands.push(lite_cmd);
+            output.push((LitePipeline::new(commands), condition));
+
+            commands = vec![];
+            condition = Some(if operator.item == "&&" {
+                PipelineCondition::AndThen
+            } else {
+                PipelineCondition::OrElse
+            });
+            lite_cmd = LiteCommand {
+                parts: rest,
+                comments: None,
+            };
+        }
+    }
+
+    output.push((LitePipeline::new(commands), condition));
+
+    (output, None)
+}
+
+/// Turns a trailing `> file` or `>> file` on the last command of a pipeline into a `save` (or
+/// `save --append`) at the end of the pipeline. For commands that take a condition, like
+/// `where size > 1kb`, a `>` is always a comparison, so only `>>` redirects their output.
+fn expand_redirection(
+    lite_pipeline: &LitePipeline,
+    scope: &dyn ParserScope,
+) -> (LitePipeline, Option<ParseError>) {
+    let last = match lite_pipeline.commands.last() {
+        Some(last) => last,
+        None => return (lite_pipeline.clone(), None),
+    };
+    let parts = &last.parts;
+    let takes_condition = takes_math_expression(last, scope);
+    let is_redirection =
+        |part: &Spanned<String>| part.item == ">>" || (part.item == ">" && !takes_condition);
+
+    match parts.last() {
+        Some(operator) if parts.len() > 1 && is_redirection(operator) => {
+            return (
+                lite_pipeline.clone(),
+                Some(ParseError::argument_error(
+                    operator.clone(),
+                    ArgumentError::MissingMandatoryPositional("a file to redirect to".into()),
+                )),
+            );
+        }
+        _ => {}
+    }
+
+    if parts.len() < 3 || !is_redirection(&parts[parts.len() - 2]) {
+        return (lite_pipeline.clone(), None);
+    }
+
+    let position = parts.len() - 2;
+    let mut lite_pipeline = lite_pipeline.clone();
+    let mut redirection = match lite_pipeline.commands.last_mut() {
+        Some(last) => last.parts.split_off(position),
+        None => return (lite_pipeline, None),
+    };
+    let target = redirection.remove(1);
+    let operator = redirection.remove(0);
+
+    let mut save = vec!["save".to_string().spanned(operator.span)];
+    if operator.item == ">>" {
+        save.push("--append".to_string().spanned(operator.span));
+    }
+    save.push(target);
+
+    lite_pipeline.commands.push(LiteCommand {
+        parts: save,
+        comments: None,
+    });
+
+    (lite_pipeline, None)
+}
+
 pub fn classify_block(
     lite_block: &LiteBlock,
     scope: &dyn ParserScope,
 ) -> (Block, Option<ParseError>) {
     let mut output = Block::basic();
+    output.span = lite_block.span();
     let mut error = None;
 
     // Check for custom commands first
@@ -1945,18 +2764,40 @@ pub fn classify_block(
 
     // Then the rest of the code
     for group in &lite_block.block {
-        let mut out_group = Group::basic();
+        let mut out_group = Group::new(vec![], group.span());
+        let mut pipelines = vec![];
         for pipeline in &group.pipelines {
-            let (pipeline, vars, err) = expand_shorthand_forms(pipeline);
+            let pipeline = join_alias_definition(pipeline);
+            let (mut split, err) = split_conditional_pipelines(&pipeline, scope);
+            if error.is_none() {
+                error = err;
+            }
+            pipelines.append(&mut split);
+        }
+
+        for (pipeline, condition) in &pipelines {
+            // The expansions below rewrite the pipeline, but its span stays the one it was
+            // written with, so that it can be printed as it was written
+            let written_span = pipeline.span();
+
+            let pipeline = expand_aliases_in_pipeline(pipeline, scope);
+            let (pipeline, err) = expand_redirection(&pipeline, scope);
             if error.is_none() {
                 error = err;
             }
 
-            let (out_pipe, err) = parse_pipeline(pipeline.clone(), scope);
+            let (pipeline, vars, err) = expand_shorthand_forms(&pipeline);
             if error.is_none() {
                 error = err;
             }
 
+            let (mut out_pipe, err) = parse_pipeline(pipeline.clone(), scope);
+            if error.is_none() {
+                error = err;
+            }
+            out_pipe.condition = *condition;
+            out_pipe.span = written_span;
+
             let pipeline = if let Some(vars) = vars {
                 let span = pipeline.span();
                 let block = hir::Block::new(
@@ -1999,7 +2840,8 @@ pub fn classify_block(
 
                 Pipeline {
                     list: vec![classified_with_env],
-                    span,
+                    span: written_span,
+                    condition: *condition,
                 }
             } else {
                 out_pipe
@@ -2042,140 +2884,1413 @@ pub fn parse(
     classify_block(&lite_block, scope)
 }
 
-#[test]
-fn unit_parse_byte_units() {
-    struct TestCase {
-        string: String,
-        value: i64,
-        unit: Unit,
+/// Parses the input like `parse`, but keeps going past errors instead of giving up on the
+/// whole line. The commands that did parse are returned along with every error found, which
+/// lets tools that work on half-written lines, like the completer, still see where they are.
+pub fn parse_partial(
+    input: &str,
+    span_offset: usize,
+    scope: &dyn ParserScope,
+) -> (Block, Vec<ParseError>) {
+    let (output, lex_error) = lex(input, span_offset);
+    let (lite_block, block_error) = parse_block(output);
+    let (block, classify_error) = classify_block(&lite_block, scope);
+
+    let errors = vec![lex_error, block_error, classify_error]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    (block, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct EmptyScope;
+
+    impl ParserScope for EmptyScope {
+        fn get_signature(&self, _name: &str) -> Option<Signature> {
+            None
+        }
+
+        fn has_signature(&self, _name: &str) -> bool {
+            false
+        }
+
+        fn add_definition(&self, _block: Block) {}
+
+        fn get_definitions(&self) -> Vec<Block> {
+            vec![]
+        }
+
+        fn get_alias(&self, _name: &str) -> Option<Vec<Spanned<String>>> {
+            None
+        }
+
+        fn add_alias(&self, _name: &str, _replacement: Vec<Spanned<String>>) {}
+
+        fn enter_scope(&self) {}
+
+        fn exit_scope(&self) {}
     }
 
-    let cases = [
-        TestCase {
-            string: String::from("108b"),
-            value: 108,
-            unit: Unit::Byte,
-        },
-        TestCase {
-            string: String::from("0B"),
-            value: 0,
-            unit: Unit::Byte,
-        },
-        TestCase {
-            string: String::from("10kb"),
-            value: 10,
-            unit: Unit::Kilobyte,
-        },
-        TestCase {
-            string: String::from("16KB"),
-            value: 16,
-            unit: Unit::Kilobyte,
-        },
-        TestCase {
-            string: String::from("99kB"),
-            value: 99,
-            unit: Unit::Kilobyte,
-        },
-        TestCase {
-            string: String::from("27Kb"),
-            value: 27,
-            unit: Unit::Kilobyte,
-        },
-        TestCase {
-            string: String::from("11Mb"),
-            value: 11,
-            unit: Unit::Megabyte,
-        },
-        TestCase {
-            string: String::from("27mB"),
-            value: 27,
-            unit: Unit::Megabyte,
-        },
-        TestCase {
-            string: String::from("811Gb"),
-            value: 811,
-            unit: Unit::Gigabyte,
-        },
-        TestCase {
-            string: String::from("27gB"),
-            value: 27,
-            unit: Unit::Gigabyte,
-        },
-        TestCase {
-            string: String::from("11Tb"),
-            value: 11,
-            unit: Unit::Terabyte,
-        },
-        TestCase {
-            string: String::from("1027tB"),
-            value: 1027,
-            unit: Unit::Terabyte,
-        },
-        TestCase {
-            string: String::from("11Pb"),
-            value: 11,
-            unit: Unit::Petabyte,
-        },
-        TestCase {
-            string: String::from("27pB"),
-            value: 27,
-            unit: Unit::Petabyte,
-        },
-        TestCase {
-            string: String::from("10kib"),
-            value: 10,
-            unit: Unit::Kibibyte,
-        },
-        TestCase {
-            string: String::from("123KiB"),
-            value: 123,
-            unit: Unit::Kibibyte,
-        },
-        TestCase {
-            string: String::from("24kiB"),
-            value: 24,
-            unit: Unit::Kibibyte,
-        },
-        TestCase {
-            string: String::from("10mib"),
-            value: 10,
-            unit: Unit::Mebibyte,
-        },
-        TestCase {
-            string: String::from("123MiB"),
-            value: 123,
-            unit: Unit::Mebibyte,
-        },
-        TestCase {
-            string: String::from("10gib"),
-            value: 10,
-            unit: Unit::Gibibyte,
-        },
-        TestCase {
-            string: String::from("123GiB"),
-            value: 123,
-            unit: Unit::Gibibyte,
-        },
-    ];
-
-    for case in cases.iter() {
-        let input_len = case.string.len();
-        let value_len = case.value.to_string().len();
-        let input = case.string.clone().spanned(Span::new(0, input_len));
-        let result = parse_unit(&input);
-        assert_eq!(result.1, None);
+    /// Lexes and lite-parses the source, which is expected to go through without errors
+    fn lite_parse(src: &str) -> LiteBlock {
+        let (tokens, err) = lex(src, 0);
+        assert!(err.is_none());
+        let (lite_block, err) = parse_block(tokens);
+        assert!(err.is_none());
+        lite_block
+    }
+
+    /// Parses a math expression and flattens it down to the operators, grouped by how they
+    /// bind, so `1 + 2 * 3` becomes `(1 + (2 * 3))`
+    fn math_shape(src: &str) -> String {
+        fn shape(expr: &SpannedExpression, src: &str) -> String {
+            match &expr.expr {
+                Expression::Binary(binary) => format!(
+                    "({} {} {})",
+                    shape(&binary.left, src),
+                    shape(&binary.op, src),
+                    shape(&binary.right, src)
+                ),
+                Expression::Not(expr) => format!("(not {})", shape(expr, src)),
+                Expression::Literal(hir::Literal::Operator(_)) => expr.span.slice(src).to_string(),
+                _ => "_".to_string(),
+            }
+        }
+
+        let lite_block = lite_parse(src);
+        let parts = lite_block.block[0].pipelines[0].commands[0].parts.clone();
+
+        let (_, expr, err) = parse_math_expression(0, &parts, &EmptyScope, true);
+        assert_eq!(err, None);
+
+        shape(&expr, src)
+    }
+
+    #[test]
+    fn unit_parse_escaped_strings() {
+        let cases = [
+            (r#""foo""#, "foo"),
+            (r#""a\tb""#, "a\tb"),
+            (r#""line\n""#, "line\n"),
+            (r#""say \"hi\"""#, "say \"hi\""),
+            (r#""back\\slash""#, "back\\slash"),
+            (r#""\u{1F600}""#, "\u{1F600}"),
+            (r#""\d+\.\w""#, "\\d+\\.\\w"),
+            (r#""..\.cargo""#, "..\\.cargo"),
+        ];
+
+        for (input, expected) in cases.iter() {
+            let arg = input.to_string().spanned(Span::new(0, input.len()));
+            assert_eq!(unescape_string(&arg), Ok(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn unit_parse_malformed_unicode_escape() {
+        let input = r#""ok \u{ZZ} ok""#;
+        let arg = input.to_string().spanned(Span::new(10, 10 + input.len()));
+
+        assert_eq!(
+            unescape_string(&arg),
+            Err(ParseError::mismatch(
+                "unicode escape of the form \\u{XXXX}",
+                "\\u{ZZ}".to_string().spanned(Span::new(14, 20))
+            ))
+        );
+    }
+
+    #[test]
+    fn unit_parse_units() {
+        struct TestCase {
+            string: String,
+            value: i64,
+            unit: Unit,
+        }
+
+        let cases = [
+            TestCase {
+                string: String::from("108b"),
+                value: 108,
+                unit: Unit::Byte,
+            },
+            TestCase {
+                string: String::from("0B"),
+                value: 0,
+                unit: Unit::Byte,
+            },
+            TestCase {
+                string: String::from("10kb"),
+                value: 10,
+                unit: Unit::Kilobyte,
+            },
+            TestCase {
+                string: String::from("16KB"),
+                value: 16,
+                unit: Unit::Kilobyte,
+            },
+            TestCase {
+                string: String::from("99kB"),
+                value: 99,
+                unit: Unit::Kilobyte,
+            },
+            TestCase {
+                string: String::from("27Kb"),
+                value: 27,
+                unit: Unit::Kilobyte,
+            },
+            TestCase {
+                string: String::from("11Mb"),
+                value: 11,
+                unit: Unit::Megabyte,
+            },
+            TestCase {
+                string: String::from("27mB"),
+                value: 27,
+                unit: Unit::Megabyte,
+            },
+            TestCase {
+                string: String::from("811Gb"),
+                value: 811,
+                unit: Unit::Gigabyte,
+            },
+            TestCase {
+                string: String::from("27gB"),
+                value: 27,
+                unit: Unit::Gigabyte,
+            },
+            TestCase {
+                string: String::from("11Tb"),
+                value: 11,
+                unit: Unit::Terabyte,
+            },
+            TestCase {
+                string: String::from("1027tB"),
+                value: 1027,
+                unit: Unit::Terabyte,
+            },
+            TestCase {
+                string: String::from("11Pb"),
+                value: 11,
+                unit: Unit::Petabyte,
+            },
+            TestCase {
+                string: String::from("27pB"),
+                value: 27,
+                unit: Unit::Petabyte,
+            },
+            TestCase {
+                string: String::from("10kib"),
+                value: 10,
+                unit: Unit::Kibibyte,
+            },
+            TestCase {
+                string: String::from("123KiB"),
+                value: 123,
+                unit: Unit::Kibibyte,
+            },
+            TestCase {
+                string: String::from("24kiB"),
+                value: 24,
+                unit: Unit::Kibibyte,
+            },
+            TestCase {
+                string: String::from("10mib"),
+                value: 10,
+                unit: Unit::Mebibyte,
+            },
+            TestCase {
+                string: String::from("123MiB"),
+                value: 123,
+                unit: Unit::Mebibyte,
+            },
+            TestCase {
+                string: String::from("10gib"),
+                value: 10,
+                unit: Unit::Gibibyte,
+            },
+            TestCase {
+                string: String::from("123GiB"),
+                value: 123,
+                unit: Unit::Gibibyte,
+            },
+            TestCase {
+                string: String::from("10KIB"),
+                value: 10,
+                unit: Unit::Kibibyte,
+            },
+            TestCase {
+                string: String::from("5mIb"),
+                value: 5,
+                unit: Unit::Mebibyte,
+            },
+            TestCase {
+                string: String::from("30s"),
+                value: 30,
+                unit: Unit::Second,
+            },
+            TestCase {
+                string: String::from("10ms"),
+                value: 10,
+                unit: Unit::Millisecond,
+            },
+            TestCase {
+                string: String::from("2d"),
+                value: 2,
+                unit: Unit::Day,
+            },
+        ];
+
+        for case in cases.iter() {
+            let input_len = case.string.len();
+            let value_len = case.value.to_string().len();
+            let input = case.string.clone().spanned(Span::new(0, input_len));
+            let result = parse_unit(&input);
+            assert_eq!(result.1, None);
+            assert_eq!(
+                result.0.expr,
+                Expression::unit(
+                    Spanned {
+                        span: Span::new(0, value_len),
+                        item: case.value
+                    },
+                    Spanned {
+                        span: Span::new(value_len, input_len),
+                        item: case.unit
+                    }
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn unit_parse_durations_are_case_sensitive() {
+        for input in ["10M", "10S", "2D", "10MS", "3Hr"].iter() {
+            let input = input.to_string().spanned(Span::new(0, input.len()));
+            let (_, err) = parse_unit(&input);
+
+            assert!(err.is_some(), "{} should not be a duration", input.item);
+        }
+
+        let (result, err) = parse_unit(&"10m".to_string().spanned(Span::new(0, 3)));
+        assert_eq!(err, None);
         assert_eq!(
-            result.0.expr,
+            result.expr,
             Expression::unit(
-                Spanned {
-                    span: Span::new(0, value_len),
-                    item: case.value
-                },
-                Spanned {
-                    span: Span::new(value_len, input_len),
-                    item: case.unit
-                }
+                10.spanned(Span::new(0, 2)),
+                Unit::Minute.spanned(Span::new(2, 3))
             )
         );
     }
+
+    #[test]
+    fn unit_negative_numbers_are_not_flags() {
+        assert!(is_flag("-r"));
+        assert!(is_flag("--raw"));
+        assert!(!is_flag("-"));
+        assert!(!is_flag("-1"));
+        assert!(!is_flag("-1.5"));
+        assert!(!is_flag("-10kb"));
+    }
+
+    #[test]
+    fn unit_parse_decimal_units() {
+        let input = "1.5KB".to_string().spanned(Span::new(0, 5));
+        let (result, err) = parse_unit(&input);
+
+        assert_eq!(err, None);
+        assert_eq!(
+            result.expr,
+            Expression::decimal_unit(
+                "1.5"
+                    .parse::<BigDecimal>()
+                    .expect("a valid decimal")
+                    .spanned(Span::new(0, 3)),
+                Unit::Kilobyte.spanned(Span::new(3, 5))
+            )
+        );
+    }
+
+    #[test]
+    fn unit_dangling_decimal_point_is_an_error() {
+        let input = "1.".to_string().spanned(Span::new(10, 12));
+
+        assert_eq!(
+            dangling_decimal_point(&input),
+            Some(ParseError::mismatch(
+                "a digit after the decimal point",
+                ".".to_string().spanned(Span::new(11, 12))
+            ))
+        );
+        assert_eq!(
+            dangling_decimal_point(&"1.5".to_string().spanned(Span::new(0, 3))),
+            None
+        );
+    }
+
+    #[test]
+    fn unit_parse_unknown_unit_suffix() {
+        let input = "1XB".to_string().spanned(Span::new(10, 13));
+        let (_, err) = parse_unit(&input);
+
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::Mismatch { expected, actual }) => {
+                assert!(expected.contains("kb, mb"));
+                assert_eq!(actual, "XB".to_string().spanned(Span::new(11, 13)));
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_math_precedence() {
+        assert_eq!(math_shape("1 + 2 * 3"), "(_ + (_ * _))");
+        assert_eq!(
+            math_shape("size > 1KB && size < 1MB"),
+            "((_ > _) && (_ < _))"
+        );
+        assert_eq!(
+            math_shape("a == 1 || b == 2 && c == 3"),
+            "((_ == _) || ((_ == _) && (_ == _)))"
+        );
+        assert_eq!(math_shape("size > 2 * 1KB"), "(_ > (_ * _))");
+        assert_eq!(math_shape("7 - 4 % 3 + 1"), "((_ - (_ % _)) + _)");
+        assert_eq!(math_shape("8 / 2 mod 3"), "((_ / _) mod _)");
+    }
+
+    #[test]
+    fn unit_parse_shorthand_keeps_numbers_in_arithmetic() {
+        let src = "size > 2 * 1kb";
+        let lite_block = lite_parse(src);
+        let parts = &lite_block.block[0].pipelines[0].commands[0].parts;
+        let (_, expr, err) = parse_math_expression(0, parts, &EmptyScope, true);
+        assert_eq!(err, None);
+
+        match &expr.expr {
+            Expression::Binary(comparison) => {
+                assert!(matches!(comparison.left.expr, Expression::Path(_)));
+                match &comparison.right.expr {
+                    Expression::Binary(product) => assert!(matches!(
+                        product.left.expr,
+                        Expression::Literal(hir::Literal::Number(_))
+                    )),
+                    other => panic!("expected a product, got {:?}", other),
+                }
+            }
+            other => panic!("expected a comparison, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_math_parentheses() {
+        assert_eq!(math_shape("(1 + 2) * 3"), "((_ + _) * _)");
+        assert_eq!(
+            math_shape("(size > 1KB) && (name != \"target\")"),
+            "((_ > _) && (_ != _))"
+        );
+        assert_eq!(
+            math_shape("(a == 1 || b == 2) && c == 3"),
+            "(((_ == _) || (_ == _)) && (_ == _))"
+        );
+    }
+
+    #[test]
+    fn unit_parse_not() {
+        assert_eq!(math_shape("not (name == \"target\")"), "(not (_ == _))");
+        assert_eq!(
+            math_shape("not a == 1 && b == 2"),
+            "((not (_ == _)) && (_ == _))"
+        );
+        assert_eq!(
+            math_shape("not (a == 1 && b == 2)"),
+            "(not ((_ == _) && (_ == _)))"
+        );
+        assert_eq!(math_shape("(not a) || b"), "((not _) || _)");
+        assert_eq!(math_shape("not == 1"), "(_ == _)");
+        assert_eq!(math_shape("a && not b"), "(_ && (not _))");
+        assert_eq!(
+            math_shape("a == 1 || not b == 2 && c"),
+            "((_ == _) || ((not (_ == _)) && _))"
+        );
+        assert_eq!(math_shape("a && not b || c"), "((_ && (not _)) || _)");
+        assert_eq!(math_shape("not not a && b"), "((not (not _)) && _)");
+        assert_eq!(math_shape("a == not"), "(_ == _)");
+    }
+
+    #[test]
+    fn unit_parse_ranges_and_dotted_paths() {
+        fn parse_any(src: &str) -> Expression {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(SyntaxShape::Any, &EmptyScope, &arg);
+            assert_eq!(err, None);
+            expr.expr
+        }
+
+        assert!(matches!(parse_any("1..10"), Expression::Range(_)));
+        assert!(matches!(parse_any("1..<10"), Expression::Range(_)));
+        assert!(matches!(parse_any("$start..10"), Expression::Range(_)));
+
+        for path in &["..\\.cargo\\", "../foo", "a..b"] {
+            assert_eq!(
+                parse_any(path),
+                Expression::Literal(hir::Literal::String(path.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn unit_parse_quoted_column_path_members() {
+        fn members(src: &str) -> Vec<String> {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_simple_column_path(&arg);
+            assert_eq!(err, None);
+
+            match expr.expr {
+                Expression::Literal(hir::Literal::ColumnPath(members)) => members
+                    .iter()
+                    .map(|member| match member {
+                        Member::Bare(bare) => bare.item.clone(),
+                        other => panic!("expected a bare member, got {:?}", other),
+                    })
+                    .collect(),
+                other => panic!("expected a column path, got {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            members("dependencies.\"serde json\""),
+            vec!["dependencies", "serde json"]
+        );
+        assert_eq!(members("\"my table\".count"), vec!["my table", "count"]);
+        assert_eq!(members("a.'b.c'.d"), vec!["a", "b.c", "d"]);
+    }
+
+    #[test]
+    fn unit_parse_comparison_operators() {
+        let cases = [
+            ("==", Operator::Equal),
+            ("!=", Operator::NotEqual),
+            ("<", Operator::LessThan),
+            ("<=", Operator::LessThanOrEqual),
+            (">", Operator::GreaterThan),
+            (">=", Operator::GreaterThanOrEqual),
+        ];
+
+        for (src, operator) in cases.iter() {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_operator(&arg);
+
+            assert_eq!(err, None);
+            assert_eq!(expr.expr, Expression::operator(*operator));
+        }
+
+        let (_, err) = parse_operator(&"=>".to_string().spanned(Span::new(0, 2)));
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn unit_parse_list_literals() {
+        fn parse_list_literal(src: &str) -> Vec<Expression> {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(SyntaxShape::Any, &EmptyScope, &arg);
+            assert_eq!(err, None);
+
+            match expr.expr {
+                Expression::List(items) => items.into_iter().map(|item| item.expr).collect(),
+                other => panic!("expected a list, got {:?}", other),
+            }
+        }
+
+        assert_eq!(parse_list_literal("[]"), vec![]);
+        assert_eq!(parse_list_literal("[ ]"), vec![]);
+
+        let items = parse_list_literal("[1 \"a b\" name [x [y]]]");
+        assert_eq!(items.len(), 4);
+        assert_eq!(items[0], Expression::integer(1.into()));
+        assert_eq!(items[1], Expression::string("a b".into()));
+        assert_eq!(items[2], Expression::string("name".into()));
+        match &items[3] {
+            Expression::List(nested) => {
+                assert_eq!(nested.len(), 2);
+                assert!(matches!(nested[1].expr, Expression::List(_)));
+            }
+            other => panic!("expected a nested list, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_membership_operators() {
+        assert_eq!(math_shape("type in [Dir Symlink]"), "(_ in _)");
+        assert_eq!(math_shape("type not-in [Dir]"), "(_ not-in _)");
+        assert_eq!(
+            math_shape("type in [Dir] && size > 1KB"),
+            "((_ in _) && (_ > _))"
+        );
+        assert_eq!(math_shape("in == in"), "(_ == _)");
+    }
+
+    #[test]
+    fn unit_parse_regex_operands() {
+        fn operator_and_operand(src: &str) -> (Expression, Expression, Option<ParseError>) {
+            let lite_block = lite_parse(src);
+            let parts = lite_block.block[0].pipelines[0].commands[0].parts.clone();
+
+            let (_, expr, err) = parse_math_expression(0, &parts, &EmptyScope, true);
+
+            match expr.expr {
+                Expression::Binary(binary) => (binary.op.expr, binary.right.expr, err),
+                other => panic!("expected a comparison, got {:?}", other),
+            }
+        }
+
+        let (op, operand, err) = operator_and_operand(r"name =~ r'test_\d+'");
+        assert_eq!(err, None);
+        assert_eq!(op, Expression::operator(Operator::Matches));
+        assert_eq!(operand, Expression::string(r"test_\d+".into()));
+
+        let (op, operand, err) = operator_and_operand(r#"name !~ r"^test notes""#);
+        assert_eq!(err, None);
+        assert_eq!(op, Expression::operator(Operator::NotMatches));
+        assert_eq!(operand, Expression::string("^test notes".into()));
+
+        let (op, _, _) = operator_and_operand("name =~ test");
+        assert_eq!(op, Expression::operator(Operator::Contains));
+
+        let (op, operand, err) = operator_and_operand("name =~ /tmp/");
+        assert_eq!(err, None);
+        assert_eq!(op, Expression::operator(Operator::Contains));
+        assert_eq!(operand, Expression::string("/tmp/".into()));
+
+        let (_, _, err) = operator_and_operand("name =~ r'*test'");
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn unit_parse_conditions_inside_blocks() {
+        fn condition(src: &str) -> Expression {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(SyntaxShape::RowCondition, &EmptyScope, &arg);
+            assert_eq!(err, None);
+            assert_eq!(expr.span, Span::new(0, src.len()));
+
+            match expr.expr {
+                Expression::Block(block) => match &block.block[0].pipelines[0].list[..] {
+                    [ClassifiedCommand::Expr(expr)] => expr.expr.clone(),
+                    other => panic!("expected a single expression, got {:?}", other),
+                },
+                other => panic!("expected a block, got {:?}", other),
+            }
+        }
+
+        assert!(matches!(
+            condition("{ $it.size > 1kb }"),
+            Expression::Binary(_)
+        ));
+        assert!(matches!(condition("{size > 1kb}"), Expression::Binary(_)));
+        assert!(matches!(
+            condition("{ not $it.hidden }"),
+            Expression::Not(_)
+        ));
+    }
+
+    #[test]
+    fn unit_parse_redirections() {
+        fn redirect(src: &str) -> Vec<Vec<String>> {
+            let lite_block = lite_parse(src);
+
+            let (pipeline, err) =
+                expand_redirection(&lite_block.block[0].pipelines[0], &EmptyScope);
+            assert_eq!(err, None);
+
+            pipeline
+                .commands
+                .iter()
+                .map(|command| command.parts.iter().map(|part| part.item.clone()).collect())
+                .collect()
+        }
+
+        assert_eq!(
+            redirect("ls > files.txt"),
+            vec![vec!["ls"], vec!["save", "files.txt"]]
+        );
+        assert_eq!(
+            redirect("ls | get name >> names.txt"),
+            vec![
+                vec!["ls"],
+                vec!["get", "name"],
+                vec!["save", "--append", "names.txt"]
+            ]
+        );
+        assert_eq!(redirect("= 1 > 2"), vec![vec!["=", "1", ">", "2"]]);
+        assert_eq!(
+            redirect("= 4 > 3 && 2 > 1"),
+            vec![vec!["=", "4", ">", "3", "&&", "2", ">", "1"]]
+        );
+        assert_eq!(
+            redirect("= 1 + 2 >> out.txt"),
+            vec![
+                vec!["=", "1", "+", "2"],
+                vec!["save", "--append", "out.txt"]
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_parse_caret_external_passes_arguments_through() {
+        let src = "^git status --short \"a b\"";
+        let lite_block = lite_parse(src);
+        let (block, err) = classify_block(&lite_block, &EmptyScope);
+        assert_eq!(err, None);
+
+        match &block.block[0].pipelines[0].list[..] {
+            [ClassifiedCommand::Internal(command)] => {
+                assert_eq!(command.name, "run_external");
+                assert!(command.args.named.is_none());
+
+                let args: Vec<_> = command
+                    .args
+                    .positional
+                    .iter()
+                    .flatten()
+                    .map(|arg| arg.expr.clone())
+                    .collect();
+                assert_eq!(
+                    args,
+                    vec![
+                        Expression::string("git".into()),
+                        Expression::string("status".into()),
+                        Expression::string("--short".into()),
+                        Expression::string("\"a b\"".into()),
+                    ]
+                );
+            }
+            other => panic!("expected an external command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_let_diagnostics() {
+        fn let_error(src: &str) -> Option<ParseError> {
+            let lite_block = lite_parse(src);
+            let (_, err) = classify_block(&lite_block, &EmptyScope);
+            err
+        }
+
+        assert_eq!(let_error("let x = 3"), None);
+        assert_eq!(
+            let_error("let x"),
+            Some(ParseError::unexpected_eof(
+                "'=' after the variable name",
+                Span::new(4, 5)
+            ))
+        );
+        assert_eq!(
+            let_error("let x 3"),
+            Some(ParseError::mismatch(
+                "=",
+                "3".to_string().spanned(Span::new(6, 7))
+            ))
+        );
+        assert_eq!(
+            let_error("let x ="),
+            Some(ParseError::unexpected_eof(
+                "a value after '='",
+                Span::new(6, 7)
+            ))
+        );
+    }
+
+    #[test]
+    fn unit_parse_parenthesized_arguments_are_substitutions() {
+        for src in &["(ls | count)", "$(ls | count)"] {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(SyntaxShape::Any, &EmptyScope, &arg);
+
+            assert_eq!(err, None);
+            assert!(matches!(expr.expr, Expression::Invocation(_)));
+        }
+
+        assert_eq!(math_shape("(1 + 2) * 3"), "((_ + _) * _)");
+    }
+
+    #[test]
+    fn unit_parse_windows_paths() {
+        let paths = [
+            r"C:\Users\me",
+            r"C:\Users\me\",
+            "C:/Users/me/",
+            r"\\server\share\file.toml",
+            r"\\server\share\",
+        ];
+
+        for path in paths.iter() {
+            let src = format!("open {} --raw", path);
+            let lite_block = lite_parse(&src);
+
+            let arg = &lite_block.block[0].pipelines[0].commands[0].parts[1];
+            assert_eq!(arg.item, *path);
+            assert_eq!(arg.span.slice(&src), *path);
+
+            let (expr, err) = parse_arg(SyntaxShape::Any, &EmptyScope, arg);
+            assert_eq!(err, None);
+            assert_eq!(expr.expr, Expression::string(path.to_string()));
+
+            let (expr, err) = parse_arg(SyntaxShape::FilePath, &EmptyScope, arg);
+            assert_eq!(err, None);
+            assert_eq!(
+                expr.expr,
+                Expression::FilePath(std::path::PathBuf::from(path))
+            );
+        }
+    }
+
+    #[test]
+    fn unit_parse_tilde_paths() {
+        fn path(src: &str) -> (Expression, Option<ParseError>) {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(SyntaxShape::FilePath, &EmptyScope, &arg);
+            (expr.expr, err)
+        }
+
+        for src in &["~", "~/projects/Cargo.toml"] {
+            match path(src) {
+                (Expression::FilePath(path), None) => assert!(!path.starts_with("~"), "{}", src),
+                other => panic!("expected an expanded path, got {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            path("\"~/projects\""),
+            (
+                Expression::FilePath(std::path::PathBuf::from("~/projects")),
+                None
+            )
+        );
+
+        match path("~alice/notes") {
+            (_, Some(err)) => match err.reason() {
+                nu_errors::ParseErrorReason::Mismatch { actual, .. } => {
+                    assert_eq!(actual.item, "~alice/notes")
+                }
+                other => panic!("expected a mismatch, got {:?}", other),
+            },
+            other => panic!("expected an error, got {:?}", other),
+        }
+        assert!(path("~alice").1.is_some());
+
+        for src in &["~foo.txt", "~foo.txt/bar", "~$x"] {
+            assert_eq!(
+                path(src),
+                (Expression::FilePath(std::path::PathBuf::from(src)), None)
+            );
+        }
+    }
+
+    #[test]
+    fn unit_parse_flags_with_attached_values() {
+        use nu_protocol::hir::NamedValue;
+
+        let signature = Signature::build("log")
+            .named(
+                "level",
+                SyntaxShape::String,
+                "the level to log at",
+                Some('l'),
+            )
+            .switch("verbose", "log everything", Some('v'));
+
+        fn parse_log(src: &str, signature: &Signature) -> (InternalCommand, Option<ParseError>) {
+            let lite_block = lite_parse(src);
+
+            let lite_cmd = &lite_block.block[0].pipelines[0].commands[0];
+            parse_internal_command(lite_cmd, &EmptyScope, signature, 0)
+        }
+
+        fn level(command: &InternalCommand) -> Expression {
+            match command
+                .args
+                .named
+                .as_ref()
+                .and_then(|named| named.get("level"))
+            {
+                Some(NamedValue::Value(_, expr)) => expr.expr.clone(),
+                other => panic!("expected a value for --level, got {:?}", other),
+            }
+        }
+
+        let (attached, err) = parse_log("log --level=debug", &signature);
+        assert_eq!(err, None);
+        let (separate, err) = parse_log("log --level debug", &signature);
+        assert_eq!(err, None);
+
+        assert_eq!(level(&attached), Expression::string("debug".into()));
+        assert_eq!(level(&attached), level(&separate));
+
+        let (_, err) = parse_log("log --color=red", &signature);
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::ArgumentError {
+                error: ArgumentError::UnexpectedFlag(flag),
+                ..
+            }) => {
+                assert_eq!(flag.item, "--color");
+                assert_eq!(flag.span, Span::new(4, 11));
+            }
+            other => panic!("expected an unexpected flag, got {:?}", other),
+        }
+
+        let (_, err) = parse_log("log --verbose=yes", &signature);
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn unit_parse_grouped_short_flags() {
+        let signature = Signature::build("ls")
+            .switch("all", "show hidden files", Some('a'))
+            .switch("long", "show all details", Some('l'))
+            .named("depth", SyntaxShape::Int, "how deep to go", Some('d'));
+        let cmd = "ls".to_string().spanned(Span::new(0, 2));
+        let flag = |src: &str| src.to_string().spanned(Span::new(3, 3 + src.len()));
+
+        let (flags, err) = get_flags_from_flag(&signature, &cmd, &flag("-la"));
+        assert_eq!(err, None);
+        let names: Vec<_> = flags.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["long", "all"]);
+
+        let (_, err) = get_flags_from_flag(&signature, &cmd, &flag("-d"));
+        assert_eq!(err, None);
+
+        let (_, err) = get_flags_from_flag(&signature, &cmd, &flag("-lza"));
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::ArgumentError {
+                error: ArgumentError::UnexpectedFlag(flag),
+                ..
+            }) => assert_eq!(flag.span, Span::new(5, 6)),
+            other => panic!("expected an unexpected flag, got {:?}", other),
+        }
+
+        let (_, err) = get_flags_from_flag(&signature, &cmd, &flag("-ad"));
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::Mismatch { actual, .. }) => {
+                assert_eq!(actual.item, "d");
+                assert_eq!(actual.span, Span::new(5, 6));
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_partial_recovers_at_pipes() {
+        fn commands(block: &Block) -> Vec<String> {
+            block
+                .block
+                .iter()
+                .flat_map(|group| group.pipelines.iter())
+                .flat_map(|pipeline| pipeline.list.iter())
+                .map(|command| match command {
+                    ClassifiedCommand::Internal(command) => command
+                        .args
+                        .positional
+                        .iter()
+                        .flatten()
+                        .next()
+                        .map(|arg| match &arg.expr {
+                            Expression::Literal(hir::Literal::String(name)) => name.clone(),
+                            other => panic!("expected a command name, got {:?}", other),
+                        })
+                        .unwrap_or_else(|| command.name.clone()),
+                    other => panic!("expected a command, got {:?}", other),
+                })
+                .collect()
+        }
+
+        let (block, errors) = parse_partial("open Cargo.toml | sel", 0, &EmptyScope);
+        assert_eq!(commands(&block), vec!["open", "sel"]);
+        assert!(errors.is_empty());
+
+        let (block, errors) = parse_partial("open Cargo.toml | select \"na", 0, &EmptyScope);
+        assert_eq!(commands(&block), vec!["open", "select"]);
+        match &errors[..] {
+            [err] => assert!(matches!(
+                err.reason(),
+                nu_errors::ParseErrorReason::Unclosed { .. }
+            )),
+            other => panic!("expected one unclosed string, got {:?}", other),
+        }
+
+        let (block, errors) = parse_partial("open Cargo.toml | | sel", 0, &EmptyScope);
+        assert_eq!(commands(&block), vec!["open", "sel"]);
+        match &errors[..] {
+            [err] => match err.reason() {
+                nu_errors::ParseErrorReason::ExtraTokens { actual } => {
+                    assert_eq!(actual.span, Span::new(18, 19))
+                }
+                other => panic!("expected a stray pipe, got {:?}", other),
+            },
+            other => panic!("expected one error, got {:?}", other),
+        }
+
+        let (block, errors) = parse_partial("open Cargo.toml | ", 0, &EmptyScope);
+        assert_eq!(commands(&block), vec!["open"]);
+        assert_eq!(errors.len(), 1);
+
+        let (block, err) = parse("open Cargo.toml | | sel", 0, &EmptyScope);
+        assert!(block.block.is_empty());
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn unit_parse_spans_cover_source_text() {
+        use nu_protocol::hir::NamedValue;
+
+        let signature = Signature::build("log")
+            .named(
+                "level",
+                SyntaxShape::String,
+                "the level to log at",
+                Some('l'),
+            )
+            .switch("verbose", "log everything", Some('v'))
+            .optional("message", SyntaxShape::String, "the message to log");
+
+        let src = "log --level debug -v \"a b\"";
+        let lite_block = lite_parse(src);
+        let lite_cmd = &lite_block.block[0].pipelines[0].commands[0];
+        let (command, err) = parse_internal_command(lite_cmd, &EmptyScope, &signature, 0);
+        assert_eq!(err, None);
+
+        assert_eq!(command.name_span.slice(src), "log");
+        assert_eq!(command.args.head.span.slice(src), "log");
+        assert_eq!(command.args.span.slice(src), src);
+
+        let named = command.args.named.as_ref().expect("named arguments");
+        match named.get("level") {
+            Some(NamedValue::Value(flag, value)) => {
+                assert_eq!(flag.slice(src), "--level");
+                assert_eq!(value.span.slice(src), "debug");
+            }
+            other => panic!("expected a value for --level, got {:?}", other),
+        }
+        match named.get("verbose") {
+            Some(NamedValue::PresentSwitch(flag)) => assert_eq!(flag.slice(src), "-v"),
+            other => panic!("expected --verbose to be present, got {:?}", other),
+        }
+
+        let positional = command
+            .args
+            .positional
+            .as_ref()
+            .expect("positional arguments");
+        assert_eq!(positional[0].span.slice(src), "\"a b\"");
+
+        let src = "size < \"foo\"";
+        let lite_block = lite_parse(src);
+        let parts = &lite_block.block[0].pipelines[0].commands[0].parts;
+        let (_, expr, err) = parse_math_expression(0, parts, &EmptyScope, false);
+        assert_eq!(err, None);
+
+        assert_eq!(expr.span.slice(src), src);
+        match &expr.expr {
+            Expression::Binary(binary) => {
+                assert_eq!(binary.left.span.slice(src), "size");
+                assert_eq!(binary.op.span.slice(src), "<");
+                assert_eq!(binary.right.span.slice(src), "\"foo\"");
+            }
+            other => panic!("expected a comparison, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_boolean_literals() {
+        fn parse_as(shape: SyntaxShape, src: &str) -> Expression {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(shape, &EmptyScope, &arg);
+            assert_eq!(err, None);
+            expr.expr
+        }
+
+        assert_eq!(
+            parse_as(SyntaxShape::Any, "true"),
+            Expression::boolean(true)
+        );
+        assert_eq!(
+            parse_as(SyntaxShape::Any, "false"),
+            Expression::boolean(false)
+        );
+        assert_eq!(
+            parse_as(SyntaxShape::Any, "\"true\""),
+            Expression::string("true".into())
+        );
+        assert_eq!(
+            parse_as(SyntaxShape::String, "true"),
+            Expression::string("true".into())
+        );
+        assert_eq!(
+            parse_as(SyntaxShape::FilePath, "true"),
+            Expression::FilePath(std::path::PathBuf::from("true"))
+        );
+        assert_eq!(math_shape("enabled == true || false"), "((_ == _) || _)");
+    }
+
+    #[test]
+    fn unit_parse_radix_integers() {
+        fn parse_as(shape: SyntaxShape, src: &str) -> (Expression, Option<ParseError>) {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(shape, &EmptyScope, &arg);
+            (expr.expr, err)
+        }
+
+        let cases = [
+            ("0x1F", 31),
+            ("0xff", 255),
+            ("0o755", 493),
+            ("0b1010", 10),
+            ("-0x10", -16),
+        ];
+        for (src, expected) in cases.iter() {
+            for shape in &[SyntaxShape::Any, SyntaxShape::Int, SyntaxShape::Number] {
+                assert_eq!(
+                    parse_as(*shape, src),
+                    (Expression::integer(BigInt::from(*expected)), None),
+                    "{}",
+                    src
+                );
+            }
+        }
+
+        for (src, bad_digit) in &[("0o9", Span::new(2, 3)), ("0b1012", Span::new(5, 6))] {
+            match parse_as(SyntaxShape::Any, src)
+                .1
+                .map(|err| err.reason().clone())
+            {
+                Some(nu_errors::ParseErrorReason::Mismatch { actual, .. }) => {
+                    assert_eq!(actual.span, *bad_digit, "{}", src)
+                }
+                other => panic!("expected a mismatch, got {:?}", other),
+            }
+        }
+
+        match parse_as(SyntaxShape::Any, "0x")
+            .1
+            .map(|err| err.reason().clone())
+        {
+            Some(nu_errors::ParseErrorReason::Mismatch { expected, actual }) => {
+                assert_eq!(expected, "digits after '0x'");
+                assert_eq!(actual.span, Span::new(0, 2));
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+
+        for src in &["0b", "0B"] {
+            assert_eq!(
+                parse_as(SyntaxShape::Any, src),
+                (
+                    Expression::unit(
+                        0.spanned(Span::new(0, 1)),
+                        Unit::Byte.spanned(Span::new(1, 2))
+                    ),
+                    None
+                ),
+                "{}",
+                src
+            );
+        }
+
+        assert_eq!(
+            parse_as(SyntaxShape::String, "0x1F"),
+            (Expression::string("0x1F".into()), None)
+        );
+    }
+
+    #[test]
+    fn unit_parse_date_literals() {
+        use chrono::{FixedOffset, TimeZone};
+        use nu_source::PrettyDebugWithSource;
+
+        fn parse_as(shape: SyntaxShape, src: &str) -> SpannedExpression {
+            let arg = src.to_string().spanned(Span::new(0, src.len()));
+            let (expr, err) = parse_arg(shape, &EmptyScope, &arg);
+            assert!(err.is_none(), "{}", src);
+            expr
+        }
+
+        let utc = FixedOffset::east(0);
+        let cases = [
+            (
+                "2019-06-01T12:00:00Z",
+                utc.ymd(2019, 6, 1).and_hms(12, 0, 0),
+            ),
+            ("2019-06-01T12:00Z", utc.ymd(2019, 6, 1).and_hms(12, 0, 0)),
+            (
+                "2019-06-01T12:00:00.5+02:00",
+                FixedOffset::east(2 * 3600)
+                    .ymd(2019, 6, 1)
+                    .and_hms_milli(12, 0, 0, 500),
+            ),
+        ];
+        for (src, expected) in cases.iter() {
+            assert_eq!(
+                parse_as(SyntaxShape::Any, src).expr,
+                Expression::date(*expected),
+                "{}",
+                src
+            );
+        }
+
+        // Without an offset, dates are local
+        for src in &["2019-06-01", "2019-06-01T12:00", "2019-06-01T12:00:00"] {
+            let expr = parse_as(SyntaxShape::Any, src);
+            assert!(
+                matches!(expr.expr, Expression::Literal(hir::Literal::Date(_))),
+                "{}",
+                src
+            );
+            assert!(expr.debug(*src).contains(src), "{}", src);
+        }
+
+        // Words that only look like dates, and dates where a string or path is expected, are
+        // left as they are
+        for src in &[
+            "2019-06-01-notes.txt",
+            "2019-13-01",
+            "2019-06-01T25:00",
+            "2019-06-0é",
+            "2019-06-0é+",
+        ] {
+            assert_eq!(
+                parse_as(SyntaxShape::Any, src).expr,
+                Expression::string(src.to_string()),
+            );
+        }
+        assert_eq!(
+            parse_as(SyntaxShape::String, "2019-06-01").expr,
+            Expression::string("2019-06-01".into())
+        );
+        assert!(matches!(
+            parse_as(SyntaxShape::FilePath, "2019-06-01").expr,
+            Expression::FilePath(_)
+        ));
+    }
+
+    #[test]
+    fn unit_parse_prints_lines_as_written() {
+        let corpus = [
+            "ls",
+            "ls | where size > 10kb | get name",
+            "echo \"hello\" 'world' `a{{$it}}b`",
+            "echo \"bare\" 'quoted' words",
+            "open Cargo.toml | get package.version",
+            "echo [1 2 3] | each { echo $it }",
+            "echo [[name size]; [a 1]] | where name == \"a\"",
+            "echo $(ls | count) (echo 1)",
+            "ls --all -l *.rs",
+            "= 1 + 2 * 3",
+            "echo 0x1F 2019-06-01 true 1.5kb",
+            "FOO=bar echo $nu.env.FOO",
+            "echo a > out.txt",
+            "echo a >> out.txt",
+            "ls && echo done || echo failed",
+            "echo a; echo b",
+            "def greet [name] { echo $name }",
+        ];
+
+        // Every space between the words of a line can be any mix of whitespace, and the line
+        // still prints as it was written
+        let separators = [" ", "  ", "\t", " \t ", "\t\t"];
+
+        for line in corpus.iter() {
+            for separator in separators.iter() {
+                let src = line.replace(' ', separator);
+                let (block, err) = parse(&src, 0, &EmptyScope);
+                assert!(err.is_none(), "{:?}: {:?}", src, err);
+
+                assert_eq!(block.print(&src), src);
+
+                for group in &block.block {
+                    let printed = group.print(&src);
+                    assert!(src.contains(&printed), "{:?} in {:?}", printed, src);
+
+                    for pipeline in &group.pipelines {
+                        let printed = pipeline.print(&src);
+                        assert!(printed.len() <= group.print(&src).len());
+                        assert!(src.contains(&printed), "{:?} in {:?}", printed, src);
+                        assert_eq!(printed.trim(), printed);
+                    }
+                }
+
+                if !src.contains(';') && !src.contains("&&") {
+                    assert_eq!(block.block[0].pipelines[0].print(&src), src);
+                }
+            }
+        }
+
+        // A pipeline split off by `&&` or `||` prints as just its own commands
+        let src = "ls  &&\techo done";
+        let (block, err) = parse(src, 0, &EmptyScope);
+        assert!(err.is_none());
+        let printed: Vec<_> = block.block[0]
+            .pipelines
+            .iter()
+            .map(|pipeline| pipeline.print(src))
+            .collect();
+        assert_eq!(printed, vec!["ls", "echo done"]);
+
+        let src = "echo a;\techo b";
+        let (block, err) = parse(src, 0, &EmptyScope);
+        assert!(err.is_none());
+        let printed: Vec<_> = block.block[0]
+            .pipelines
+            .iter()
+            .map(|pipeline| pipeline.print(src))
+            .collect();
+        assert_eq!(printed, vec!["echo a", "echo b"]);
+
+        let src = "echo a\n\techo  b";
+        let (block, err) = parse(src, 0, &EmptyScope);
+        assert!(err.is_none());
+        assert_eq!(block.print(src), src);
+        assert_eq!(block.block[1].print(src), "echo  b");
+    }
+
+    #[test]
+    fn unit_parse_rest_comes_after_optional_positionals() {
+        let signature = Signature::build("take")
+            .required("table", SyntaxShape::String, "the table to take from")
+            .optional("count", SyntaxShape::Int, "how many rows to take")
+            .rest(SyntaxShape::String, "the columns to keep");
+
+        fn parse_with(signature: &Signature, src: &str) -> (Vec<Expression>, Option<ParseError>) {
+            let lite_block = lite_parse(src);
+            let lite_cmd = &lite_block.block[0].pipelines[0].commands[0];
+            let (command, err) = parse_internal_command(lite_cmd, &EmptyScope, signature, 0);
+
+            let positional = command
+                .args
+                .positional
+                .unwrap_or_default()
+                .into_iter()
+                .map(|arg| arg.expr)
+                .collect();
+            (positional, err)
+        }
+
+        // The optional positional is filled before anything goes to the rest
+        assert_eq!(
+            parse_with(&signature, "take t 2 name size"),
+            (
+                vec![
+                    Expression::string("t".into()),
+                    Expression::integer(BigInt::from(2)),
+                    Expression::string("name".into()),
+                    Expression::string("size".into()),
+                ],
+                None
+            )
+        );
+        assert_eq!(
+            parse_with(&signature, "take t 2"),
+            (
+                vec![
+                    Expression::string("t".into()),
+                    Expression::integer(BigInt::from(2))
+                ],
+                None
+            )
+        );
+        assert_eq!(
+            parse_with(&signature, "take t"),
+            (vec![Expression::string("t".into())], None)
+        );
+
+        // So a rest argument can't skip over the optional positional
+        let (_, err) = parse_with(&signature, "take t name size");
+        match err.map(|err| err.reason().clone()) {
+            Some(nu_errors::ParseErrorReason::Mismatch { expected, actual }) => {
+                assert_eq!(expected, "int for `count`");
+                assert_eq!(actual.item, "name");
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unit_parse_falls_back_to_commands_differing_in_case() {
+        #[derive(Debug)]
+        struct CaseInsensitiveScope(Vec<Signature>);
+
+        impl ParserScope for CaseInsensitiveScope {
+            fn get_signature(&self, name: &str) -> Option<Signature> {
+                self.0.iter().find(|v| v.name == name).cloned()
+            }
+
+            fn has_signature(&self, name: &str) -> bool {
+                self.0.iter().any(|v| v.name == name)
+            }
+
+            fn get_command_names_ignoring_case(&self, name: &str) -> Vec<String> {
+                self.0
+                    .iter()
+                    .filter(|v| v.name.to_lowercase() == name.to_lowercase())
+                    .map(|v| v.name.clone())
+                    .collect()
+            }
+
+            fn add_definition(&self, _block: Block) {}
+
+            fn get_definitions(&self) -> Vec<Block> {
+                vec![]
+            }
+
+            fn get_alias(&self, _name: &str) -> Option<Vec<Spanned<String>>> {
+                None
+            }
+
+            fn add_alias(&self, _name: &str, _replacement: Vec<Spanned<String>>) {}
+
+            fn enter_scope(&self) {}
+
+            fn exit_scope(&self) {}
+        }
+
+        let scope = CaseInsensitiveScope(vec![
+            Signature::build("ls"),
+            Signature::build("config get").required("get", SyntaxShape::String, "the key"),
+            Signature::build("Foo"),
+            Signature::build("foo"),
+        ]);
+
+        fn call_name(src: &str, scope: &dyn ParserScope) -> (Option<String>, Option<ParseError>) {
+            let (block, err) = parse(src, 0, scope);
+            let name = block.block[0].pipelines[0]
+                .list
+                .get(0)
+                .and_then(|call| match call {
+                    ClassifiedCommand::Internal(internal) => Some(internal.name.clone()),
+                    _ => None,
+                });
+            (name, err)
+        }
+
+        assert_eq!(call_name("LS", &scope), (Some("ls".to_string()), None));
+        assert_eq!(
+            call_name("Config GET path", &scope),
+            (Some("config get".to_string()), None)
+        );
+
+        // An exact match is preferred to one ignoring case
+        assert_eq!(call_name("Foo", &scope), (Some("Foo".to_string()), None));
+
+        let (_, err) = call_name("FOO", &scope);
+        assert_eq!(
+            err.map(|err| err.reason().clone()),
+            Some(nu_errors::ParseErrorReason::Mismatch {
+                expected: "`Foo` or `foo`".to_string(),
+                actual: "FOO".to_string().spanned(Span::new(0, 3)),
+            })
+        );
+    }
 }
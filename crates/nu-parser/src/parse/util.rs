@@ -24,6 +24,94 @@ pub(crate) fn trim_quotes(input: &str) -> String {
     }
 }
 
+/// Decodes the escape sequences inside of a double-quoted string literal. The
+/// given token still includes its surrounding quotes, which lets a malformed
+/// escape be reported with the span of just that escape.
+///
+/// The recognized escapes are `\n`, `\t`, `\r`, `\"`, `\\` and `\u{XXXX}`. A
+/// backslash followed by any other character is kept as-is, so regexes like
+/// `"\d+"` and Windows paths like `"C:\Windows"` are left untouched. Paths with
+/// a segment starting with one of the escape letters are not: `"C:\new"`,
+/// `"C:\temp"` and `"C:\root"` quietly decode to a newline, tab or carriage
+/// return, and `"C:\users"` is an error from the `\u` escape. Such paths need
+/// to be single-quoted.
+pub fn unescape_string(lite_arg: &Spanned<String>) -> Result<String, ParseError> {
+    let inner = &lite_arg.item[1..lite_arg.item.len() - 1];
+    let offset = lite_arg.span.start() + 1;
+
+    let mut output = String::with_capacity(inner.len());
+    let mut chars = inner.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.peek().map(|(_, c)| *c) {
+            Some('n') => output.push('\n'),
+            Some('t') => output.push('\t'),
+            Some('r') => output.push('\r'),
+            Some('"') => output.push('"'),
+            Some('\\') => output.push('\\'),
+            Some('u') => {
+                let _ = chars.next();
+                let mut end = idx + 2;
+                let mut digits = String::new();
+                let mut closed = false;
+
+                if let Some((_, '{')) = chars.peek() {
+                    let _ = chars.next();
+                    end += 1;
+
+                    for (i, c) in &mut chars {
+                        end = i + c.len_utf8();
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        digits.push(c);
+                    }
+                }
+
+                let decoded = if closed
+                    && !digits.is_empty()
+                    && digits.len() <= 6
+                    && digits.chars().all(|c| c.is_ascii_hexdigit())
+                {
+                    u32::from_str_radix(&digits, 16)
+                        .ok()
+                        .and_then(std::char::from_u32)
+                } else {
+                    None
+                };
+
+                match decoded {
+                    Some(c) => output.push(c),
+                    None => {
+                        return Err(ParseError::mismatch(
+                            "unicode escape of the form \\u{XXXX}",
+                            inner[idx..end]
+                                .to_string()
+                                .spanned(Span::new(offset + idx, offset + end)),
+                        ))
+                    }
+                }
+
+                continue;
+            }
+            _ => {
+                output.push('\\');
+                continue;
+            }
+        }
+
+        let _ = chars.next();
+    }
+
+    Ok(output)
+}
+
 pub(crate) fn verify_and_strip(
     contents: &Spanned<String>,
     left: char,
@@ -7,6 +7,12 @@ pub trait ParserScope: Debug {
 
     fn has_signature(&self, name: &str) -> bool;
 
+    /// The names of the commands that match `name` when case is ignored. Scopes that don't allow
+    /// case-insensitive lookup never find any.
+    fn get_command_names_ignoring_case(&self, _name: &str) -> Vec<String> {
+        vec![]
+    }
+
     fn add_definition(&self, block: Block);
 
     fn get_definitions(&self) -> Vec<Block>;
@@ -1,6 +1,6 @@
 use nu_protocol::hir::*;
 use nu_protocol::UnspannedPathMember;
-use nu_source::{Spanned, SpannedItem};
+use nu_source::{Span, Spanned, SpannedItem};
 
 /// Converts a SpannedExpression into a spanned shape(s) ready for color-highlighting
 pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>> {
@@ -44,7 +44,9 @@ pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>
         Expression::Literal(Literal::GlobPattern(_)) => {
             vec![FlatShape::GlobPattern.spanned(e.span)]
         }
-        Expression::Literal(Literal::Number(_)) => vec![FlatShape::Int.spanned(e.span)],
+        Expression::Literal(Literal::Number(_)) | Expression::Literal(Literal::Date(_)) => {
+            vec![FlatShape::Int.spanned(e.span)]
+        }
         Expression::Literal(Literal::Operator(_)) => vec![FlatShape::Operator.spanned(e.span)],
         Expression::Literal(Literal::Size(number, unit)) => vec![FlatShape::Size {
             number: number.span,
@@ -63,6 +65,12 @@ pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>
             output.append(&mut expression_to_flat_shape(&binary.right));
             output
         }
+        Expression::Not(expr) => {
+            let mut output = vec![FlatShape::Operator
+                .spanned(Span::new(e.span.start(), e.span.start() + "not".len()))];
+            output.append(&mut expression_to_flat_shape(expr));
+            output
+        }
         Expression::Range(range) => {
             let mut output = vec![];
             if let Some(left) = &range.left {
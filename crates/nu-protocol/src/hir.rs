@@ -2,6 +2,7 @@ use std::cmp::{Ord, Ordering, PartialOrd};
 use std::convert::From;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +18,7 @@ use nu_source::{
 use nu_source::{IntoSpanned, Span, Spanned, SpannedItem, Tag};
 
 use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
 use indexmap::IndexMap;
 use log::trace;
 use num_bigint::{BigInt, ToBigInt};
@@ -42,6 +44,13 @@ impl InternalCommand {
         }
     }
 
+    /// The command itself along with every internal command called in its arguments
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        let mut commands = vec![self];
+        commands.extend(self.args.get_internal_commands());
+        commands
+    }
+
     pub fn has_it_usage(&self) -> bool {
         self.args.has_it_usage()
     }
@@ -85,6 +94,15 @@ pub enum ClassifiedCommand {
 }
 
 impl ClassifiedCommand {
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        match self {
+            ClassifiedCommand::Expr(expr) => expr.get_internal_commands(),
+            ClassifiedCommand::Dynamic(call) => call.get_internal_commands(),
+            ClassifiedCommand::Internal(internal) => internal.get_internal_commands(),
+            ClassifiedCommand::Error(_) => vec![],
+        }
+    }
+
     fn has_it_usage(&self) -> bool {
         match self {
             ClassifiedCommand::Expr(expr) => expr.has_it_usage(),
@@ -104,21 +122,36 @@ impl ClassifiedCommand {
     }
 }
 
+/// How a pipeline is chained to the pipeline that runs before it in the same group
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub enum PipelineCondition {
+    /// `&&`: only run the pipeline if the previous one succeeded
+    AndThen,
+    /// `||`: only run the pipeline if the previous one failed
+    OrElse,
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
 pub struct Pipeline {
     pub list: Vec<ClassifiedCommand>,
     pub span: Span,
+    pub condition: Option<PipelineCondition>,
 }
 
 impl Pipeline {
     pub fn new(span: Span) -> Pipeline {
-        Pipeline { list: vec![], span }
+        Pipeline {
+            list: vec![],
+            span,
+            condition: None,
+        }
     }
 
     pub fn basic() -> Pipeline {
         Pipeline {
             list: vec![],
             span: Span::unknown(),
+            condition: None,
         }
     }
 
@@ -129,6 +162,12 @@ impl Pipeline {
     pub fn has_it_usage(&self) -> bool {
         self.list.iter().any(|cc| cc.has_it_usage())
     }
+
+    /// Prints the pipeline exactly as it was written in `source`, the text it was parsed from,
+    /// keeping its whitespace and quoting.
+    pub fn print(&self, source: &str) -> String {
+        self.span.slice(source).to_string()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
@@ -155,6 +194,12 @@ impl Group {
     pub fn has_it_usage(&self) -> bool {
         self.pipelines.iter().any(|cc| cc.has_it_usage())
     }
+
+    /// Prints the group exactly as it was written in `source`, the text it was parsed from,
+    /// including the `;`, `&&` and `||` between its pipelines.
+    pub fn print(&self, source: &str) -> String {
+        self.span.slice(source).to_string()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
@@ -206,6 +251,12 @@ impl Block {
         self.infer_params();
     }
 
+    /// Prints the block exactly as it was written in `source`, the text it was parsed from,
+    /// from the start of its first group to the end of its last.
+    pub fn print(&self, source: &str) -> String {
+        self.span.slice(source).to_string()
+    }
+
     pub fn set_redirect(&mut self, external_redirection: ExternalRedirection) {
         if let Some(group) = self.block.last_mut() {
             if let Some(pipeline) = group.pipelines.last_mut() {
@@ -216,6 +267,23 @@ impl Block {
         }
     }
 
+    /// Every internal command called in the block, including the ones in nested blocks and in
+    /// the definitions of custom commands
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        let mut commands = vec![];
+        for definition in self.definitions.values() {
+            commands.extend(definition.get_internal_commands());
+        }
+        for group in &self.block {
+            for pipeline in &group.pipelines {
+                for elem in &pipeline.list {
+                    commands.extend(elem.get_internal_commands());
+                }
+            }
+        }
+        commands
+    }
+
     pub fn has_it_usage(&self) -> bool {
         self.block.iter().any(|x| x.has_it_usage())
     }
@@ -560,6 +628,24 @@ impl Unit {
     }
 
     pub fn compute(self, size: &Number) -> UntaggedValue {
+        if let Number::Decimal(decimal) = size {
+            // Scale decimals into the base unit before dropping the fraction, so that
+            // `1.5KB` is 1500 bytes rather than 1000.
+            return match self.compute(&Number::Int(BigInt::from(1))) {
+                UntaggedValue::Primitive(Primitive::Filesize(bytes)) => filesize(
+                    (decimal * BigDecimal::new(bytes, 0))
+                        .to_bigint()
+                        .expect("Conversion should never fail."),
+                ),
+                UntaggedValue::Primitive(Primitive::Duration(nanos)) => duration(
+                    (decimal * BigDecimal::new(nanos, 0))
+                        .to_bigint()
+                        .expect("Conversion should never fail."),
+                ),
+                other => other,
+            };
+        }
+
         let size = size.clone();
 
         match self {
@@ -672,6 +758,8 @@ impl SpannedExpression {
                     Operator::Plus | Operator::Minus => 90,
                     Operator::NotContains
                     | Operator::Contains
+                    | Operator::Matches
+                    | Operator::NotMatches
                     | Operator::LessThan
                     | Operator::LessThanOrEqual
                     | Operator::GreaterThan
@@ -688,6 +776,10 @@ impl SpannedExpression {
         }
     }
 
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        self.expr.get_internal_commands()
+    }
+
     pub fn has_it_usage(&self) -> bool {
         self.expr.has_it_usage()
     }
@@ -740,6 +832,10 @@ impl PrettyDebugWithSource for SpannedExpression {
                 },
                 Expression::Variable(_, _) => DbgDocBldr::keyword(self.span.slice(source)),
                 Expression::Binary(binary) => binary.pretty_debug(source),
+                Expression::Not(expr) => {
+                    (DbgDocBldr::operator("not") + DbgDocBldr::space() + expr.pretty_debug(source))
+                        .group()
+                }
                 Expression::Range(range) => range.pretty_debug(source),
                 Expression::Block(_) => DbgDocBldr::opaque("block"),
                 Expression::Invocation(_) => DbgDocBldr::opaque("invocation"),
@@ -799,6 +895,10 @@ impl PrettyDebugWithSource for SpannedExpression {
             },
             Expression::Variable(_, _) => DbgDocBldr::keyword(self.span.slice(source)),
             Expression::Binary(binary) => binary.pretty_debug(source),
+            Expression::Not(expr) => {
+                (DbgDocBldr::operator("not") + DbgDocBldr::space() + expr.pretty_debug(source))
+                    .group()
+            }
             Expression::Range(range) => range.pretty_debug(source),
             Expression::Block(_) => DbgDocBldr::opaque("block"),
             Expression::Invocation(_) => DbgDocBldr::opaque("invocation"),
@@ -851,6 +951,10 @@ pub enum Operator {
     GreaterThanOrEqual,
     Contains,
     NotContains,
+    /// `=~` with an `r'regex'` on its right-hand side
+    Matches,
+    /// `!~` with an `r'regex'` on its right-hand side
+    NotMatches,
     Plus,
     Minus,
     Multiply,
@@ -863,6 +967,34 @@ pub enum Operator {
     Pow,
 }
 
+impl FromStr for Operator {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(match input {
+            "==" => Operator::Equal,
+            "!=" => Operator::NotEqual,
+            "<" => Operator::LessThan,
+            "<=" => Operator::LessThanOrEqual,
+            ">" => Operator::GreaterThan,
+            ">=" => Operator::GreaterThanOrEqual,
+            "=~" => Operator::Contains,
+            "!~" => Operator::NotContains,
+            "+" => Operator::Plus,
+            "-" => Operator::Minus,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            "in" => Operator::In,
+            "not-in" => Operator::NotIn,
+            "mod" | "%" => Operator::Modulo,
+            "&&" => Operator::And,
+            "||" => Operator::Or,
+            "**" => Operator::Pow,
+            _ => return Err(()),
+        })
+    }
+}
+
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash, Deserialize, Serialize, new)]
 pub struct Binary {
     pub left: SpannedExpression,
@@ -942,6 +1074,7 @@ pub enum Literal {
     GlobPattern(String),
     ColumnPath(Vec<Member>),
     Bare(String),
+    Date(DateTime<FixedOffset>),
 }
 
 impl Literal {
@@ -970,6 +1103,7 @@ impl ShellTypeName for Literal {
             Literal::Bare(_) => "string",
             Literal::GlobPattern(_) => "pattern",
             Literal::Operator(_) => "operator",
+            Literal::Date(_) => "date",
         }
     }
 }
@@ -990,6 +1124,7 @@ impl PrettyDebugWithSource for SpannedLiteral {
                     DbgDocBldr::delimit("b\"", DbgDocBldr::primitive(bare), "\"")
                 }
                 Literal::Operator(operator) => DbgDocBldr::primitive(format!("{:?}", operator)),
+                Literal::Date(_) => DbgDocBldr::primitive(self.span.slice(source)),
             },
         }
     }
@@ -1015,6 +1150,9 @@ impl PrettyDebugWithSource for SpannedLiteral {
             Literal::Operator(operator) => {
                 DbgDocBldr::typed("operator", DbgDocBldr::primitive(format!("{:?}", operator)))
             }
+            Literal::Date(_) => {
+                DbgDocBldr::typed("date", DbgDocBldr::primitive(self.span.slice(source)))
+            }
         }
     }
 }
@@ -1043,6 +1181,7 @@ pub enum Expression {
     Synthetic(Synthetic),
     Variable(String, Span),
     Binary(Box<Binary>),
+    Not(Box<SpannedExpression>),
     Range(Box<Range>),
     Block(hir::Block),
     List(Vec<SpannedExpression>),
@@ -1074,6 +1213,7 @@ impl ShellTypeName for Expression {
             Expression::List(..) => "list",
             Expression::Table(..) => "table",
             Expression::Binary(..) => "binary",
+            Expression::Not(..) => "not",
             Expression::Range(..) => "range",
             Expression::Block(..) => "block",
             Expression::Invocation(..) => "command invocation",
@@ -1149,14 +1289,57 @@ impl Expression {
         ))
     }
 
+    pub fn decimal_unit(d: Spanned<BigDecimal>, unit: Spanned<Unit>) -> Expression {
+        Expression::Literal(Literal::Size(Number::Decimal(d.item).spanned(d.span), unit))
+    }
+
+    pub fn not(expr: SpannedExpression) -> Expression {
+        Expression::Not(Box::new(expr))
+    }
+
     pub fn variable(v: String, span: Span) -> Expression {
         Expression::Variable(v, span)
     }
 
+    pub fn date(date: DateTime<FixedOffset>) -> Expression {
+        Expression::Literal(Literal::Date(date))
+    }
+
     pub fn boolean(b: bool) -> Expression {
         Expression::Boolean(b)
     }
 
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        match self {
+            Expression::Table(headers, values) => headers
+                .iter()
+                .chain(values.iter().flatten())
+                .flat_map(|se| se.get_internal_commands())
+                .collect(),
+            Expression::List(list) => list
+                .iter()
+                .flat_map(|se| se.get_internal_commands())
+                .collect(),
+            Expression::Block(block) | Expression::Invocation(block) => {
+                block.get_internal_commands()
+            }
+            Expression::Binary(binary) => {
+                let mut commands = binary.left.get_internal_commands();
+                commands.extend(binary.right.get_internal_commands());
+                commands
+            }
+            Expression::Not(expr) => expr.get_internal_commands(),
+            Expression::Path(path) => path.head.get_internal_commands(),
+            Expression::Range(range) => range
+                .left
+                .iter()
+                .chain(range.right.iter())
+                .flat_map(|se| se.get_internal_commands())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     pub fn has_it_usage(&self) -> bool {
         match self {
             Expression::Variable(name, _) if name == "$it" => true,
@@ -1167,6 +1350,7 @@ impl Expression {
             Expression::List(list) => list.iter().any(|se| se.has_it_usage()),
             Expression::Invocation(block) => block.has_it_usage(),
             Expression::Binary(binary) => binary.left.has_it_usage() || binary.right.has_it_usage(),
+            Expression::Not(expr) => expr.has_it_usage(),
             Expression::Path(path) => path.head.has_it_usage(),
             Expression::Range(range) => {
                 (if let Some(left) = &range.left {
@@ -1213,6 +1397,9 @@ impl Expression {
                 output.extend(binary.left.get_free_variables(known_variables));
                 output.extend(binary.right.get_free_variables(known_variables));
             }
+            Expression::Not(expr) => {
+                output.extend(expr.get_free_variables(known_variables));
+            }
             Expression::Path(path) => {
                 output.extend(path.head.get_free_variables(known_variables));
             }
@@ -1239,6 +1426,14 @@ pub enum NamedValue {
 }
 
 impl NamedValue {
+    fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        if let NamedValue::Value(_, se) = self {
+            se.get_internal_commands()
+        } else {
+            vec![]
+        }
+    }
+
     fn has_it_usage(&self) -> bool {
         if let NamedValue::Value(_, se) = self {
             se.has_it_usage()
@@ -1322,6 +1517,17 @@ impl Call {
         }
     }
 
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        let mut commands = self.head.get_internal_commands();
+        if let Some(pos) = &self.positional {
+            commands.extend(pos.iter().flat_map(|x| x.get_internal_commands()));
+        }
+        if let Some(named) = &self.named {
+            commands.extend(named.get_internal_commands());
+        }
+        commands
+    }
+
     pub fn has_it_usage(&self) -> bool {
         self.head.has_it_usage()
             || (if let Some(pos) = &self.positional {
@@ -1513,6 +1719,12 @@ impl NamedArguments {
         self.named.is_empty()
     }
 
+    pub fn get_internal_commands(&self) -> Vec<&InternalCommand> {
+        self.iter()
+            .flat_map(|x| x.1.get_internal_commands())
+            .collect()
+    }
+
     pub fn has_it_usage(&self) -> bool {
         self.iter().any(|x| x.1.has_it_usage())
     }
@@ -100,6 +100,29 @@ impl PositionalType {
 
 type Description = String;
 
+/// Marks a command or a flag as being on its way out. Using it still works, but with a warning
+/// that names what to use instead.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    /// The version of Nu it was deprecated in
+    pub since: String,
+    /// What to use instead, if it was renamed
+    pub renamed_to: Option<String>,
+}
+
+impl Deprecation {
+    /// Describes the deprecation, eg) `deprecated since 0.28.0, use `get` instead`
+    pub fn describe(&self) -> String {
+        match &self.renamed_to {
+            Some(renamed_to) => format!(
+                "deprecated since {}, use `{}` instead",
+                self.since, renamed_to
+            ),
+            None => format!("deprecated since {}", self.since),
+        }
+    }
+}
+
 /// The full signature of a command. All commands have a signature similar to a function signature.
 /// Commands will use this information to register themselves with Nu's core engine so that the command
 /// can be invoked, help can be displayed, and calls to the command can be error-checked.
@@ -121,6 +144,12 @@ pub struct Signature {
     pub input: Option<Type>,
     /// If the command is expected to filter data, or to consume it (as a sink)
     pub is_filter: bool,
+    /// Set when the command itself is deprecated
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    /// The deprecated flags, by name
+    #[serde(default)]
+    pub deprecated_flags: IndexMap<String, Deprecation>,
 }
 
 impl PartialEq for Signature {
@@ -198,6 +227,8 @@ impl Signature {
             is_filter: false,
             yields: None,
             input: None,
+            deprecated: None,
+            deprecated_flags: IndexMap::new(),
         }
     }
 
@@ -310,6 +341,8 @@ impl Signature {
     }
 
     /// Set the type for the "rest" of the positional arguments
+    /// Arguments fill the required and optional positionals first, in the order they were
+    /// declared, so the rest only collects the arguments left over after all of them.
     /// Note: Not naming the field in your struct holding the rest values "rest", can
     /// cause errors when deserializing
     pub fn rest(mut self, ty: SyntaxShape, desc: impl Into<String>) -> Signature {
@@ -329,6 +362,34 @@ impl Signature {
         self
     }
 
+    /// Mark the command as deprecated since the given version, optionally naming the command
+    /// that replaces it
+    pub fn deprecated(mut self, since: impl Into<String>, renamed_to: Option<&str>) -> Signature {
+        self.deprecated = Some(Deprecation {
+            since: since.into(),
+            renamed_to: renamed_to.map(|name| name.to_string()),
+        });
+        self
+    }
+
+    /// Mark a flag of the signature as deprecated since the given version, optionally naming the
+    /// flag that replaces it
+    pub fn deprecated_flag(
+        mut self,
+        name: impl Into<String>,
+        since: impl Into<String>,
+        renamed_to: Option<&str>,
+    ) -> Signature {
+        self.deprecated_flags.insert(
+            name.into(),
+            Deprecation {
+                since: since.into(),
+                renamed_to: renamed_to.map(|name| format!("--{}", name)),
+            },
+        );
+        self
+    }
+
     /// Get list of the short-hand flags
     pub fn get_shorts(&self) -> Vec<char> {
         let mut shorts = Vec::new();
@@ -339,4 +400,22 @@ impl Signature {
         }
         shorts
     }
+
+    /// A one-line summary of how the command is called, eg. `nth <row number> ...args`
+    pub fn usage_line(&self) -> String {
+        let mut line = self.name.clone();
+
+        for (positional, _) in &self.positional {
+            match positional {
+                PositionalType::Mandatory(name, _) => line.push_str(&format!(" <{}>", name)),
+                PositionalType::Optional(name, _) => line.push_str(&format!(" ({})", name)),
+            }
+        }
+
+        if self.rest_positional.is_some() {
+            line.push_str(" ...args");
+        }
+
+        line
+    }
 }
@@ -22,6 +22,27 @@ pub fn did_you_mean(obj_source: &Value, field_tried: String) -> Option<Vec<Strin
     }
 }
 
+/// Finds the closest of the given names to the one you tried, if any of them is close enough
+/// to be a likely typo. Differences in case alone always count as a close match.
+pub fn closest_match(
+    possibilities: impl IntoIterator<Item = String>,
+    tried: &str,
+) -> Option<String> {
+    let tried_lowercase = tried.to_lowercase();
+    let max_distance = (tried.chars().count() + 1) / 2;
+
+    possibilities
+        .into_iter()
+        .filter(|word| word != tried)
+        .map(|word| {
+            let edit_distance = levenshtein_distance(&word.to_lowercase(), &tried_lowercase);
+            (edit_distance, word)
+        })
+        .filter(|(edit_distance, _)| *edit_distance <= max_distance)
+        .min()
+        .map(|(_, word)| word)
+}
+
 /// Borrowed from https://crates.io/crates/natural
 fn levenshtein_distance(str1: &str, str2: &str) -> usize {
     let n = str1.len();
@@ -91,4 +112,15 @@ mod test {
 
         assert_eq!(None, did_you_mean(&empty_source, "hat".to_string()))
     }
+
+    #[test]
+    fn closest_match_suggests_near_misses() {
+        let names = || vec!["open".to_string(), "save".to_string(), "select".to_string()];
+
+        assert_eq!(Some("open".to_string()), closest_match(names(), "opne"));
+        assert_eq!(Some("select".to_string()), closest_match(names(), "selcet"));
+        assert_eq!(Some("open".to_string()), closest_match(names(), "OPEN"));
+        assert_eq!(None, closest_match(names(), "xyzzy"));
+        assert_eq!(None, closest_match(names(), "open"));
+    }
 }
@@ -95,10 +95,10 @@ impl InputStream {
         match String::from_utf8(bytes) {
             Ok(s) => Ok(s.tagged(value_tag.clone())),
             Err(_) => Err(ShellError::labeled_error_with_secondary(
-                "Expected a string from pipeline",
+                "Expected a string from pipeline, found binary data that isn't text",
                 "requires string input",
                 tag,
-                "value originates from here",
+                "binary originates from here",
                 value_tag,
             )),
         }
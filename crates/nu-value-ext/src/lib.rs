@@ -214,20 +214,54 @@ pub fn get_data_by_column_path<F>(
 where
     F: FnOnce(&Value, &PathMember, ShellError) -> ShellError,
 {
+    let members = path.members();
     let mut current = value.clone();
+    let mut index = 0;
 
-    for p in path.iter() {
-        let value = get_data_by_member(&current, p);
-
-        match value {
-            Ok(v) => current = v.clone(),
-            Err(e) => return Err(get_error(&current, &p, e)),
+    while let Some(p) = members.get(index) {
+        match get_data_by_member(&current, p) {
+            Ok(v) => {
+                current = v;
+                index += 1;
+            }
+            Err(e) => match get_data_by_dotted_members(&current, &members[index..]) {
+                Some((v, used)) => {
+                    current = v;
+                    index += used;
+                }
+                None => return Err(get_error(&current, &p, e)),
+            },
         }
     }
 
     Ok(current)
 }
 
+/// Finds a column named by the leading members joined with dots, like the columns `flatten` makes,
+/// along with how many of the members its name took up. The longest name that matches wins.
+pub fn get_data_by_dotted_members(value: &Value, members: &[PathMember]) -> Option<(Value, usize)> {
+    let row = match &value.value {
+        UntaggedValue::Row(row) => row,
+        _ => return None,
+    };
+
+    let mut names = vec![];
+    for member in members {
+        match &member.unspanned {
+            UnspannedPathMember::String(name) => names.push(name.as_str()),
+            UnspannedPathMember::Int(_) => break,
+        }
+    }
+
+    (2..=names.len()).rev().find_map(|used| {
+        let span = members[0].span.until(members[used - 1].span);
+        let name = names[..used].join(".");
+
+        row.get_data_by_key(name[..].spanned(span))
+            .map(|value| (value, used))
+    })
+}
+
 pub fn swap_data_by_column_path<F>(
     value: &Value,
     path: &ColumnPath,
@@ -136,4 +136,23 @@ fn get_table_data_by_key() {
             .unwrap(),
         table(&[nothing(), nothing()])
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn get_data_by_column_path_finds_dotted_column_names() {
+    let field_path = column_path("people.name.first").as_column_path().unwrap();
+
+    let value = row(indexmap! {
+        "people.name".into() =>
+            row(indexmap! {
+                "first".into() => string("Andrés")
+            })
+    });
+
+    assert_eq!(
+        *value
+            .get_data_by_column_path(&field_path, Box::new(error_callback("people.name.first")))
+            .unwrap(),
+        *string("Andrés")
+    );
+}
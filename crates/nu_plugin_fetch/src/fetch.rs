@@ -1,17 +1,36 @@
 use base64::encode;
+use futures::future::{select, Either};
+use futures_timer::Delay;
 use nu_errors::ShellError;
 use nu_protocol::{CallInfo, CommandAction, ReturnSuccess, ReturnValue, UntaggedValue, Value};
 use nu_source::{AnchorLocation, Span, Tag};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
+use surf::StatusCode;
+
+/// How many redirects are followed before giving up on a URL
+const MAX_REDIRECTS: u8 = 5;
+
+/// How long a request may take when no `--timeout` is given, in seconds
+const DEFAULT_TIMEOUT: u64 = 30;
+
+/// How much of the body of a failed response is shown in the error
+const MAX_ERROR_BODY_LENGTH: usize = 200;
 
-#[derive(Default)]
 pub struct Fetch {
     pub path: Option<Value>,
     pub tag: Tag,
     pub has_raw: bool,
     pub user: Option<String>,
     pub password: Option<String>,
+    pub timeout: Duration,
+}
+
+impl Default for Fetch {
+    fn default() -> Fetch {
+        Fetch::new()
+    }
 }
 
 impl Fetch {
@@ -22,6 +41,7 @@ impl Fetch {
             has_raw: false,
             user: None,
             password: None,
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT),
         }
     }
 
@@ -50,6 +70,11 @@ impl Fetch {
             None => None,
         };
 
+        self.timeout = match call_info.args.get("timeout") {
+            Some(seconds) => Duration::from_secs(seconds.as_u64()?),
+            None => Duration::from_secs(DEFAULT_TIMEOUT),
+        };
+
         ReturnSuccess::value(UntaggedValue::nothing().into_untagged_value())
     }
 }
@@ -59,23 +84,33 @@ pub async fn fetch(
     has_raw: bool,
     user: Option<String>,
     password: Option<String>,
+    timeout: Duration,
 ) -> ReturnValue {
     let path_str = path.as_string()?;
     let path_span = path.tag.span;
 
-    let result = helper(&path_str, path_span, has_raw, user, password).await;
-
-    if let Err(e) = result {
-        return Err(e);
-    }
-    let (file_extension, value) = result?;
+    let request = Box::pin(helper(&path_str, path_span, user, password));
+    let (file_extension, value) = match select(request, Delay::new(timeout)).await {
+        Either::Left((result, _)) => result?,
+        Either::Right(_) => {
+            return Err(ShellError::labeled_error(
+                format!(
+                    "No response from {} within {} seconds",
+                    path_str,
+                    timeout.as_secs()
+                ),
+                "timed out",
+                path_span,
+            ))
+        }
+    };
 
     let file_extension = if has_raw {
         None
     } else {
         // If the extension could not be determined via mimetype, try to use the path
         // extension. Some file types do not declare their mimetypes (such as bson files).
-        file_extension.or_else(|| path_str.split('.').last().map(String::from))
+        file_extension.or_else(|| url_extension(&path_str))
     };
 
     if let Some(extension) = file_extension {
@@ -87,16 +122,52 @@ pub async fn fetch(
     }
 }
 
+/// The extension of the last segment of the URL's path, like `json` for `http://a.b/data.json`
+fn url_extension(location: &str) -> Option<String> {
+    url::Url::parse(location)
+        .ok()?
+        .path_segments()
+        .and_then(|segments| segments.last())
+        .and_then(|name| if name.is_empty() { None } else { Some(name) })
+        .and_then(|name| {
+            PathBuf::from(name)
+                .extension()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+}
+
+/// The format of the content, as the name of the `from` subcommand that reads it, and whether it
+/// is binary rather than text
+fn content_format(content_type: &mime::Mime) -> (Option<String>, bool) {
+    let suffix = content_type.suffix().map(|suffix| suffix.as_str());
+
+    match (content_type.type_(), content_type.subtype(), suffix) {
+        (mime::IMAGE, mime::SVG, _) => (Some("svg".to_string()), false),
+        (mime::IMAGE, image_ty, _) => (Some(image_ty.to_string()), true),
+        (mime::APPLICATION, mime::OCTET_STREAM, _) => (None, true),
+        (_, mime::JSON, _) | (_, _, Some("json")) => (Some("json".to_string()), false),
+        (_, mime::XML, _) | (_, _, Some("xml")) => (Some("xml".to_string()), false),
+        (mime::TEXT, mime::HTML, _) => (Some("html".to_string()), false),
+        (mime::TEXT, mime::CSV, _) => (Some("csv".to_string()), false),
+        (_, sub_ty, _) => match sub_ty.as_str() {
+            "tab-separated-values" => (Some("tsv".to_string()), false),
+            "yaml" | "x-yaml" => (Some("yaml".to_string()), false),
+            "toml" => (Some("toml".to_string()), false),
+            // Like plain text, anything else is left to the extension of the URL
+            _ => (None, false),
+        },
+    }
+}
+
 // Helper function that actually goes to retrieve the resource from the url given
 // The Option<String> return a possible file extension which can be used in AutoConvert commands
 async fn helper(
     location: &str,
     span: Span,
-    has_raw: bool,
     user: Option<String>,
     password: Option<String>,
 ) -> std::result::Result<(Option<String>, Value), ShellError> {
-    let url = match url::Url::parse(location) {
+    let mut url = match url::Url::parse(location) {
         Ok(u) => u,
         Err(e) => {
             return Err(ShellError::labeled_error(
@@ -113,12 +184,6 @@ async fn helper(
         _ => None,
     };
 
-    let mut response = surf::RequestBuilder::new(surf::http::Method::Get, url);
-
-    if let Some(login) = login {
-        response = surf::get(location).header("Authorization", format!("Basic {}", login));
-    }
-
     let generate_error = |t: &str, e: surf::Error, span: &Span| {
         ShellError::labeled_error(
             format!("Could not load {} from remote url: {:?}", t, e),
@@ -131,157 +196,340 @@ async fn helper(
         anchor: Some(AnchorLocation::Url(location.to_string())),
     };
 
-    match response.await {
-        Ok(mut r) => match r.header("content-type") {
-            Some(content_type) => {
-                let content_type_header_value = content_type.get(0);
-                let content_type_header_value = match content_type_header_value {
-                    Some(h) => h,
-                    None => {
-                        return Err(ShellError::labeled_error(
-                            "no content type found",
-                            "no content type found",
-                            span,
-                        ))
-                    }
-                };
-                let content_type = mime::Mime::from_str(content_type_header_value.as_str())
-                    .map_err(|_| {
-                        ShellError::labeled_error(
-                            format!("MIME type unknown: {}", content_type_header_value),
-                            "given unknown MIME type",
-                            span,
-                        )
-                    })?;
-                match (content_type.type_(), content_type.subtype()) {
-                    (mime::APPLICATION, mime::XML) => Ok((
-                        Some("xml".to_string()),
-                        UntaggedValue::string(
-                            r.body_string()
-                                .await
-                                .map_err(|e| generate_error("text", e, &span))?,
-                        )
-                        .into_value(tag),
-                    )),
-                    (mime::APPLICATION, mime::JSON) => Ok((
-                        Some("json".to_string()),
-                        UntaggedValue::string(
-                            r.body_string()
-                                .await
-                                .map_err(|e| generate_error("text", e, &span))?,
-                        )
-                        .into_value(tag),
-                    )),
-                    (mime::APPLICATION, mime::OCTET_STREAM) => {
-                        let buf: Vec<u8> = r
-                            .body_bytes()
-                            .await
-                            .map_err(|e| generate_error("binary", e, &span))?;
-                        Ok((None, UntaggedValue::binary(buf).into_value(tag)))
-                    }
-                    (mime::IMAGE, mime::SVG) => Ok((
-                        Some("svg".to_string()),
-                        UntaggedValue::string(
-                            r.body_string()
-                                .await
-                                .map_err(|e| generate_error("svg", e, &span))?,
-                        )
-                        .into_value(tag),
-                    )),
-                    (mime::IMAGE, image_ty) => {
-                        let buf: Vec<u8> = r
-                            .body_bytes()
-                            .await
-                            .map_err(|e| generate_error("image", e, &span))?;
-                        Ok((
-                            Some(image_ty.to_string()),
-                            UntaggedValue::binary(buf).into_value(tag),
-                        ))
-                    }
-                    (mime::TEXT, mime::HTML) => Ok((
-                        Some("html".to_string()),
-                        UntaggedValue::string(
-                            r.body_string()
-                                .await
-                                .map_err(|e| generate_error("text", e, &span))?,
-                        )
-                        .into_value(tag),
-                    )),
-                    (mime::TEXT, mime::CSV) => Ok((
-                        Some("csv".to_string()),
-                        UntaggedValue::string(
-                            r.body_string()
-                                .await
-                                .map_err(|e| generate_error("text", e, &span))?,
-                        )
-                        .into_value(tag),
-                    )),
-                    (mime::TEXT, mime::PLAIN) => {
-                        let path_extension = url::Url::parse(location)
-                            .map_err(|_| {
-                                ShellError::labeled_error(
-                                    format!("Cannot parse URL: {}", location),
-                                    "cannot parse",
-                                    span,
-                                )
-                            })?
-                            .path_segments()
-                            .and_then(|segments| segments.last())
-                            .and_then(|name| if name.is_empty() { None } else { Some(name) })
-                            .and_then(|name| {
-                                PathBuf::from(name)
-                                    .extension()
-                                    .map(|name| name.to_string_lossy().to_string())
-                            });
-
-                        Ok((
-                            path_extension,
-                            UntaggedValue::string(
-                                r.body_string()
-                                    .await
-                                    .map_err(|e| generate_error("text", e, &span))?,
-                            )
-                            .into_value(tag),
-                        ))
-                    }
-                    (_ty, _sub_ty) if has_raw => {
-                        let raw_bytes = r.body_bytes().await;
-                        let raw_bytes = match raw_bytes {
-                            Ok(r) => r,
-                            Err(e) => {
-                                return Err(ShellError::labeled_error(
-                                    "error with raw_bytes",
-                                    e.to_string(),
-                                    &span,
-                                ));
-                            }
-                        };
-
-                        // For unsupported MIME types, we do not know if the data is UTF-8,
-                        // so we get the raw body bytes and try to convert to UTF-8 if possible.
-                        match std::str::from_utf8(&raw_bytes) {
-                            Ok(response_str) => {
-                                Ok((None, UntaggedValue::string(response_str).into_value(tag)))
-                            }
-                            Err(_) => Ok((None, UntaggedValue::binary(raw_bytes).into_value(tag))),
-                        }
-                    }
-                    (ty, sub_ty) => Err(ShellError::unimplemented(format!(
-                        "Not yet supported MIME type: {} {}",
-                        ty, sub_ty
-                    ))),
-                }
+    // The credentials are only for the host they were given for, so they're no longer sent once
+    // a redirect leads somewhere else
+    let origin = url.origin();
+    let mut same_origin = true;
+
+    let mut redirects = 0;
+    let mut r = loop {
+        let mut request = surf::get(url.clone());
+
+        same_origin = same_origin && url.origin() == origin;
+        if let Some(login) = login.as_ref().filter(|_| same_origin) {
+            request = request.header("Authorization", format!("Basic {}", login));
+        }
+
+        let response = request.await.map_err(|e| {
+            ShellError::labeled_error("url could not be opened", e.to_string(), span)
+        })?;
+
+        let location = match response.status() {
+            StatusCode::MovedPermanently
+            | StatusCode::Found
+            | StatusCode::SeeOther
+            | StatusCode::TemporaryRedirect
+            | StatusCode::PermanentRedirect => response.header("location").map(|l| l.last()),
+            _ => None,
+        };
+
+        match location {
+            Some(location) if redirects < MAX_REDIRECTS => {
+                redirects += 1;
+                url = url.join(location.as_str()).map_err(|e| {
+                    ShellError::labeled_error(
+                        format!("Redirected to an incorrect url: {}", location),
+                        e.to_string(),
+                        span,
+                    )
+                })?;
             }
-            // TODO: Should this return "nothing" or Err?
-            None => Ok((
-                None,
-                UntaggedValue::string("No content type found".to_owned()).into_value(tag),
-            )),
-        },
-        Err(e) => Err(ShellError::labeled_error(
-            "url could not be opened",
-            e.to_string(),
+            Some(_) => {
+                return Err(ShellError::labeled_error(
+                    format!("Gave up after {} redirects", MAX_REDIRECTS),
+                    "too many redirects",
+                    span,
+                ))
+            }
+            None => break response,
+        }
+    };
+
+    if !r.status().is_success() {
+        let body = r.body_string().await.unwrap_or_default();
+        let body = body.trim();
+        let excerpt = match body.char_indices().nth(MAX_ERROR_BODY_LENGTH) {
+            Some((end, _)) => format!("{}...", &body[..end]),
+            None if body.is_empty() => "the response has no body".to_string(),
+            None => body.to_string(),
+        };
+
+        return Err(ShellError::labeled_error(
+            format!(
+                "Request failed with status {} {}",
+                r.status() as u16,
+                r.status().canonical_reason()
+            ),
+            excerpt,
             span,
-        )),
+        ));
+    }
+
+    let content_type = r
+        .header("content-type")
+        .and_then(|values| values.get(0))
+        .and_then(|value| mime::Mime::from_str(value.as_str()).ok());
+
+    let (file_extension, is_binary) = match &content_type {
+        Some(content_type) => content_format(content_type),
+        None => (None, false),
+    };
+
+    let bytes = r
+        .body_bytes()
+        .await
+        .map_err(|e| generate_error("body", e, &span))?;
+
+    // Anything that isn't binary by its type is text, unless it turns out not to be UTF-8
+    let value = if is_binary {
+        UntaggedValue::binary(bytes)
+    } else {
+        match String::from_utf8(bytes) {
+            Ok(text) => UntaggedValue::string(text),
+            Err(err) => UntaggedValue::binary(err.into_bytes()),
+        }
+    };
+
+    Ok((file_extension, value.into_value(tag)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_format, fetch, url_extension};
+    use futures::executor::block_on;
+    use nu_protocol::{CommandAction, ReturnSuccess, UntaggedValue};
+    use nu_source::Tag;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::str::FromStr;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::time::Duration;
+
+    /// Serves the given responses, one per connection, and returns the URL of the server
+    fn serve(responses: Vec<String>) -> String {
+        serve_recording(responses).0
+    }
+
+    /// Like `serve`, but also hands over each request the server gets
+    fn serve_recording(responses: Vec<String>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("can listen on a local port");
+        let url = format!("http://{}", listener.local_addr().expect("has an address"));
+        let (requests, received) = channel();
+
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().expect("accepts a connection");
+                let mut request = [0; 4096];
+                let read = stream.read(&mut request).unwrap_or(0);
+                let _ = requests.send(String::from_utf8_lossy(&request[..read]).to_lowercase());
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        (url, received)
+    }
+
+    fn response(status: &str, headers: &[&str], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {}\r\ncontent-length: {}\r\n", status, body.len());
+        for header in headers {
+            response.push_str(&format!("{}\r\n", header));
+        }
+        response.push_str("connection: close\r\n\r\n");
+        response.push_str(body);
+        response
+    }
+
+    fn get(url: &str, raw: bool) -> Result<ReturnSuccess, nu_errors::ShellError> {
+        let path = UntaggedValue::string(url).into_value(Tag::unknown());
+        block_on(fetch(&path, raw, None, None, Duration::from_secs(5)))
+    }
+
+    fn converted(result: ReturnSuccess) -> (String, String) {
+        match result {
+            ReturnSuccess::Action(CommandAction::AutoConvert(value, extension)) => {
+                (value.as_string().expect("the body is text"), extension)
+            }
+            _ => panic!("expected the body to be converted"),
+        }
+    }
+
+    #[test]
+    fn picks_the_format_from_the_content_type() {
+        let url = serve(vec![response(
+            "200 OK",
+            &["content-type: application/json; charset=utf-8"],
+            r#"{"stargazers_count": 42}"#,
+        )]);
+
+        let (body, extension) = converted(get(&format!("{}/repos/nu", url), false).unwrap());
+
+        assert_eq!(extension, "json");
+        assert_eq!(body, r#"{"stargazers_count": 42}"#);
+    }
+
+    #[test]
+    fn falls_back_to_the_extension_of_the_url() {
+        let url = serve(vec![response(
+            "200 OK",
+            &["content-type: text/plain"],
+            "a,b",
+        )]);
+
+        let (_, extension) = converted(get(&format!("{}/data.csv", url), false).unwrap());
+
+        assert_eq!(extension, "csv");
+    }
+
+    #[test]
+    fn raw_skips_the_conversion() {
+        let url = serve(vec![response(
+            "200 OK",
+            &["content-type: application/json"],
+            "{}",
+        )]);
+
+        match get(&url, true).unwrap() {
+            ReturnSuccess::Value(value) => assert_eq!(value.as_string().unwrap(), "{}"),
+            _ => panic!("expected the body as it is"),
+        }
+    }
+
+    #[test]
+    fn follows_redirects() {
+        let target = serve(vec![response("200 OK", &["content-type: text/csv"], "a,b")]);
+        let redirects = serve(vec![response(
+            "302 Found",
+            &[&format!("location: {}/data", target)],
+            "",
+        )]);
+
+        let (body, extension) = converted(get(&redirects, false).unwrap());
+
+        assert_eq!(extension, "csv");
+        assert_eq!(body, "a,b");
+    }
+
+    fn get_as_user(url: &str) -> Result<ReturnSuccess, nu_errors::ShellError> {
+        let path = UntaggedValue::string(url).into_value(Tag::unknown());
+        block_on(fetch(
+            &path,
+            true,
+            Some("user".to_string()),
+            Some("secret".to_string()),
+            Duration::from_secs(5),
+        ))
+    }
+
+    #[test]
+    fn keeps_credentials_on_redirects_to_the_same_origin() {
+        let (url, requests) = serve_recording(vec![
+            response("302 Found", &["location: /data"], ""),
+            response("200 OK", &["content-type: text/plain"], "a"),
+        ]);
+
+        get_as_user(&url).unwrap();
+
+        for request in requests.iter().take(2) {
+            assert!(request.contains("authorization: basic"), "{}", request);
+        }
+    }
+
+    #[test]
+    fn drops_credentials_on_redirects_to_another_origin() {
+        let (target, target_requests) =
+            serve_recording(vec![response("200 OK", &["content-type: text/plain"], "a")]);
+        let (redirects, redirect_requests) = serve_recording(vec![response(
+            "302 Found",
+            &[&format!("location: {}/data", target)],
+            "",
+        )]);
+
+        get_as_user(&redirects).unwrap();
+
+        let redirect_request = redirect_requests
+            .recv()
+            .expect("the redirect was requested");
+        let target_request = target_requests.recv().expect("the target was requested");
+
+        assert!(redirect_request.contains("authorization: basic"));
+        assert!(
+            !target_request.contains("authorization"),
+            "{}",
+            target_request
+        );
+    }
+
+    #[test]
+    fn gives_up_on_endless_redirects() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("can listen on a local port");
+        let url = format!("http://{}", listener.local_addr().expect("has an address"));
+        let redirect = response("302 Found", &["location: /again"], "");
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.expect("accepts a connection");
+                let mut request = [0; 4096];
+                let _ = stream.read(&mut request);
+                let _ = stream.write_all(redirect.as_bytes());
+            }
+        });
+
+        let error = format!("{:?}", get(&url, false).unwrap_err());
+
+        assert!(error.contains("Gave up after 5 redirects"));
+    }
+
+    #[test]
+    fn failed_requests_are_errors_with_the_status_and_body() {
+        let url = serve(vec![response(
+            "404 Not Found",
+            &["content-type: application/json"],
+            r#"{"message": "Not Found"}"#,
+        )]);
+
+        let error = format!("{:?}", get(&url, false).unwrap_err());
+
+        assert!(error.contains("404 Not Found"));
+        assert!(error.contains(r#"{\"message\": \"Not Found\"}"#));
+    }
+
+    #[test]
+    fn gives_up_after_the_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("can listen on a local port");
+        let url = format!("http://{}", listener.local_addr().expect("has an address"));
+        let path = UntaggedValue::string(url).into_value(Tag::unknown());
+
+        let result = block_on(fetch(&path, false, None, None, Duration::from_millis(200)));
+
+        assert!(format!("{:?}", result.unwrap_err()).contains("timed out"));
+        drop(listener);
+    }
+
+    #[test]
+    fn knows_the_formats_of_common_content_types() {
+        let format = |content_type: &str| {
+            content_format(&mime::Mime::from_str(content_type).expect("a valid MIME type"))
+        };
+
+        assert_eq!(
+            format("application/vnd.github+json").0.as_deref(),
+            Some("json")
+        );
+        assert_eq!(format("text/xml").0.as_deref(), Some("xml"));
+        assert_eq!(format("application/x-yaml").0.as_deref(), Some("yaml"));
+        assert_eq!(format("image/png"), (Some("png".to_string()), true));
+        assert_eq!(format("text/plain").0, None);
+    }
+
+    #[test]
+    fn finds_the_extension_of_the_url() {
+        assert_eq!(
+            url_extension("https://example.com/a/data.json?page=2").as_deref(),
+            Some("json")
+        );
+        assert_eq!(
+            url_extension("https://api.github.com/repos/nushell/nushell"),
+            None
+        );
     }
 }
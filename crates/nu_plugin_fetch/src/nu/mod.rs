@@ -27,6 +27,12 @@ impl Plugin for Fetch {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "timeout",
+                SyntaxShape::Int,
+                "how many seconds to wait for a response (defaults to 30)",
+                Some('t'),
+            )
             .switch("raw", "fetch contents as text rather than a table", Some('r'))
             .filter())
     }
@@ -40,6 +46,7 @@ impl Plugin for Fetch {
             self.has_raw,
             self.user.clone(),
             self.password.clone(),
+            self.timeout,
         ))])
     }
 }
@@ -3,6 +3,7 @@ use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, ReturnValue, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
 use rusqlite::{types::ValueRef, Connection, Row, NO_PARAMS};
+use std::convert::TryFrom;
 use std::io::Write;
 use std::path::Path;
 
@@ -10,6 +11,7 @@ use std::path::Path;
 pub struct FromSqlite {
     pub state: Vec<u8>,
     pub name_tag: Tag,
+    pub limit: Option<u64>,
 }
 
 impl FromSqlite {
@@ -17,41 +19,47 @@ impl FromSqlite {
         FromSqlite {
             state: vec![],
             name_tag: Tag::unknown(),
+            limit: None,
         }
     }
 }
 
+/// Reads every table of the database into a record with a column per table, holding at most
+/// `limit` rows of each table when given
 pub fn convert_sqlite_file_to_nu_value(
     path: &Path,
     tag: impl Into<Tag> + Clone,
+    limit: Option<u64>,
 ) -> Result<Value, rusqlite::Error> {
     let conn = Connection::open(path)?;
 
-    let mut meta_out = Vec::new();
+    let mut tables = TaggedDictBuilder::new(tag.clone());
     let mut meta_stmt = conn.prepare("select name from sqlite_master where type='table'")?;
     let mut meta_rows = meta_stmt.query(NO_PARAMS)?;
 
+    // A negative limit is no limit at all to SQLite
+    let limit = limit
+        .and_then(|limit| i64::try_from(limit).ok())
+        .unwrap_or(-1);
+
     while let Some(meta_row) = meta_rows.next()? {
         let table_name: String = meta_row.get(0)?;
-        let mut meta_dict = TaggedDictBuilder::new(tag.clone());
         let mut out = Vec::new();
-        let mut table_stmt = conn.prepare(&format!("select * from [{}]", table_name))?;
-        let mut table_rows = table_stmt.query(NO_PARAMS)?;
+        let mut table_stmt = conn.prepare(&format!(
+            "select * from [{}] limit ?",
+            table_name.replace("]", "]]")
+        ))?;
+        let mut table_rows = table_stmt.query(&[limit])?;
         while let Some(table_row) = table_rows.next()? {
             out.push(convert_sqlite_row_to_nu_value(table_row, tag.clone()))
         }
-        meta_dict.insert_value(
-            "table_name".to_string(),
-            UntaggedValue::Primitive(Primitive::String(table_name)).into_value(tag.clone()),
-        );
-        meta_dict.insert_value(
-            "table_values",
+        tables.insert_value(
+            table_name,
             UntaggedValue::Table(out).into_value(tag.clone()),
         );
-        meta_out.push(meta_dict.into_value());
     }
-    let tag = tag.into();
-    Ok(UntaggedValue::Table(meta_out).into_value(tag))
+
+    Ok(tables.into_value())
 }
 
 fn convert_sqlite_row_to_nu_value(row: &Row, tag: impl Into<Tag> + Clone) -> Value {
@@ -67,9 +75,7 @@ fn convert_sqlite_row_to_nu_value(row: &Row, tag: impl Into<Tag> + Clone) -> Val
 
 fn convert_sqlite_value_to_nu_value(value: ValueRef, tag: impl Into<Tag> + Clone) -> Value {
     match value {
-        ValueRef::Null => {
-            UntaggedValue::Primitive(Primitive::String(String::from(""))).into_value(tag)
-        }
+        ValueRef::Null => UntaggedValue::nothing().into_value(tag),
         ValueRef::Integer(i) => UntaggedValue::int(i).into_value(tag),
         ValueRef::Real(f) => {
             let f = bigdecimal::BigDecimal::from_f64(f);
@@ -97,6 +103,7 @@ fn convert_sqlite_value_to_nu_value(value: ValueRef, tag: impl Into<Tag> + Clone
 pub fn from_sqlite_bytes_to_value(
     mut bytes: Vec<u8>,
     tag: impl Into<Tag> + Clone,
+    limit: Option<u64>,
 ) -> Result<Value, std::io::Error> {
     // FIXME: should probably write a sqlite virtual filesystem
     // that will allow us to use bytes as a file to avoid this
@@ -104,21 +111,19 @@ pub fn from_sqlite_bytes_to_value(
     // best done as a PR to rusqlite.
     let mut tempfile = tempfile::NamedTempFile::new()?;
     tempfile.write_all(bytes.as_mut_slice())?;
-    match convert_sqlite_file_to_nu_value(tempfile.path(), tag) {
+    match convert_sqlite_file_to_nu_value(tempfile.path(), tag, limit) {
         Ok(value) => Ok(value),
         Err(e) => Err(std::io::Error::new(std::io::ErrorKind::Other, e)),
     }
 }
 
-pub fn from_sqlite(bytes: Vec<u8>, name_tag: Tag) -> Result<Vec<ReturnValue>, ShellError> {
-    match from_sqlite_bytes_to_value(bytes, name_tag.clone()) {
-        Ok(x) => match x {
-            Value {
-                value: UntaggedValue::Table(list),
-                ..
-            } => Ok(list.into_iter().map(ReturnSuccess::value).collect()),
-            _ => Ok(vec![ReturnSuccess::value(x)]),
-        },
+pub fn from_sqlite(
+    bytes: Vec<u8>,
+    name_tag: Tag,
+    limit: Option<u64>,
+) -> Result<Vec<ReturnValue>, ShellError> {
+    match from_sqlite_bytes_to_value(bytes, name_tag.clone(), limit) {
+        Ok(x) => Ok(vec![ReturnSuccess::value(x)]),
         Err(_) => Err(ShellError::labeled_error(
             "Could not parse as SQLite",
             "input cannot be parsed as SQLite",
@@ -4,18 +4,28 @@ mod tests;
 use crate::FromSqlite;
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
-use nu_protocol::{CallInfo, Primitive, ReturnValue, Signature, UntaggedValue, Value};
+use nu_protocol::{CallInfo, Primitive, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tag;
 
 impl Plugin for FromSqlite {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("from sqlite")
-            .desc("Convert from sqlite binary into table")
+            .desc("Convert from sqlite binary into a record with a column per table")
+            .named(
+                "limit",
+                SyntaxShape::Int,
+                "read at most this many rows of each table",
+                Some('l'),
+            )
             .filter())
     }
 
     fn begin_filter(&mut self, call_info: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
         self.name_tag = call_info.name_tag;
+        self.limit = match call_info.args.get("limit") {
+            Some(limit) => Some(limit.as_u64()?),
+            None => None,
+        };
         Ok(vec![])
     }
 
@@ -27,6 +37,13 @@ impl Plugin for FromSqlite {
             } => {
                 self.state.extend_from_slice(&b);
             }
+            // `open --raw` streams the parts of the file that happen to be valid UTF-8 as text
+            Value {
+                value: UntaggedValue::Primitive(Primitive::String(s)),
+                ..
+            } => {
+                self.state.extend_from_slice(s.as_bytes());
+            }
             Value { tag, .. } => {
                 return Err(ShellError::labeled_error_with_secondary(
                     "Expected binary from pipeline",
@@ -41,6 +58,6 @@ impl Plugin for FromSqlite {
     }
 
     fn end_filter(&mut self) -> Result<Vec<ReturnValue>, ShellError> {
-        crate::from_sqlite::from_sqlite(self.state.clone(), Tag::unknown())
+        crate::from_sqlite::from_sqlite(self.state.clone(), Tag::unknown(), self.limit)
     }
 }
@@ -1 +1,95 @@
-mod integration {}
+mod integration {
+    use crate::FromSqlite;
+    use nu_plugin::test_helpers::{plugin, CallStub};
+    use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue, Value};
+    use nu_source::{Span, SpannedItem, Tag};
+    use nu_value_ext::ValueExt;
+
+    fn sample_db() -> Value {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../tests/fixtures/formats/sample.db");
+        let bytes = std::fs::read(path).expect("the sample database exists");
+
+        UntaggedValue::binary(bytes).into_value(Tag::unknown())
+    }
+
+    fn open(call_stub: &mut CallStub) -> Value {
+        let mut returned = plugin(&mut FromSqlite::new())
+            .args(call_stub.create())
+            .input(sample_db())
+            .setup(|_, _| {})
+            .test()
+            .expect("the sample database can be read");
+
+        match returned.pop() {
+            Some(Ok(ReturnSuccess::Value(value))) => value,
+            _ => panic!("expected the database as a value"),
+        }
+    }
+
+    fn table(database: &Value, name: &str) -> Vec<Value> {
+        match database.get_data_by_key(name.spanned_unknown()) {
+            Some(Value {
+                value: UntaggedValue::Table(rows),
+                ..
+            }) => rows,
+            _ => panic!("expected a table named {}", name),
+        }
+    }
+
+    fn cell(row: &Value, column: &str) -> UntaggedValue {
+        row.get_data_by_key(column.spanned_unknown())
+            .expect("the column exists")
+            .value
+    }
+
+    #[test]
+    fn reads_a_column_per_table() {
+        let database = open(&mut CallStub::new());
+
+        assert_eq!(table(&database, "strings").len(), 6);
+        assert_eq!(table(&database, "ints").len(), 5);
+        assert_eq!(table(&database, "floats").len(), 4);
+    }
+
+    #[test]
+    fn reads_integers_reals_text_and_nulls() {
+        let database = open(&mut CallStub::new());
+
+        let ints = table(&database, "ints");
+        assert_eq!(cell(&ints[3], "z"), UntaggedValue::int(4253));
+        assert_eq!(cell(&ints[4], "z"), UntaggedValue::nothing());
+
+        let floats = table(&database, "floats");
+        assert_eq!(
+            cell(&floats[2], "f"),
+            UntaggedValue::decimal_from_float(23.0, Span::unknown())
+        );
+
+        let strings = table(&database, "strings");
+        assert_eq!(cell(&strings[4], "x"), UntaggedValue::string("world"));
+    }
+
+    #[test]
+    fn reads_blobs_as_binary() {
+        let database = open(&mut CallStub::new());
+        let strings = table(&database, "strings");
+
+        assert_eq!(
+            cell(&strings[0], "y"),
+            UntaggedValue::Primitive(Primitive::Binary(vec![0x00, 0x01]))
+        );
+    }
+
+    #[test]
+    fn reads_at_most_the_limit_of_rows_per_table() {
+        let database = open(
+            CallStub::new()
+                .with_named_parameter("limit", UntaggedValue::int(2).into_untagged_value()),
+        );
+
+        assert_eq!(table(&database, "strings").len(), 2);
+        assert_eq!(table(&database, "ints").len(), 2);
+        assert_eq!(table(&database, "floats").len(), 2);
+    }
+}
@@ -2,6 +2,7 @@ use nu_errors::ShellError;
 use nu_protocol::{did_you_mean, ColumnPath, Primitive, ShellTypeName, UntaggedValue, Value};
 use nu_source::{span_for_spanned_list, HasSpan, SpannedItem, Tagged};
 use nu_value_ext::{get_data_by_column_path, ValueExt};
+use num_traits::cast::ToPrimitive;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Action {
@@ -21,6 +22,9 @@ pub struct Inc {
     pub field: Option<Tagged<ColumnPath>>,
     pub error: Option<String>,
     pub action: Option<Action>,
+    pub by: Option<i64>,
+    pub decrement: bool,
+    pub row: usize,
 }
 
 impl Inc {
@@ -28,13 +32,51 @@ impl Inc {
         Default::default()
     }
 
-    fn apply(&self, input: &str) -> UntaggedValue {
+    /// The `dec` sibling, which counts integers down rather than up
+    pub fn dec() -> Self {
+        Inc {
+            decrement: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        if self.decrement {
+            "dec"
+        } else {
+            "inc"
+        }
+    }
+
+    /// Steps a number up, or down with `dec`, unless that would overflow
+    fn stepped(&self, n: i64) -> Option<i64> {
+        let by = self.by.unwrap_or(1);
+
+        if self.decrement {
+            n.checked_sub(by)
+        } else {
+            n.checked_add(by)
+        }
+    }
+
+    fn out_of_range(&self, value: &Value, reason: &str) -> ShellError {
+        ShellError::labeled_error(
+            format!(
+                "Can't {} by {} in row {}",
+                self.name(),
+                self.by.unwrap_or(1),
+                self.row
+            ),
+            reason,
+            value.tag(),
+        )
+    }
+
+    /// Applies the action to a string, if it holds something the action works on
+    fn apply(&self, input: &str) -> Option<UntaggedValue> {
         match &self.action {
             Some(Action::SemVerAction(act_on)) => {
-                let mut ver = match semver::Version::parse(&input) {
-                    Ok(parsed_ver) => parsed_ver,
-                    Err(_) => return UntaggedValue::string(input.to_string()),
-                };
+                let mut ver = semver::Version::parse(&input).ok()?;
 
                 match act_on {
                     SemVerAction::Major => ver.increment_major(),
@@ -42,12 +84,12 @@ impl Inc {
                     SemVerAction::Patch => ver.increment_patch(),
                 }
 
-                UntaggedValue::string(ver.to_string())
+                Some(UntaggedValue::string(ver.to_string()))
+            }
+            Some(Action::Default) | None => {
+                let v = self.stepped(input.parse::<i64>().ok()?)?;
+                Some(UntaggedValue::string(format!("{}", v)))
             }
-            Some(Action::Default) | None => match input.parse::<u64>() {
-                Ok(v) => UntaggedValue::string(format!("{}", v + 1)),
-                Err(_) => UntaggedValue::string(input),
-            },
         }
     }
 
@@ -67,21 +109,48 @@ impl Inc {
         self.error = Some(message.to_string());
     }
 
-    pub fn usage() -> &'static str {
-        "Usage: inc field [--major|--minor|--patch]"
+    pub fn usage(&self) -> &'static str {
+        if self.decrement {
+            "Usage: dec field [--by <integer>]"
+        } else {
+            "Usage: inc field [--major|--minor|--patch|--by <integer>]"
+        }
     }
 
     pub fn inc(&self, value: Value) -> Result<Value, ShellError> {
         match &value.value {
-            UntaggedValue::Primitive(Primitive::Int(i)) => {
-                Ok(UntaggedValue::int(i + 1).into_value(value.tag()))
+            UntaggedValue::Primitive(Primitive::Int(_)) => {
+                let stepped = self.stepped(value.as_i64()?).ok_or_else(|| {
+                    self.out_of_range(&value, "the result doesn't fit in a 64-bit integer")
+                })?;
+
+                Ok(UntaggedValue::int(stepped).into_value(value.tag()))
             }
             UntaggedValue::Primitive(Primitive::Filesize(b)) => {
-                Ok(UntaggedValue::filesize(b + 1_u64).into_value(value.tag()))
-            }
-            UntaggedValue::Primitive(Primitive::String(ref s)) => {
-                Ok(self.apply(&s).into_value(value.tag()))
+                let stepped = b
+                    .to_i64()
+                    .and_then(|b| self.stepped(b))
+                    .filter(|size| *size >= 0)
+                    .ok_or_else(|| self.out_of_range(&value, "a file size can't go below zero"))?;
+
+                Ok(UntaggedValue::filesize(stepped as u64).into_value(value.tag()))
             }
+            UntaggedValue::Primitive(Primitive::String(ref s)) => match self.apply(&s) {
+                Some(applied) => Ok(applied.into_value(value.tag())),
+                None => {
+                    let expected = match (&self.action, self.decrement) {
+                        (Some(Action::SemVerAction(_)), _) => "a version",
+                        (_, true) => "an integer",
+                        (_, false) => "an integer or, with --major, --minor or --patch, a version",
+                    };
+
+                    Err(ShellError::labeled_error(
+                        format!("Can't {} '{}' in row {}", self.name(), s, self.row),
+                        format!("expected {}", expected),
+                        value.tag(),
+                    ))
+                }
+            },
             UntaggedValue::Table(values) => {
                 if values.len() == 1 {
                     Ok(UntaggedValue::Table(vec![self.inc(values[0].clone())?])
@@ -125,15 +194,16 @@ impl Inc {
                         .replace_data_at_column_path(&f, replacement.value.into_untagged_value())
                         .ok_or_else(|| {
                             ShellError::labeled_error(
-                                "inc could not find field to replace",
+                                format!("{} could not find field to replace", self.name()),
                                 "column name",
                                 value.tag(),
                             )
                         })
                 }
-                None => Err(ShellError::untagged_runtime_error(
-                    "inc needs a field when incrementing a column in a table",
-                )),
+                None => Err(ShellError::untagged_runtime_error(format!(
+                    "{} needs a field when changing a column in a table",
+                    self.name()
+                ))),
             },
             _ => Err(ShellError::type_error(
                 "incrementable value",
@@ -154,21 +224,99 @@ mod tests {
         fn major() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Major);
-            assert_eq!(inc.apply("0.1.3"), string("1.0.0").value);
+            assert_eq!(inc.apply("0.1.3"), Some(string("1.0.0").value));
         }
 
         #[test]
         fn minor() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Minor);
-            assert_eq!(inc.apply("0.1.3"), string("0.2.0").value);
+            assert_eq!(inc.apply("0.1.3"), Some(string("0.2.0").value));
         }
 
         #[test]
         fn patch() {
             let mut inc = Inc::new();
             inc.for_semver(SemVerAction::Patch);
-            assert_eq!(inc.apply("0.1.3"), string("0.1.4").value);
+            assert_eq!(inc.apply("0.1.3"), Some(string("0.1.4").value));
+        }
+
+        #[test]
+        fn resets_the_lower_segments() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Minor);
+            assert_eq!(inc.apply("1.4.7-beta.2"), Some(string("1.5.0").value));
+        }
+
+        #[test]
+        fn leaves_what_is_not_a_version() {
+            let mut inc = Inc::new();
+            inc.for_semver(SemVerAction::Patch);
+            assert_eq!(inc.apply("nightly"), None);
+        }
+    }
+
+    mod numbers {
+        use crate::Inc;
+        use nu_protocol::UntaggedValue;
+
+        #[test]
+        fn errors_on_overflowing_integers() {
+            let inc = Inc::new();
+            let max = UntaggedValue::int(i64::MAX).into_untagged_value();
+
+            assert!(inc.inc(max).is_err());
+        }
+
+        #[test]
+        fn errors_on_file_sizes_below_zero() {
+            let dec = Inc::dec();
+            let empty = UntaggedValue::filesize(0u64).into_untagged_value();
+
+            assert!(dec.inc(empty).is_err());
+        }
+
+        #[test]
+        fn steps_file_sizes() {
+            let mut dec = Inc::dec();
+            dec.by = Some(512);
+            let size = UntaggedValue::filesize(2048u64).into_untagged_value();
+
+            assert_eq!(
+                dec.inc(size).ok().map(|size| size.value),
+                Some(UntaggedValue::filesize(1536u64))
+            );
+        }
+    }
+
+    mod integer {
+        use crate::Inc;
+        use nu_test_support::value::string;
+
+        #[test]
+        fn by_one() {
+            let inc = Inc::new();
+            assert_eq!(inc.apply("41"), Some(string("42").value));
+        }
+
+        #[test]
+        fn by_the_given_step() {
+            let mut inc = Inc::new();
+            inc.by = Some(10);
+            assert_eq!(inc.apply("41"), Some(string("51").value));
+        }
+
+        #[test]
+        fn down_with_dec() {
+            let mut dec = Inc::dec();
+            dec.by = Some(2);
+            assert_eq!(dec.apply("0"), Some(string("-2").value));
+        }
+
+        #[test]
+        fn leaves_what_would_overflow() {
+            let inc = Inc::new();
+            assert_eq!(inc.apply(&i64::MAX.to_string()), None);
         }
     }
 }
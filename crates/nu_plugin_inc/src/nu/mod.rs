@@ -14,6 +14,19 @@ use nu_value_ext::ValueExt;
 
 impl Plugin for Inc {
     fn config(&mut self) -> Result<Signature, ShellError> {
+        if self.decrement {
+            return Ok(Signature::build("dec")
+                .desc("Decrement a value. Optionally use the column of a table.")
+                .named(
+                    "by",
+                    SyntaxShape::Int,
+                    "the amount to decrement integers by (1 by default)",
+                    Some('b'),
+                )
+                .rest(SyntaxShape::ColumnPath, "the column(s) to update")
+                .filter());
+        }
+
         Ok(Signature::build("inc")
             .desc("Increment a value or version. Optionally use the column of a table.")
             .switch(
@@ -31,6 +44,12 @@ impl Plugin for Inc {
                 "increment the patch version (eg 1.2.1 -> 1.2.2)",
                 Some('p'),
             )
+            .named(
+                "by",
+                SyntaxShape::Int,
+                "the amount to increment integers by (1 by default)",
+                Some('b'),
+            )
             .rest(SyntaxShape::ColumnPath, "the column(s) to update")
             .filter())
     }
@@ -45,6 +64,9 @@ impl Plugin for Inc {
         if call_info.args.has("patch") {
             self.for_semver(SemVerAction::Patch);
         }
+        if let Some(by) = call_info.args.get("by") {
+            self.by = Some(by.as_i64()?);
+        }
 
         if let Some(args) = call_info.args.positional {
             for arg in args {
@@ -75,13 +97,16 @@ impl Plugin for Inc {
             Some(reason) => Err(ShellError::untagged_runtime_error(format!(
                 "{}: {}",
                 reason,
-                Inc::usage()
+                self.usage()
             ))),
             None => Ok(vec![]),
         }
     }
 
     fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
-        Ok(vec![ReturnSuccess::value(self.inc(input)?)])
+        let result = self.inc(input);
+        self.row += 1;
+
+        Ok(vec![ReturnSuccess::value(result?)])
     }
 }
@@ -69,6 +69,72 @@ mod integration {
             });
         Ok(())
     }
+
+    #[test]
+    fn picks_up_by_flag() {
+        plugin(&mut Inc::new())
+            .args(
+                CallStub::new()
+                    .with_named_parameter("by", UntaggedValue::int(5).into_untagged_value())
+                    .create(),
+            )
+            .setup(|plugin, _| assert_eq!(plugin.by, Some(5)));
+    }
+
+    mod integer {
+        use crate::Inc;
+        use nu_plugin::test_helpers::{expect_return_value_at, plugin, CallStub};
+        use nu_protocol::UntaggedValue;
+        use nu_test_support::value::{int, string};
+
+        #[test]
+        fn increments_by_the_given_step() {
+            let run = plugin(&mut Inc::new())
+                .args(
+                    CallStub::new()
+                        .with_named_parameter("by", UntaggedValue::int(5).into_untagged_value())
+                        .create(),
+                )
+                .input(int(10))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = expect_return_value_at(run, 0);
+
+            assert_eq!(actual, int(15));
+        }
+
+        #[test]
+        fn decrements_with_dec() {
+            let run = plugin(&mut Inc::dec())
+                .args(CallStub::new().create())
+                .input(string("3"))
+                .setup(|_, _| {})
+                .test();
+
+            let actual = expect_return_value_at(run, 0);
+
+            assert_eq!(actual, string("2"));
+        }
+
+        #[test]
+        fn errors_with_the_row_and_value_that_are_not_integers() {
+            let mut inc = Inc::new();
+            let mut run = plugin(&mut inc);
+
+            run.args(CallStub::new().create())
+                .input(int(1))
+                .setup(|_, _| {})
+                .test()
+                .expect("an integer can be incremented");
+
+            let error = run.input(string("nightly")).test().unwrap_err();
+            let actual = error.into_diagnostic().map(|d| d.message);
+
+            assert_eq!(actual, Some("Can't inc 'nightly' in row 1".to_string()));
+        }
+    }
+
     mod sem_ver {
         use crate::Inc;
         use nu_errors::ShellError;
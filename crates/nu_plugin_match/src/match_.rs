@@ -1,18 +1,22 @@
 use regex::Regex;
 
 pub struct Match {
-    pub column: String,
+    pub column: Option<String>,
     pub regex: Regex,
+    pub captures: Vec<String>,
     pub invert: bool,
+    pub keep: bool,
 }
 
 impl Match {
     #[allow(clippy::trivial_regex)]
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         Ok(Match {
-            column: String::new(),
+            column: None,
             regex: Regex::new("")?,
+            captures: vec![],
             invert: false,
+            keep: false,
         })
     }
 }
@@ -1,7 +1,11 @@
+#[cfg(test)]
+mod tests;
+
 use nu_errors::ShellError;
 use nu_plugin::Plugin;
 use nu_protocol::{
-    CallInfo, Primitive, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value,
+    CallInfo, Primitive, ReturnSuccess, ReturnValue, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
 };
 
 use crate::Match;
@@ -10,9 +14,19 @@ use regex::Regex;
 impl Plugin for Match {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("match")
-            .desc("Filter rows by Regex pattern.")
-            .required("member", SyntaxShape::String, "the column name to match")
+            .desc("Filter strings, or rows by a column, with a regex, giving its named captures as columns.")
             .required("regex", SyntaxShape::String, "the regex to match with")
+            .named(
+                "column",
+                SyntaxShape::String,
+                "the column of the rows to match",
+                Some('c'),
+            )
+            .switch(
+                "keep",
+                "keep what doesn't match, with empty captures",
+                Some('k'),
+            )
             .switch("insensitive", "case-insensitive search", Some('i'))
             .switch(
                 "multiline",
@@ -33,22 +47,27 @@ impl Plugin for Match {
         let multiline = call_info.args.has("multiline");
         let dotall = call_info.args.has("dotall");
         self.invert = call_info.args.has("invert");
-        if let Some(args) = call_info.args.positional {
-            match &args[0] {
+        self.keep = call_info.args.has("keep");
+
+        if let Some(column) = call_info.args.get("column") {
+            match column {
                 Value {
                     value: UntaggedValue::Primitive(Primitive::String(s)),
                     ..
                 } => {
-                    self.column = s.clone();
+                    self.column = Some(s.clone());
                 }
                 Value { tag, .. } => {
                     return Err(ShellError::labeled_error(
                         "Unrecognized type in params",
-                        "value",
+                        "expected a column name",
                         tag,
                     ));
                 }
             }
+        }
+
+        if let Some(args) = call_info.args.positional {
             let flags = match (insensitive, multiline, dotall) {
                 (false, false, false) => "",
                 (true, false, false) => "(?i)",
@@ -60,18 +79,25 @@ impl Plugin for Match {
                 (true, true, true) => "(?ims)",
             }
             .to_owned();
-            match &args[1] {
+            match &args[0] {
                 Value {
                     value: UntaggedValue::Primitive(Primitive::String(s)),
                     tag,
                 } => {
+                    // The regex is compiled once here, and used for every value of the stream
                     self.regex = Regex::new(&(flags + s)).map_err(|_| {
                         ShellError::labeled_error(
-                            "Internal error while creating regex",
-                            "internal error created by pattern",
+                            "Invalid regular expression",
+                            "this pattern isn't a valid regex",
                             tag,
                         )
                     })?;
+                    self.captures = self
+                        .regex
+                        .capture_names()
+                        .flatten()
+                        .map(String::from)
+                        .collect();
                 }
                 Value { tag, .. } => {
                     return Err(ShellError::labeled_error(
@@ -87,38 +113,75 @@ impl Plugin for Match {
     }
 
     fn filter(&mut self, input: Value) -> Result<Vec<ReturnValue>, ShellError> {
-        let flag: bool;
-        match &input {
-            Value {
-                value: UntaggedValue::Row(dict),
-                tag,
-            } => {
-                if let Some(val) = dict.entries.get(&self.column) {
-                    if let Ok(s) = val.as_string() {
-                        flag = self.regex.is_match(&s);
-                    } else {
-                        return Err(ShellError::labeled_error(
-                            "expected string",
-                            "value",
-                            val.tag(),
-                        ));
-                    }
-                } else {
+        let text = match (&self.column, &input) {
+            (
+                Some(column),
+                Value {
+                    value: UntaggedValue::Row(dict),
+                    tag,
+                },
+            ) => match dict.entries.get(column) {
+                Some(val) => val.as_string().map_err(|_| {
+                    ShellError::labeled_error("Expected a string", "value", val.tag())
+                })?,
+                None => {
                     return Err(ShellError::labeled_error(
-                        format!("column not in row! {:?} {:?}", &self.column, dict),
-                        "row",
+                        format!("Unknown column '{}'", column),
+                        "row without the column",
                         tag,
-                    ));
+                    ))
                 }
+            },
+            (None, Value { tag, .. }) if input.is_row() => {
+                return Err(ShellError::labeled_error(
+                    "Rows need a column to match",
+                    "use --column to pick one",
+                    tag,
+                ));
             }
-            Value { tag, .. } => {
+            (None, _) => input.as_string()?,
+            (Some(_), Value { tag, .. }) => {
                 return Err(ShellError::labeled_error("Expected row", "value", tag));
             }
+        };
+
+        let captures = self.regex.captures(&text);
+
+        if captures.is_some() == self.invert && !self.keep {
+            return Ok(vec![]);
+        }
+
+        // Without named groups, matching only decides what is kept
+        if self.captures.is_empty() {
+            return Ok(vec![Ok(ReturnSuccess::Value(input))]);
+        }
+
+        let mut dict = TaggedDictBuilder::new(&input.tag);
+
+        if let Value {
+            value: UntaggedValue::Row(row),
+            ..
+        } = &input
+        {
+            for (column, value) in row.entries.iter() {
+                dict.insert_value(column, value.clone());
+            }
         }
-        if flag ^ self.invert {
-            Ok(vec![Ok(ReturnSuccess::Value(input))])
-        } else {
-            Ok(vec![])
+
+        for name in self.captures.iter() {
+            let captured = captures
+                .as_ref()
+                .filter(|_| !self.invert)
+                .and_then(|captures| captures.name(name));
+
+            match captured {
+                Some(captured) => {
+                    dict.insert_untagged(name, UntaggedValue::string(captured.as_str()))
+                }
+                None => dict.insert_untagged(name, UntaggedValue::nothing()),
+            }
         }
+
+        Ok(vec![Ok(ReturnSuccess::Value(dict.into_value()))])
     }
 }
@@ -0,0 +1,132 @@
+mod integration {
+    use crate::Match;
+    use indexmap::IndexMap;
+    use nu_plugin::test_helpers::{expect_return_value_at, plugin};
+    use nu_protocol::{CallInfo, EvaluatedArgs, UntaggedValue, Value};
+    use nu_source::Tag;
+    use nu_value_ext::get_data;
+
+    fn call(pattern: &str, flags: &[(&str, Value)]) -> CallInfo {
+        let named: IndexMap<String, Value> = flags
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+
+        CallInfo {
+            args: EvaluatedArgs::new(Some(vec![string(pattern)]), Some(named)),
+            name_tag: Tag::unknown(),
+        }
+    }
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    fn switch() -> Value {
+        UntaggedValue::boolean(true).into_untagged_value()
+    }
+
+    fn row(entries: &[(&str, &str)]) -> Value {
+        UntaggedValue::row(
+            entries
+                .iter()
+                .map(|(column, value)| (column.to_string(), string(value)))
+                .collect(),
+        )
+        .into_untagged_value()
+    }
+
+    #[test]
+    fn gives_named_captures_as_columns() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        let actual = plugin(&mut plugin_match)
+            .args(call(r"(?P<level>[A-Z]+): (?P<message>.*)", &[]))
+            .setup(|_, _| {})
+            .input(string("ERROR: disk full"))
+            .test();
+
+        let actual = expect_return_value_at(actual, 0);
+
+        assert_eq!(get_data(&actual, "level").borrow(), &string("ERROR"));
+        assert_eq!(get_data(&actual, "message").borrow(), &string("disk full"));
+    }
+
+    #[test]
+    fn drops_what_doesnt_match() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        let actual = plugin(&mut plugin_match)
+            .args(call(r"(?P<level>[A-Z]+): (?P<message>.*)", &[]))
+            .setup(|_, _| {})
+            .input(string("nothing to see here"))
+            .test();
+
+        assert!(actual.expect("the filter failed").is_empty());
+    }
+
+    #[test]
+    fn keeps_what_doesnt_match_with_empty_captures() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        let actual = plugin(&mut plugin_match)
+            .args(call(
+                r"(?P<level>[A-Z]+): (?P<message>.*)",
+                &[("keep", switch())],
+            ))
+            .setup(|_, _| {})
+            .input(string("nothing to see here"))
+            .test();
+
+        let actual = expect_return_value_at(actual, 0);
+
+        assert!(get_data(&actual, "level").borrow().is_none());
+    }
+
+    #[test]
+    fn adds_captures_to_rows_matched_by_column() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        let actual = plugin(&mut plugin_match)
+            .args(call(r"test_(?P<number>\d+)", &[("column", string("name"))]))
+            .setup(|_, _| {})
+            .input(row(&[("name", "test_42.txt"), ("type", "File")]))
+            .test();
+
+        let actual = expect_return_value_at(actual, 0);
+
+        assert_eq!(get_data(&actual, "type").borrow(), &string("File"));
+        assert_eq!(get_data(&actual, "number").borrow(), &string("42"));
+    }
+
+    #[test]
+    fn filters_rows_without_named_captures() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        let actual = plugin(&mut plugin_match)
+            .args(call(
+                r"\.rs$",
+                &[("column", string("name")), ("invert", switch())],
+            ))
+            .setup(|_, _| {})
+            .input(row(&[("name", "Cargo.toml")]))
+            .test();
+
+        let actual = expect_return_value_at(actual, 0);
+
+        assert_eq!(get_data(&actual, "name").borrow(), &string("Cargo.toml"));
+    }
+
+    #[test]
+    fn refuses_an_invalid_regex() {
+        let mut plugin_match = Match::new().expect("the plugin couldn't be created");
+
+        plugin(&mut plugin_match)
+            .args(call(r"test_(\d+", &[]))
+            .setup(|_, returned_values| {
+                let actual = format!("{:?}", returned_values.unwrap_err());
+
+                assert!(actual.contains("Invalid regular expression"));
+            });
+    }
+}
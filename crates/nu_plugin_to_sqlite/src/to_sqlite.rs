@@ -82,34 +82,46 @@ fn get_insert_values(rows: Vec<Value>) -> Result<String, std::io::Error> {
     Ok(values.into_iter().fold("".to_string(), comma_concat))
 }
 
-fn generate_statements(table: Dictionary) -> Result<(String, String), std::io::Error> {
-    let table_name = match table.entries.get("table_name") {
-        Some(Value {
-            value: UntaggedValue::Primitive(Primitive::String(table_name)),
-            ..
-        }) => table_name,
-        _ => {
-            return Err(std::io::Error::new(
+fn generate_statements(
+    table_name: &str,
+    rows: &[Value],
+) -> Result<(String, String), std::io::Error> {
+    let columns = get_columns(rows)?;
+    let insert_values = get_insert_values(rows.to_vec())?;
+    let create = format!("create table {}({})", table_name, columns);
+    let insert = format!("insert into {} values {}", table_name, insert_values);
+    Ok((create, insert))
+}
+
+/// The tables held by a row, which is either a record with a column per table (the way `from
+/// sqlite` reads a database) or a single table given by its `table_name` and `table_values`
+fn get_tables(row: &Dictionary) -> Result<Vec<(String, Vec<Value>)>, std::io::Error> {
+    if let (Some(table_name), Some(table_values)) = (
+        row.entries.get("table_name"),
+        row.entries.get("table_values"),
+    ) {
+        return match (&table_name.value, &table_values.value) {
+            (
+                UntaggedValue::Primitive(Primitive::String(table_name)),
+                UntaggedValue::Table(rows),
+            ) => Ok(vec![(table_name.clone(), rows.clone())]),
+            _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
-                "Could not find table name",
-            ))
-        }
-    };
-    let (columns, insert_values) = match table.entries.get("table_values") {
-        Some(Value {
-            value: UntaggedValue::Table(l),
-            ..
-        }) => (get_columns(l), get_insert_values(l.to_vec())),
-        _ => {
-            return Err(std::io::Error::new(
+                "Could not find table name and values",
+            )),
+        };
+    }
+
+    row.entries
+        .iter()
+        .map(|(table_name, table)| match &table.value {
+            UntaggedValue::Table(rows) => Ok((table_name.clone(), rows.clone())),
+            _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
-                "Could not find table values",
-            ))
-        }
-    };
-    let create = format!("create table {}({})", table_name, columns?);
-    let insert = format!("insert into {} values {}", table_name, insert_values?);
-    Ok((create, insert))
+                format!("Expected {} to be a table", table_name),
+            )),
+        })
+        .collect()
 }
 
 fn sqlite_input_stream_to_bytes(values: Vec<Value>) -> Result<Value, std::io::Error> {
@@ -126,14 +138,22 @@ fn sqlite_input_stream_to_bytes(values: Vec<Value>) -> Result<Value, std::io::Er
     for value in values.into_iter() {
         match &value.value {
             UntaggedValue::Row(d) => {
-                let (create, insert) = generate_statements(d.to_owned())?;
-                match conn
-                    .execute(&create, NO_PARAMS)
-                    .and_then(|_| conn.execute(&insert, NO_PARAMS))
-                {
-                    Ok(_) => (),
-                    Err(e) => {
-                        return Err(std::io::Error::new(std::io::ErrorKind::Other, e));
+                for (table_name, rows) in get_tables(d)? {
+                    // The columns of a table are those of its rows, so there's nothing to
+                    // create for an empty one
+                    if rows.is_empty() {
+                        continue;
+                    }
+
+                    let (create, insert) = generate_statements(&table_name, &rows)?;
+                    match conn
+                        .execute(&create, NO_PARAMS)
+                        .and_then(|_| conn.execute(&insert, NO_PARAMS))
+                    {
+                        Ok(_) => (),
+                        Err(e) => {
+                            return Err(std::io::Error::new(std::io::ErrorKind::Other, e));
+                        }
                     }
                 }
             }
@@ -0,0 +1,53 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu_with_plugins;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn by_one_with_field_passed() {
+    Playground::setup("plugin_dec_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [package]
+                    edition = "2018"
+                "#,
+        )]);
+
+        let actual = nu_with_plugins!(
+            cwd: dirs.test(),
+            "open sample.toml | dec package.edition | get package.edition"
+        );
+
+        assert_eq!(actual.out, "2017");
+    })
+}
+
+#[test]
+fn by_the_given_step() {
+    let actual = nu_with_plugins!(
+        cwd: ".",
+        "echo 10 | dec --by 4"
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn errors_on_versions() {
+    let actual = nu_with_plugins!(
+        cwd: ".",
+        r#"echo "0.1.3" | dec"#
+    );
+
+    assert!(actual.err.contains("Can't dec '0.1.3' in row 0"));
+}
+
+#[test]
+fn errors_on_file_sizes_below_zero() {
+    let actual = nu_with_plugins!(
+        cwd: ".",
+        "echo 1kb | dec --by 2000"
+    );
+
+    assert!(actual.err.contains("a file size can't go below zero"));
+}
@@ -11,7 +11,7 @@ fn can_only_apply_one() {
 
     assert!(actual
         .err
-        .contains("Usage: inc field [--major|--minor|--patch]"));
+        .contains("Usage: inc field [--major|--minor|--patch|--by <integer>]"));
 }
 
 #[test]
@@ -133,3 +133,73 @@ fn semversion_without_passing_field() {
         assert_eq!(actual.out, "0.1.4");
     })
 }
+
+#[test]
+fn by_the_given_step() {
+    Playground::setup("plugin_inc_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [package]
+                    edition = "2018"
+                "#,
+        )]);
+
+        let actual = nu_with_plugins!(
+            cwd: dirs.test(),
+            "open sample.toml | inc package.edition --by 3 | get package.edition"
+        );
+
+        assert_eq!(actual.out, "2021");
+    })
+}
+
+#[test]
+fn semversion_saved_back_to_the_file() {
+    Playground::setup("plugin_inc_test_8", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "Cargo.toml",
+            r#"
+                    [package]
+                    version = "0.27.1"
+                "#,
+        )]);
+
+        let actual = nu_with_plugins!(
+            cwd: dirs.test(),
+            "open Cargo.toml | inc package.version --minor | save Cargo.toml; open Cargo.toml | get package.version"
+        );
+
+        assert_eq!(actual.out, "0.28.0");
+    })
+}
+
+#[test]
+fn errors_on_values_that_are_neither_integers_nor_versions() {
+    Playground::setup("plugin_inc_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [package]
+                    version = "nightly"
+                "#,
+        )]);
+
+        let actual = nu_with_plugins!(
+            cwd: dirs.test(),
+            "open sample.toml | inc package.version --patch"
+        );
+
+        assert!(actual.err.contains("Can't inc 'nightly' in row 0"));
+    })
+}
+
+#[test]
+fn errors_on_integers_that_would_overflow() {
+    let actual = nu_with_plugins!(
+        cwd: ".",
+        "echo 9223372036854775807 | inc"
+    );
+
+    assert!(actual.err.contains("Can't inc by 1 in row 0"));
+}
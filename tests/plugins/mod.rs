@@ -1,2 +1,4 @@
-#[cfg(features = "inc")]
+#[cfg(feature = "inc")]
+mod core_dec;
+#[cfg(feature = "inc")]
 mod core_inc;
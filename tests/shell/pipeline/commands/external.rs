@@ -68,6 +68,22 @@ fn correctly_escape_external_arguments() {
     assert_eq!(actual.out, "$0");
 }
 
+#[cfg(not(windows))]
+#[test]
+fn caret_passes_flags_and_quoted_arguments_to_the_external_untouched() {
+    let actual = nu!(cwd: ".", r#"^printf '[%s]' --short -x "a b""#);
+
+    assert_eq!(actual.out, "[--short][-x][a b]");
+}
+
+#[cfg(not(windows))]
+#[test]
+fn grouped_short_flags_are_passed_to_externals_verbatim() {
+    let actual = nu!(cwd: ".", r#"^printf '[%s]' -la -n5"#);
+
+    assert_eq!(actual.out, "[-la][-n5]");
+}
+
 mod it_evaluation {
     use super::nu;
     use nu_test_support::fs::Stub::{EmptyFile, FileWithContent, FileWithContentToBeTrimmed};
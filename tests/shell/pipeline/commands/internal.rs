@@ -292,6 +292,51 @@ fn set_variable() {
     assert_eq!(actual.out, "17");
 }
 
+#[test]
+fn set_variable_shadows_earlier_binding() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let x = 5
+            let x = $(= $x + 1)
+            echo $x
+        "#
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn set_variable_from_a_pipeline() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            let version = $(open cargo_sample.toml | get package.version)
+            echo $version
+        "#
+    );
+
+    assert_eq!(actual.out, "0.1.1");
+}
+
+#[test]
+fn set_variable_requires_equals_and_value() {
+    let actual = nu!(cwd: ".", "let x 3");
+
+    assert!(actual.err.contains("Expected =, found 3"));
+
+    let actual = nu!(cwd: ".", "let x =");
+
+    assert!(actual.err.contains("a value after '='"));
+}
+
+#[test]
+fn undefined_variable_is_an_error() {
+    let actual = nu!(cwd: ".", "echo $nope");
+
+    assert!(actual.err.contains("unknown variable"));
+}
+
 #[test]
 fn set_doesnt_leak() {
     let actual = nu!(
@@ -317,6 +362,43 @@ fn set_env_variable() {
     assert_eq!(actual.out, "hello world");
 }
 
+#[test]
+fn env_variable_shorthand() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let-env TESTENVVAR = "hello world"
+            echo [$env.TESTENVVAR] | where $it == $env.TESTENVVAR
+        "#
+    );
+
+    assert_eq!(actual.out, "hello world");
+}
+
+#[test]
+fn env_variable_shorthand_includes_path() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            with-env [PATH "/nu/bin"] { echo $env.PATH }
+        "#
+    );
+
+    assert_eq!(actual.out, "/nu/bin");
+}
+
+#[test]
+fn env_variable_shorthand_errors_on_missing_variable() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $env.NU_SURELY_NOT_SET
+        "#
+    );
+
+    assert!(actual.err.contains("Unknown column"));
+}
+
 #[test]
 fn set_env_doesnt_leak() {
     let actual = nu!(
@@ -351,6 +433,80 @@ fn proper_shadow_set_aliases() {
     assert_eq!(actual.out, "falsetruefalse");
 }
 
+#[test]
+fn parenthesized_argument_invocation() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo (open cargo_sample.toml | get package.version)"
+    );
+
+    assert_eq!(actual.out, "0.1.1");
+}
+
+#[test]
+fn nested_parenthesized_argument_invocation() {
+    let actual = nu!(cwd: ".", "echo (echo (echo abc | str length))");
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn unbalanced_parentheses_report_errors() {
+    let actual = nu!(cwd: ".", "echo (echo 3))");
+
+    assert!(actual.err.contains("a matching '(' before ')'"));
+
+    let actual = nu!(cwd: ".", "echo (echo 3");
+
+    assert!(actual.err.contains("expected ')' before the end"));
+}
+
+#[test]
+fn unclosed_strings_report_the_opening_quote() {
+    let actual = nu!(cwd: ".", r#"echo "unclosed"#);
+
+    assert!(actual.err.contains(r#"'"' is never closed"#));
+    assert!(actual.err.contains(r#"expected '"' before the end"#));
+
+    let actual = nu!(cwd: ".", r#"config --get "a | ls"#);
+
+    assert!(actual.err.contains(r#"'"' is never closed"#));
+    assert!(actual.err.contains(r#"expected '"' before the end"#));
+}
+
+#[test]
+fn unknown_command_suggests_a_close_match() {
+    let actual = nu!(cwd: ".", "opne Cargo.toml");
+
+    assert!(actual.err.contains("Command not found"));
+    assert!(actual.err.contains("did you mean 'open'?"));
+}
+
+#[test]
+fn unknown_command_suggests_a_match_differing_only_in_case() {
+    let actual = nu!(cwd: ".", "OPEN Cargo.toml");
+
+    assert!(actual.err.contains("did you mean 'open'?"));
+}
+
+#[test]
+fn unknown_command_without_a_close_match_has_no_suggestion() {
+    let actual = nu!(cwd: ".", "ferris_is_not_here.exe");
+
+    assert!(actual.err.contains("Command not found"));
+    assert!(!actual.err.contains("did you mean"));
+}
+
+#[test]
+fn dangling_pipe_reports_errors() {
+    let actual = nu!(
+        cwd: ".",
+        "echo hi |"
+    );
+
+    assert!(actual.err.contains("Expected a command after '|'"));
+}
+
 #[cfg(feature = "which")]
 #[test]
 fn argument_invocation_reports_errors() {
@@ -735,6 +891,44 @@ mod parse {
         );
     }
 
+    #[test]
+    fn errors_suggest_a_close_flag() {
+        let actual = nu!(cwd: ".", "debug --rwa");
+
+        assert!(actual
+            .err
+            .contains("unexpected flag, did you mean '--raw'?"));
+
+        let actual = nu!(cwd: ".", "debug --ferris");
+
+        assert!(!actual.err.contains("did you mean"));
+    }
+
+    #[test]
+    fn errors_suggest_a_short_flag_differing_only_in_case() {
+        let actual = nu!(cwd: ".", "debug -R");
+
+        assert!(actual.err.contains("unexpected flag, did you mean '-r'?"));
+
+        let actual = nu!(cwd: ".", "debug -x");
+
+        assert!(actual.err.contains("unexpected flag (try debug -h)"));
+    }
+
+    #[test]
+    fn errors_for_subcommand_flags_name_the_subcommand() {
+        let actual = nu!(cwd: ".", "config get --ferris");
+
+        assert!(actual.err.contains("unexpected flag (try config get -h)"));
+    }
+
+    #[test]
+    fn external_commands_pass_unknown_flags_through() {
+        let actual = nu!(cwd: ".", "^echo --rwa -R");
+
+        assert_eq!(actual.out, "--rwa -R");
+    }
+
     #[test]
     fn errors_if_flag_is_not_supported() {
         let actual = nu!(cwd: ".", "debug --ferris");
@@ -760,6 +954,118 @@ mod parse {
             )
         );
     }
+
+    #[test]
+    fn errors_if_a_required_argument_is_missing() {
+        let actual = nu!(cwd: ".", "echo [1 2 3] | nth");
+
+        assert!(actual.err.contains("usage: nth <row number> ...args"));
+
+        let actual = nu!(cwd: ".", "open");
+
+        assert!(actual.err.contains("usage: open <path>"));
+
+        let actual = nu!(cwd: ".", "config get");
+
+        assert!(actual.err.contains("usage: config get <get>"));
+    }
+
+    #[test]
+    fn errors_if_passed_more_arguments_than_accepted() {
+        let actual = nu!(cwd: ".", "echo [1 2 3] | skip 1 2");
+
+        assert!(actual
+            .err
+            .contains("unexpected argument (usage: skip (rows))"));
+
+        let actual = nu!(cwd: ".", "echo [1 2 3] | first 1 2");
+
+        assert!(actual
+            .err
+            .contains("unexpected argument (usage: first (rows))"));
+
+        let actual = nu!(cwd: ".", "cd . ..");
+
+        assert!(actual
+            .err
+            .contains("unexpected argument (usage: cd (directory))"));
+    }
+
+    #[test]
+    fn optional_and_rest_arguments_can_be_left_out() {
+        let actual = nu!(cwd: ".", "echo [1 2 3] | first");
+
+        assert_eq!(actual.out, "1");
+
+        let actual = nu!(cwd: ".", "echo [1 2 3] | skip | count");
+
+        assert_eq!(actual.out, "2");
+
+        let actual = nu!(cwd: ".", "echo [1 2 3] | nth 0 2 | math sum");
+
+        assert_eq!(actual.out, "4");
+    }
+
+    #[test]
+    fn errors_name_the_positional_given_the_wrong_type() {
+        let actual = nu!(cwd: ".", "echo [1 2 3] | skip foo");
+
+        assert!(actual.err.contains("Expected int for `rows`, found foo"));
+
+        let actual = nu!(cwd: ".", "echo [1 2 3] | first two");
+
+        assert!(actual.err.contains("Expected int for `rows`, found two"));
+
+        let actual = nu!(cwd: ".", "echo [1 2 3] | nth first");
+
+        assert!(actual
+            .err
+            .contains("Expected int for `row number`, found first"));
+    }
+
+    #[test]
+    fn errors_if_rest_arguments_have_the_wrong_type() {
+        let actual = nu!(cwd: ".", "echo [1 2 3] | nth 0 last");
+
+        assert!(actual.err.contains("Expected int, found last"));
+    }
+
+    #[test]
+    fn flags_accept_values_attached_with_equals() {
+        let actual = nu!(cwd: ".", "echo a | str lpad --length=5 --character=x");
+
+        assert_eq!(actual.out, "xxxxa");
+    }
+
+    #[test]
+    fn grouped_short_flags_are_each_applied() {
+        let actual = nu!(cwd: "tests/fixtures", "ls -la formats | get readonly | count");
+
+        assert!(actual.err.is_empty());
+        assert_ne!(actual.out, "0");
+    }
+
+    #[test]
+    fn errors_if_grouped_short_flag_takes_a_value() {
+        let actual = nu!(cwd: ".", "echo a | str lpad -lc 5 x");
+
+        assert!(actual
+            .err
+            .contains("flags that take a value can't be grouped"));
+    }
+
+    #[test]
+    fn errors_if_flag_with_attached_value_is_not_supported() {
+        let actual = nu!(cwd: ".", "debug --ferris=crab");
+
+        assert!(
+            actual.err.contains("unexpected flag"),
+            format!(
+                "error message '{}' should contain 'unexpected flag'",
+                actual.err
+            )
+        );
+    }
 }
 
 mod tilde_expansion {